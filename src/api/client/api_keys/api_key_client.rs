@@ -0,0 +1,37 @@
+use crate::api::client::api_keys::request::api_key_request::ApiKeyCreateRequest;
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use uuid::Uuid;
+
+pub struct ApiKeyClient;
+
+impl ApiKeyClient {
+  pub fn all(access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/users/me/api-keys")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn create(payload: &ApiKeyCreateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/users/me/api-keys")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn revoke(id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("DELETE")
+      .uri(format!("/users/me/api-keys/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+}
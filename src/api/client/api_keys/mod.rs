@@ -0,0 +1,3 @@
+pub(crate) mod api_key_client;
+pub(crate) mod request;
+pub(crate) mod response;
@@ -0,0 +1,10 @@
+use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ApiKeyCreateRequest {
+  pub name: String,
+  pub scopes: Vec<ApiKeyScope>,
+  pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyResponse {
+  pub id: Uuid,
+  pub name: String,
+  pub scopes: Vec<String>,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyCreatedResponse {
+  #[serde(flatten)]
+  pub api_key: ApiKeyResponse,
+  pub key: String,
+}
@@ -1,4 +1,7 @@
-use crate::api::client::auth::request::auth_request::{AuthSignInRequest, AuthSignUpRequest};
+use crate::api::client::auth::request::auth_request::{
+  AcceptInviteRequest, AuthSignInRequest, AuthSignUpRequest, MagicLinkRequest,
+  MagicLinkVerifyRequest, OAuth2SignInRequest,
+};
 use crate::api::repository::users::constant::user_constant::OAuth2Provider;
 use axum::body::Body;
 use axum::extract::Request;
@@ -34,6 +37,15 @@ impl AuthClient {
       .unwrap()
   }
 
+  pub fn sign_in_oauth2(provider: &OAuth2Provider, payload: &OAuth2SignInRequest) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/auth/sign-in/{}", provider.as_str()))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
   pub fn sign_out(access_token: String) -> Request<Body> {
     Request::builder()
       .method("POST")
@@ -53,4 +65,31 @@ impl AuthClient {
       .body(Body::empty())
       .unwrap()
   }
+
+  pub fn magic_link(payload: &MagicLinkRequest) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/auth/magic-link")
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn magic_link_verify(payload: &MagicLinkVerifyRequest) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/auth/magic-link/verify")
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn accept_invite(payload: &AcceptInviteRequest) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/auth/accept-invite")
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
 }
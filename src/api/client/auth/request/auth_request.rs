@@ -16,4 +16,49 @@ pub struct AuthSignInRequest {
 #[derive(Clone, Serialize, Deserialize)]
 pub struct OAuth2SignInRequest {
   pub code: String,
+  pub state: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MagicLinkRequest {
+  pub email: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MagicLinkVerifyRequest {
+  pub token: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AcceptInviteRequest {
+  pub token: String,
+  pub password: String,
+  pub name: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SamlAcsRequest {
+  #[serde(rename = "SAMLResponse")]
+  pub saml_response: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SamlTenantCreateRequest {
+  pub slug: String,
+  pub idp_entity_id: String,
+  pub idp_sso_url: String,
+  pub idp_certificate: String,
+  pub email_attribute: Option<String>,
+  pub name_attribute: Option<String>,
+  pub allowed_domains: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SamlTenantConfigureRequest {
+  pub idp_entity_id: String,
+  pub idp_sso_url: String,
+  pub idp_certificate: String,
+  pub email_attribute: Option<String>,
+  pub name_attribute: Option<String>,
+  pub allowed_domains: Vec<String>,
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -5,3 +6,18 @@ pub struct SignInResponse {
   pub access_token: String,
   pub refresh_token: Option<String>,
 }
+
+/// A SAML tenant, with the SP entity ID and ACS URL to register on its IdP.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SamlTenantResponse {
+  pub slug: String,
+  pub entity_id: String,
+  pub acs_url: String,
+  pub idp_entity_id: String,
+  pub idp_sso_url: String,
+  pub email_attribute: Option<String>,
+  pub name_attribute: Option<String>,
+  pub allowed_domains: Vec<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,32 @@
+use crate::api::client::auth::request::auth_request::OAuth2SignInRequest;
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+
+pub struct IdentityClient;
+
+impl IdentityClient {
+  pub fn link(
+    provider: &OAuth2Provider,
+    payload: &OAuth2SignInRequest,
+    access_token: String,
+  ) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/users/me/identities/{}", provider.as_str()))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn unlink(provider: &OAuth2Provider, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("DELETE")
+      .uri(format!("/users/me/identities/{}", provider.as_str()))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod identity_client;
+pub(crate) mod response;
@@ -0,0 +1,12 @@
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdentityResponse {
+  pub id: Uuid,
+  pub provider: OAuth2Provider,
+  pub email: Option<String>,
+  pub created_at: Option<DateTime<Utc>>,
+}
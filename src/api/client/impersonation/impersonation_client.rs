@@ -0,0 +1,17 @@
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::AUTHORIZATION;
+use uuid::Uuid;
+
+pub struct ImpersonationClient;
+
+impl ImpersonationClient {
+  pub fn impersonate(user_id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/admin/users/{}/impersonate", user_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod impersonation_client;
+pub(crate) mod response;
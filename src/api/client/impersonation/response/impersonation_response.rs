@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImpersonationResponse {
+  pub access_token: String,
+  pub user_id: Uuid,
+  pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,37 @@
+use crate::api::client::invitations::request::invitation_request::InvitationCreateRequest;
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use uuid::Uuid;
+
+pub struct InvitationClient;
+
+impl InvitationClient {
+  pub fn all(access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/users/invitations")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn create(payload: &InvitationCreateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/users/invitations")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn revoke(id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("DELETE")
+      .uri(format!("/users/invitations/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod invitation_client;
+pub(crate) mod request;
+pub(crate) mod response;
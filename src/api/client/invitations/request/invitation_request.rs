@@ -0,0 +1,8 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct InvitationCreateRequest {
+  pub email: String,
+  pub role: UserRole,
+}
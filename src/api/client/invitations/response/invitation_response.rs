@@ -0,0 +1,14 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InvitationResponse {
+  pub id: Uuid,
+  pub email: String,
+  pub role: UserRole,
+  pub invited_by: Option<Uuid>,
+  pub expires_at: DateTime<Utc>,
+  pub created_at: Option<DateTime<Utc>>,
+}
@@ -1,3 +1,11 @@
+pub(crate) mod api_keys;
 pub(crate) mod auth;
+pub(crate) mod identities;
+pub(crate) mod impersonation;
+pub(crate) mod invitations;
 pub(crate) mod oauth2;
+pub(crate) mod oauth;
+pub(crate) mod organizations;
+pub(crate) mod saml;
+pub(crate) mod scim;
 pub(crate) mod users;
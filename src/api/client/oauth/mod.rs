@@ -0,0 +1,3 @@
+pub(crate) mod oauth_client;
+pub(crate) mod request;
+pub(crate) mod response;
@@ -0,0 +1,100 @@
+use crate::api::client::oauth::request::oauth_request::{
+  OAuthAuthorizeRequest, OAuthClientCreateRequest, OAuthTokenGrantRequest, OAuthTokenRequest,
+};
+use axum::body::Body;
+use axum::extract::Request;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use serde::Serialize;
+
+pub struct OAuthClient;
+
+impl OAuthClient {
+  pub fn create_client(payload: &OAuthClientCreateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/oauth/clients")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn introspect(
+    payload: &OAuthTokenRequest,
+    client_id: &str,
+    client_secret: &str,
+  ) -> Request<Body> {
+    Self::client_request("/oauth/introspect", payload, client_id, client_secret)
+  }
+
+  pub fn revoke(
+    payload: &OAuthTokenRequest,
+    client_id: &str,
+    client_secret: &str,
+  ) -> Request<Body> {
+    Self::client_request("/oauth/revoke", payload, client_id, client_secret)
+  }
+
+  pub fn token(
+    payload: &OAuthTokenGrantRequest,
+    client_id: &str,
+    client_secret: &str,
+  ) -> Request<Body> {
+    Self::client_request("/oauth/token", payload, client_id, client_secret)
+  }
+
+  pub fn authorize(payload: &OAuthAuthorizeRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!(
+        "/oauth/authorize?{}",
+        serde_urlencoded::to_string(payload).unwrap()
+      ))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn user_info(access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/oauth/userinfo")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn jwks() -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/oauth/jwks")
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn discovery() -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/.well-known/openid-configuration")
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  fn client_request<T: Serialize>(
+    uri: &str,
+    payload: &T,
+    client_id: &str,
+    client_secret: &str,
+  ) -> Request<Body> {
+    let credentials: String = BASE64_STANDARD.encode(format!("{}:{}", client_id, client_secret));
+    Request::builder()
+      .method("POST")
+      .uri(uri)
+      .header(AUTHORIZATION, format!("Basic {}", credentials))
+      .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+      .body(Body::from(serde_urlencoded::to_string(payload).unwrap()))
+      .unwrap()
+  }
+}
@@ -0,0 +1,51 @@
+use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+use crate::core::security::jwt_manager::TokenType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OAuthClientCreateRequest {
+  pub name: String,
+  #[serde(default)]
+  pub scopes: Vec<ApiKeyScope>,
+  #[serde(default)]
+  pub redirect_uris: Vec<String>,
+}
+
+/// Query of `GET /oauth/authorize`; PKCE with `S256` is mandatory.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OAuthAuthorizeRequest {
+  pub response_type: String,
+  pub client_id: String,
+  pub redirect_uri: String,
+  pub scope: String,
+  pub state: Option<String>,
+  pub nonce: Option<String>,
+  pub code_challenge: String,
+  pub code_challenge_method: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OAuthTokenGrantRequest {
+  pub grant_type: String,
+  pub scope: Option<String>,
+  pub code: Option<String>,
+  pub redirect_uri: Option<String>,
+  pub code_verifier: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OAuthTokenRequest {
+  pub token: String,
+  pub token_type_hint: Option<String>,
+}
+
+impl OAuthTokenRequest {
+  /// A hint the server doesn't know is ignored, as RFC 7662 §2.1 and RFC 7009 §2.1 allow.
+  pub fn token_type(&self) -> Option<TokenType> {
+    match self.token_type_hint.as_deref() {
+      Some("access_token") => Some(TokenType::AccessToken),
+      Some("refresh_token") => Some(TokenType::RefreshToken),
+      _ => None,
+    }
+  }
+}
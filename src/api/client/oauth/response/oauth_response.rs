@@ -0,0 +1,71 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OAuthClientResponse {
+  pub client_id: String,
+  pub client_secret: String,
+  pub name: String,
+  pub scopes: Vec<String>,
+  pub redirect_uris: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OAuthAuthorizeResponse {
+  pub redirect_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OAuthTokenResponse {
+  pub access_token: String,
+  pub token_type: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_in: Option<i64>,
+  pub scope: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OidcUserInfoResponse {
+  pub sub: Uuid,
+  pub email: String,
+  pub email_verified: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OidcDiscoveryResponse {
+  pub issuer: String,
+  pub authorization_endpoint: String,
+  pub token_endpoint: String,
+  pub userinfo_endpoint: String,
+  pub jwks_uri: String,
+  pub response_types_supported: Vec<String>,
+  pub grant_types_supported: Vec<String>,
+  pub subject_types_supported: Vec<String>,
+  pub id_token_signing_alg_values_supported: Vec<String>,
+  pub scopes_supported: Vec<String>,
+  pub claims_supported: Vec<String>,
+  pub token_endpoint_auth_methods_supported: Vec<String>,
+  pub code_challenge_methods_supported: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct OAuthIntrospectResponse {
+  pub active: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sub: Option<Uuid>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jti: Option<Uuid>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<UserRole>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub scope: Option<String>,
+}
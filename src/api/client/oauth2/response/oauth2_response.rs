@@ -4,6 +4,76 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct OAuth2UserInfo {
   pub(crate) sub: String,
   pub(crate) email: String,
+  #[serde(default)]
+  pub(crate) email_verified: bool,
   pub(crate) name: Option<String>,
   pub(crate) picture: Option<String>,
 }
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GithubUserInfo {
+  pub(crate) id: i64,
+  pub(crate) email: Option<String>,
+  pub(crate) name: Option<String>,
+  pub(crate) avatar_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GithubEmail {
+  pub(crate) email: String,
+  pub(crate) primary: bool,
+  pub(crate) verified: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MicrosoftUserInfo {
+  pub(crate) id: String,
+  pub(crate) mail: Option<String>,
+  pub(crate) user_principal_name: String,
+  pub(crate) display_name: Option<String>,
+}
+
+impl GithubUserInfo {
+  /// GitHub only exposes verified addresses, either as the public or the primary email.
+  pub(crate) fn into_user_info(self, email: String) -> OAuth2UserInfo {
+    OAuth2UserInfo {
+      sub: self.id.to_string(),
+      email,
+      email_verified: true,
+      name: self.name,
+      picture: self.avatar_url,
+    }
+  }
+}
+
+impl From<MicrosoftUserInfo> for OAuth2UserInfo {
+  fn from(user_info: MicrosoftUserInfo) -> Self {
+    OAuth2UserInfo {
+      sub: user_info.id,
+      email: user_info.mail.unwrap_or(user_info.user_principal_name),
+      email_verified: false,
+      name: user_info.display_name,
+      picture: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct OidcDiscovery {
+  pub(crate) issuer: String,
+  pub(crate) authorization_endpoint: String,
+  pub(crate) token_endpoint: String,
+  pub(crate) jwks_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct OidcIdTokenClaims {
+  pub(crate) sub: String,
+  pub(crate) email: Option<String>,
+  #[serde(default)]
+  pub(crate) email_verified: bool,
+  pub(crate) name: Option<String>,
+  pub(crate) picture: Option<String>,
+  pub(crate) nonce: Option<String>,
+}
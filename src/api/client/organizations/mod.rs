@@ -0,0 +1,3 @@
+pub(crate) mod organization_client;
+pub(crate) mod request;
+pub(crate) mod response;
@@ -0,0 +1,97 @@
+use crate::api::client::organizations::request::organization_request::{
+  OrganizationCreateRequest, OrganizationInviteRequest, OrganizationTransferRequest,
+};
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use uuid::Uuid;
+
+pub struct OrganizationClient;
+
+impl OrganizationClient {
+  pub fn all(access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/organizations")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn create(payload: &OrganizationCreateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/organizations")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn get(organization_id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/organizations/{}", organization_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn members(organization_id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/organizations/{}/members", organization_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn invite(
+    organization_id: Uuid,
+    payload: &OrganizationInviteRequest,
+    access_token: String,
+  ) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/organizations/{}/members", organization_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn accept(organization_id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/organizations/{}/accept", organization_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn remove(organization_id: Uuid, user_id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("DELETE")
+      .uri(format!(
+        "/organizations/{}/members/{}",
+        organization_id, user_id
+      ))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn transfer(
+    organization_id: Uuid,
+    payload: &OrganizationTransferRequest,
+    access_token: String,
+  ) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/organizations/{}/transfer", organization_id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+}
@@ -0,0 +1,19 @@
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OrganizationCreateRequest {
+  pub name: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OrganizationInviteRequest {
+  pub email: String,
+  pub role: OrganizationRole,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OrganizationTransferRequest {
+  pub user_id: Uuid,
+}
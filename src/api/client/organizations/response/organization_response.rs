@@ -0,0 +1,21 @@
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationResponse {
+  pub id: Uuid,
+  pub name: String,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationMemberResponse {
+  pub organization_id: Uuid,
+  pub user_id: Uuid,
+  pub role: OrganizationRole,
+  pub invited_by: Option<Uuid>,
+  pub accepted_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,51 @@
+use crate::api::client::auth::request::auth_request::{
+  SamlAcsRequest, SamlTenantConfigureRequest, SamlTenantCreateRequest,
+};
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+
+pub struct SamlClient;
+
+impl SamlClient {
+  pub fn metadata(tenant: &str) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/saml/{}/metadata", tenant))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn acs(tenant: &str, payload: &SamlAcsRequest) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri(format!("/saml/{}/acs", tenant))
+      .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+      .body(Body::from(serde_urlencoded::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn create_tenant(payload: &SamlTenantCreateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/saml/tenants")
+      .header(CONTENT_TYPE, "application/json")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn configure_tenant(
+    tenant: &str,
+    payload: &SamlTenantConfigureRequest,
+    access_token: String,
+  ) -> Request<Body> {
+    Request::builder()
+      .method("PUT")
+      .uri(format!("/saml/tenants/{}", tenant))
+      .header(CONTENT_TYPE, "application/json")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod request;
+pub(crate) mod response;
+pub(crate) mod scim_client;
@@ -0,0 +1,39 @@
+use crate::api::client::scim::response::scim_response::ScimName;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListRequest {
+  pub filter: Option<String>,
+  pub start_index: Option<u64>,
+  pub count: Option<u64>,
+}
+
+/// Body of `POST` and `PUT /Users`. Attributes without a column here are ignored.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+  #[serde(default)]
+  pub schemas: Vec<String>,
+  pub user_name: String,
+  pub name: Option<ScimName>,
+  pub display_name: Option<String>,
+  pub active: Option<bool>,
+  pub password: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScimPatchRequest {
+  #[serde(default)]
+  pub schemas: Vec<String>,
+  #[serde(rename = "Operations")]
+  pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScimPatchOperation {
+  pub op: String,
+  pub path: Option<String>,
+  pub value: Option<Value>,
+}
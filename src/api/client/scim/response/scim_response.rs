@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub formatted: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimEmail {
+  pub value: String,
+  pub primary: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+  pub resource_type: String,
+  pub created: Option<DateTime<Utc>>,
+  pub last_modified: Option<DateTime<Utc>>,
+  pub location: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+  pub schemas: Vec<String>,
+  pub id: Uuid,
+  pub user_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<ScimName>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_name: Option<String>,
+  pub emails: Vec<ScimEmail>,
+  pub active: bool,
+  pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse {
+  pub schemas: Vec<String>,
+  pub total_results: i64,
+  pub start_index: u64,
+  pub items_per_page: usize,
+  #[serde(rename = "Resources")]
+  pub resources: Vec<ScimUserResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimSupported {
+  pub supported: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimBulkSupported {
+  pub supported: bool,
+  pub max_operations: u64,
+  pub max_payload_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimFilterSupported {
+  pub supported: bool,
+  pub max_results: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScimAuthenticationScheme {
+  #[serde(rename = "type")]
+  pub scheme_type: String,
+  pub name: String,
+  pub description: String,
+  pub primary: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimServiceProviderConfigResponse {
+  pub schemas: Vec<String>,
+  pub patch: ScimSupported,
+  pub bulk: ScimBulkSupported,
+  pub filter: ScimFilterSupported,
+  pub change_password: ScimSupported,
+  pub sort: ScimSupported,
+  pub etag: ScimSupported,
+  pub authentication_schemes: Vec<ScimAuthenticationScheme>,
+}
@@ -0,0 +1,81 @@
+use crate::api::client::scim::request::scim_request::{
+  ScimListRequest, ScimPatchRequest, ScimUserRequest,
+};
+use crate::api::controller::scim::scim_controller::SCIM_CONTENT_TYPE;
+use axum::body::Body;
+use axum::extract::Request;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use uuid::Uuid;
+
+pub struct ScimClient;
+
+impl ScimClient {
+  pub fn service_provider_config(secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/scim/v2/ServiceProviderConfig")
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn all(payload: &ScimListRequest, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!(
+        "/scim/v2/Users?{}",
+        serde_urlencoded::to_string(payload).unwrap()
+      ))
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn get(id: Uuid, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/scim/v2/Users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn create(payload: &ScimUserRequest, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("POST")
+      .uri("/scim/v2/Users")
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .header(CONTENT_TYPE, SCIM_CONTENT_TYPE)
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn replace(id: Uuid, payload: &ScimUserRequest, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("PUT")
+      .uri(format!("/scim/v2/Users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .header(CONTENT_TYPE, SCIM_CONTENT_TYPE)
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn patch(id: Uuid, payload: &ScimPatchRequest, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("PATCH")
+      .uri(format!("/scim/v2/Users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .header(CONTENT_TYPE, SCIM_CONTENT_TYPE)
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
+
+  pub fn delete(id: Uuid, secret: &str) -> Request<Body> {
+    Request::builder()
+      .method("DELETE")
+      .uri(format!("/scim/v2/Users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", secret))
+      .body(Body::empty())
+      .unwrap()
+  }
+}
@@ -1,4 +1,5 @@
 use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -13,3 +14,14 @@ pub struct UserUpdateStatusRequest {
   pub user_id: Uuid,
   pub status: UserStatus,
 }
+
+/// Admin update of another account; omitted fields are left unchanged. `status_reason` and
+/// `status_until` go with a `suspended` or `banned` status and are cleared by any other status.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct UserUpdateRequest {
+  pub name: Option<String>,
+  pub role: Option<UserRole>,
+  pub status: Option<UserStatus>,
+  pub status_reason: Option<String>,
+  pub status_until: Option<DateTime<Utc>>,
+}
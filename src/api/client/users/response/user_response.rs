@@ -1,4 +1,4 @@
-use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -9,6 +9,9 @@ pub struct UserResponse {
   pub email: String,
   pub name: Option<String>,
   pub role: UserRole,
+  pub status: UserStatus,
+  pub status_reason: Option<String>,
+  pub status_until: Option<DateTime<Utc>>,
   pub profile_url: Option<String>,
   pub logged_in_at: Option<DateTime<Utc>>,
   pub created_at: Option<DateTime<Utc>>,
@@ -1,7 +1,8 @@
-use crate::api::client::users::request::user_request::UserUpdateRoleRequest;
+use crate::api::client::users::request::user_request::{UserUpdateRequest, UserUpdateRoleRequest};
 use axum::body::Body;
 use axum::extract::Request;
 use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use uuid::Uuid;
 
 pub struct UserClient;
 
@@ -15,6 +16,15 @@ impl UserClient {
       .unwrap()
   }
 
+  pub fn filter(query: &str, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/users?{}", query))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
   pub fn me(access_token: String) -> Request<Body> {
     Request::builder()
       .method("GET")
@@ -46,4 +56,23 @@ impl UserClient {
       .body(Body::from(serde_json::to_string(payload).unwrap()))
       .unwrap()
   }
+
+  pub fn get(id: Uuid, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri(format!("/users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  pub fn update(id: Uuid, payload: &UserUpdateRequest, access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("PATCH")
+      .uri(format!("/users/{}", id))
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .header(CONTENT_TYPE, "application/json")
+      .body(Body::from(serde_json::to_string(payload).unwrap()))
+      .unwrap()
+  }
 }
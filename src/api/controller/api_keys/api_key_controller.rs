@@ -0,0 +1,453 @@
+use crate::api::client::api_keys::request::api_key_request::ApiKeyCreateRequest;
+use crate::api::manager::api_keys::api_key_manager::ApiKeyManager;
+use crate::api::state::api_keys::api_key_state::ApiKeyState;
+use crate::core::error::error::AppError;
+use crate::core::response::constant::BaseMessage;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::jwt_manager::Claims;
+use axum::extract::{Path, State};
+use axum::routing::{delete, get};
+use axum::{response::IntoResponse, Json, Router};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ApiKeyController;
+
+impl ApiKeyController {
+  pub(crate) fn configure(state: Arc<ApiKeyState>) -> Router {
+    Router::new()
+      .nest(
+        "/users/me/api-keys",
+        Router::new()
+          .route("/", get(Self::all).post(Self::create))
+          .route("/:id", delete(Self::revoke)),
+      )
+      .with_state(state.clone())
+  }
+
+  /// Keys can only be managed from a regular session, never with another key or while
+  /// impersonating, which would outlive the impersonation.
+  fn session_claims(claims: Claims) -> Result<Claims, AppError> {
+    match claims.is_scoped() || claims.act.is_some() {
+      true => Err(AppError::PermissionDenied),
+      false => Ok(claims),
+    }
+  }
+}
+
+impl ApiKeyController {
+  async fn all(
+    State(state): State<Arc<ApiKeyState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token_data: Claims = Self::session_claims(token_data)?;
+    Ok(SuccessResponse {
+      data: state.manager.all(token_data.sub).await?.into(),
+    })
+  }
+
+  async fn create(
+    State(state): State<Arc<ApiKeyState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Json(payload): Json<ApiKeyCreateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token_data: Claims = Self::session_claims(token_data)?;
+    Ok(SuccessResponse {
+      data: state.manager.create(token_data.sub, payload).await?.into(),
+    })
+  }
+
+  async fn revoke(
+    State(state): State<Arc<ApiKeyState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token_data: Claims = Self::session_claims(token_data)?;
+    state.manager.revoke(token_data.sub, id).await?;
+    Ok(SuccessResponse {
+      data: BaseMessage::Success.into(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::api_keys::api_key_client::ApiKeyClient;
+  use crate::api::client::api_keys::response::api_key_response::{
+    ApiKeyCreatedResponse, ApiKeyResponse,
+  };
+  use crate::api::client::users::request::user_request::UserUpdateRequest;
+  use crate::api::client::users::response::user_response::UserResponse;
+  use crate::api::client::users::user_client::UserClient;
+  use crate::api::controller::users::user_controller::UserController;
+  use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+  use crate::api::repository::api_keys::repository::api_key_repository::ApiKeyRepository;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::state::roles::role_state::RoleState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::{JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use crate::utils::datetime_utils::TimeUtils;
+  use crate::utils::hash_utils::HashUtils;
+  use crate::utils::random_utils::RandomUtils;
+  use axum::body::Body;
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use chrono::Duration;
+  use faker_rand::fr_fr::names::FullName;
+  use serde_json::{json, Value};
+
+  async fn init_setup() -> (Router, Arc<ApiKeyState>) {
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let state: Arc<ApiKeyState> = Arc::new(ApiKeyState::new(&db_pool).await);
+    let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
+    let app: Router = Router::new()
+      .merge(ApiKeyController::configure(state.clone()))
+      .merge(UserController::configure(user_state.clone()))
+      .layer(Extension(state.clone()))
+      .layer(Extension(user_state))
+      .layer(Extension(Arc::new(RoleState::new(&db_pool).await)));
+    (app, state)
+  }
+
+  fn claims_of(access_token: &str) -> Claims {
+    let config: AppConfig = APP_CONFIG.clone();
+    JwtManager::new(config.jwt)
+      .validate_jwt(access_token, TokenType::AccessToken)
+      .unwrap()
+  }
+
+  async fn init_api_key(
+    state: &Arc<ApiKeyState>,
+    access_token: &str,
+    scopes: Vec<ApiKeyScope>,
+  ) -> ApiKeyCreatedResponse {
+    state
+      .manager
+      .create(
+        claims_of(access_token).sub,
+        ApiKeyCreateRequest {
+          name: rand::random::<FullName>().to_string(),
+          scopes,
+          expires_at: None,
+        },
+      )
+      .await
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_api_key_create_success() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let payload = ApiKeyCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![ApiKeyScope::Read, ApiKeyScope::Read],
+      expires_at: Some(TimeUtils::utc_now() + Duration::days(30)),
+    };
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let request: Request<Body> = ApiKeyClient::create(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+
+    if let Some(data) = body.get("data") {
+      let data_value: ApiKeyCreatedResponse =
+        serde_json::from_value(data.clone()).expect("Failed to deserialize data");
+      assert!(data_value.key.starts_with("pat_"));
+      assert_eq!(data_value.api_key.name, payload.name);
+      assert_eq!(data_value.api_key.scopes, vec!["read".to_string()]);
+      assert!(data_value.api_key.expires_at.is_some());
+    } else {
+      panic!("Expected 'data' field not found in response body");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_api_key_create_without_scopes() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let payload = ApiKeyCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![],
+      expires_at: None,
+    };
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| ApiKeyClient::create(&payload, token),
+        access_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid api key request",
+            "code": 400
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_api_key_all_hides_secret() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let api_key: ApiKeyCreatedResponse =
+      init_api_key(&state, &access_token, vec![ApiKeyScope::Read]).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(ApiKeyClient::all(access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let data: &Vec<Value> = body.get("data").unwrap().as_array().unwrap();
+    let listed: &Value = data
+      .iter()
+      .find(|value| value.get("id") == Some(&json!(api_key.api_key.id)))
+      .expect("Created api key not listed");
+
+    assert!(listed.get("key").is_none());
+    assert!(listed.get("key_hash").is_none());
+  }
+
+  #[tokio::test]
+  async fn test_api_key_authenticates_and_records_last_used() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let api_key: ApiKeyCreatedResponse =
+      init_api_key(&state, &access_token, vec![ApiKeyScope::Read]).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(UserClient::me(api_key.key.clone()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let user: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(user.id, claims_of(&access_token).sub);
+
+    let api_keys: Vec<ApiKeyResponse> = state.manager.all(user.id).await.unwrap();
+    let used: &ApiKeyResponse = api_keys
+      .iter()
+      .find(|key| key.id == api_key.api_key.id)
+      .unwrap();
+    assert!(used.last_used_at.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_api_key_requires_write_scope() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+    let read_key: ApiKeyCreatedResponse = init_api_key(
+      &state,
+      &access_token,
+      vec![ApiKeyScope::Read, ApiKeyScope::Admin],
+    )
+    .await;
+    let write_key: ApiKeyCreatedResponse = init_api_key(
+      &state,
+      &access_token,
+      vec![ApiKeyScope::Write, ApiKeyScope::Admin],
+    )
+    .await;
+    let payload = UserUpdateRequest {
+      name: Some(rand::random::<FullName>().to_string()),
+      ..Default::default()
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| UserClient::update(user.id, &payload, token),
+        read_key.key,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+
+    let response: Response = test_client
+      .get_response(UserClient::update(user.id, &payload, write_key.key))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_api_key_cannot_manage_api_keys() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let api_key: ApiKeyCreatedResponse = init_api_key(
+      &state,
+      &access_token,
+      vec![ApiKeyScope::Read, ApiKeyScope::Write],
+    )
+    .await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_unauthorized_request(
+        ApiKeyClient::all,
+        api_key.key,
+        json!({
+            "error": "invalid jwt token",
+            "code": 401
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_api_key_admin_route_requires_admin_scope() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+    let read_key: ApiKeyCreatedResponse =
+      init_api_key(&state, &access_token, vec![ApiKeyScope::Read]).await;
+    let admin_key: ApiKeyCreatedResponse = init_api_key(
+      &state,
+      &access_token,
+      vec![ApiKeyScope::Read, ApiKeyScope::Admin],
+    )
+    .await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        UserClient::all,
+        read_key.key,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+
+    let response: Response = test_client
+      .get_response(UserClient::all(admin_key.key))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_api_key_revoke_success() {
+    let (app, state) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let api_key: ApiKeyCreatedResponse =
+      init_api_key(&state, &access_token, vec![ApiKeyScope::Read]).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(ApiKeyClient::revoke(
+        api_key.api_key.id,
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    test_client
+      .call_unauthorized_request(
+        UserClient::me,
+        api_key.key,
+        json!({
+            "error": "invalid api key",
+            "code": 401
+        }),
+      )
+      .await;
+
+    test_client
+      .call_request(
+        |token| ApiKeyClient::revoke(api_key.api_key.id, token),
+        access_token,
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "api key not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_api_key_expired() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let repository: RepositoryImpl = RepositoryImpl::new(db_pool);
+
+    let id: Uuid = Uuid::new_v4();
+    let secret: String = RandomUtils::alphanumeric(40);
+    repository
+      .create(
+        id,
+        claims_of(&AuthSetup::get_user_access_token()).sub,
+        rand::random::<FullName>().to_string(),
+        HashUtils::hash_password(&secret),
+        vec![ApiKeyScope::Read.as_str().to_string()],
+        Some(TimeUtils::utc_now() - Duration::days(1)),
+      )
+      .await
+      .unwrap();
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_unauthorized_request(
+        UserClient::me,
+        format!("pat_{}_{}", id.simple(), secret),
+        json!({
+            "error": "invalid api key",
+            "code": 401
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_api_key_invalid() {
+    let (app, _) = init_setup().await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_unauthorized_request(
+        UserClient::me,
+        format!("pat_{}_fake", Uuid::new_v4().simple()),
+        json!({
+            "error": "invalid api key",
+            "code": 401
+        }),
+      )
+      .await;
+  }
+}
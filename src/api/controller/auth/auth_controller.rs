@@ -1,5 +1,6 @@
 use crate::api::client::auth::request::auth_request::{
-  AuthSignInRequest, AuthSignUpRequest, OAuth2SignInRequest,
+  AcceptInviteRequest, AuthSignInRequest, AuthSignUpRequest, MagicLinkRequest,
+  MagicLinkVerifyRequest, OAuth2SignInRequest,
 };
 use crate::api::client::auth::response::auth_response::SignInResponse;
 use crate::api::manager::auth::auth_manager::AuthManager;
@@ -30,7 +31,10 @@ impl AuthController {
           )
           .route("/sign-in/:provider", post(Self::sign_in_oauth2))
           .route("/sign-out", post(Self::sign_out))
-          .route("/refresh-token", post(Self::refresh_token)),
+          .route("/refresh-token", post(Self::refresh_token))
+          .route("/magic-link", post(Self::magic_link))
+          .route("/magic-link/verify", post(Self::magic_link_verify))
+          .route("/accept-invite", post(Self::accept_invite)),
       )
       .with_state(state.clone())
   }
@@ -60,11 +64,11 @@ impl AuthController {
 
   async fn sign_in_oauth2_get_redirect_uri(
     State(state): State<Arc<AuthState>>,
-    provider: Path<OAuth2Provider>,
+    Path(provider): Path<OAuth2Provider>,
   ) -> Result<impl IntoResponse, AppError> {
     let redirect_url: String = state
       .manager
-      .sign_in_oauth2_get_redirect_uri(provider.clone())
+      .sign_in_oauth2_get_redirect_uri(provider)
       .await?;
     Ok(
       SuccessResponse {
@@ -76,13 +80,10 @@ impl AuthController {
 
   async fn sign_in_oauth2(
     State(state): State<Arc<AuthState>>,
-    provider: Path<OAuth2Provider>,
+    Path(provider): Path<OAuth2Provider>,
     Json(payload): Json<OAuth2SignInRequest>,
   ) -> Result<impl IntoResponse, AppError> {
-    let token: SignInResponse = state
-      .manager
-      .sign_in_oauth2(provider.clone(), payload)
-      .await?;
+    let token: SignInResponse = state.manager.sign_in_oauth2(provider, payload).await?;
     Ok(SuccessResponse { data: token.into() }.into_response())
   }
 
@@ -109,6 +110,35 @@ impl AuthController {
       .await?;
     Ok(SuccessResponse { data: token.into() }.into_response())
   }
+
+  async fn magic_link(
+    State(state): State<Arc<AuthState>>,
+    Json(payload): Json<MagicLinkRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.send_magic_link(payload).await?;
+    Ok(
+      SuccessResponse {
+        data: BaseMessage::Success.into(),
+      }
+      .into_response(),
+    )
+  }
+
+  async fn magic_link_verify(
+    State(state): State<Arc<AuthState>>,
+    Json(payload): Json<MagicLinkVerifyRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token: SignInResponse = state.manager.sign_in_magic_link(payload).await?;
+    Ok(SuccessResponse { data: token.into() }.into_response())
+  }
+
+  async fn accept_invite(
+    State(state): State<Arc<AuthState>>,
+    Json(payload): Json<AcceptInviteRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token: SignInResponse = state.manager.accept_invite(payload).await?;
+    Ok(SuccessResponse { data: token.into() }.into_response())
+  }
 }
 
 #[cfg(test)]
@@ -116,9 +146,24 @@ mod tests {
   use super::*;
   use crate::api::client::auth::auth_client::AuthClient;
   use crate::api::client::auth::response::auth_response::SignInResponse;
+  use crate::api::manager::users::user_manager::UserManager;
+  use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentityEntity;
+  use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+  use crate::api::repository::users::constant::user_constant::{
+    OAuth2Provider, UserRole, UserStatus,
+  };
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::test::ldap_stub::{LdapStub, LdapStubEntry, LDAP_STUB_ADMIN_GROUP};
   use crate::api::test::test_context::{
     AuthSetup, TestClient, USER_ACCESS_TOKEN, USER_DEFAULT_EMAIL, USER_REFRESH_TOKEN,
   };
+  use crate::core::security::jwt_manager::{Claims, JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use crate::utils::hash_utils::HashUtils;
   use axum::body::Body;
   use axum::http::{Request, StatusCode};
   use axum::response::Response;
@@ -126,6 +171,37 @@ mod tests {
   use faker_rand::fr_fr::names::FullName;
   use serde_json::{json, Value};
   use std::sync::MutexGuard;
+  use uuid::Uuid;
+
+  async fn ldap_setup(stub: &LdapStub) -> (TestClient, RepositoryImpl) {
+    let mut config: AppConfig = APP_CONFIG.clone();
+    config.ldap = stub.config();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let state: Arc<AuthState> = Arc::new(
+      AuthState::new(
+        &db_pool,
+        JwtManager::new(config.jwt),
+        &config.aws,
+        &config.oauth2,
+        &config.magic_link,
+        &config.saml,
+        &config.ldap,
+      )
+      .await,
+    );
+    (
+      TestClient::new(AuthController::configure(state)),
+      RepositoryImpl::new(db_pool),
+    )
+  }
+
+  fn ldap_email() -> String {
+    format!("ldap-{}@example.com", Uuid::new_v4())
+  }
+
+  fn ldap_uid() -> String {
+    format!("ldap-{}", Uuid::new_v4().simple())
+  }
 
   #[tokio::test]
   async fn test_auth_sign_up_success() {
@@ -264,7 +340,67 @@ mod tests {
 
     assert_eq!(response.status(), StatusCode::OK);
 
-    let _: Value = test_client.get_body(response).await;
+    let body: Value = test_client.get_body(response).await;
+    let redirect_url: &str = body.get("data").unwrap().as_str().unwrap();
+    assert!(redirect_url.contains("state="));
+    assert!(redirect_url.contains("code_challenge="));
+    assert!(redirect_url.contains("code_challenge_method=S256"));
+  }
+
+  #[tokio::test]
+  async fn test_auth_get_oauth_redirect_url_per_provider() {
+    let (app, _, _) = AuthSetup::init().await;
+
+    let test_client: TestClient = TestClient::new(app);
+    for (provider, auth_url) in [
+      (
+        OAuth2Provider::Github,
+        "https://github.com/login/oauth/authorize",
+      ),
+      (
+        OAuth2Provider::Microsoft,
+        "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+      ),
+    ] {
+      let response: Response = test_client
+        .get_response(AuthClient::sign_in_oauth2_get_redirect_uri(&provider))
+        .await;
+      assert_eq!(response.status(), StatusCode::OK);
+
+      let body: Value = test_client.get_body(response).await;
+      let redirect_url: &str = body.get("data").unwrap().as_str().unwrap();
+      assert!(redirect_url.starts_with(auth_url));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_oauth2_invalid_state() {
+    let (app, _, _) = AuthSetup::init().await;
+
+    let provider: OAuth2Provider = OAuth2Provider::Google;
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in_oauth2_get_redirect_uri(&provider))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let payload = OAuth2SignInRequest {
+      code: "fake_code".to_string(),
+      state: "fake_state".to_string(),
+    };
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in_oauth2(&provider, &payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid oauth2 state",
+          "code": 400
+      })
+    );
   }
 
   #[tokio::test]
@@ -349,4 +485,288 @@ mod tests {
       )
       .await;
   }
+
+  fn magic_link_token(link: &str) -> String {
+    link.split_once("?token=").unwrap().1.to_string()
+  }
+
+  #[tokio::test]
+  async fn test_auth_magic_link_success() {
+    let (app, _, _) = AuthSetup::init().await;
+
+    let payload = MagicLinkRequest {
+      email: rand::random::<Email>().to_string(),
+    };
+    let request: Request<Body> = AuthClient::magic_link(&payload);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_auth_magic_link_verify_creates_user() {
+    let (app, auth_state, user_state) = AuthSetup::init().await;
+
+    let email: String = rand::random::<Email>().to_string().to_lowercase();
+    let link: String = auth_state
+      .manager
+      .create_magic_link(email.clone())
+      .await
+      .unwrap();
+    let payload = MagicLinkVerifyRequest {
+      token: magic_link_token(&link),
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(AuthClient::magic_link_verify(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let token: SignInResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(token.refresh_token.is_some());
+
+    let config: AppConfig = APP_CONFIG.clone();
+    let claims: Claims = JwtManager::new(config.jwt)
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    let user: UserEntity = user_state.manager.me(claims.sub).await.unwrap();
+    assert_eq!(user.email, email);
+    assert!(user.password.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_auth_magic_link_verify_single_use() {
+    let (app, auth_state, _) = AuthSetup::init().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let link: String = auth_state
+      .manager
+      .create_magic_link(USER_DEFAULT_EMAIL.clone())
+      .await
+      .unwrap();
+    let payload = MagicLinkVerifyRequest {
+      token: magic_link_token(&link),
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(AuthClient::magic_link_verify(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(AuthClient::magic_link_verify(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid magic link",
+          "code": 401
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_auth_magic_link_verify_invalid_token() {
+    let (app, _, _) = AuthSetup::init().await;
+
+    let payload = MagicLinkVerifyRequest {
+      token: "fake_token".to_string(),
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(AuthClient::magic_link_verify(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid magic link",
+          "code": 401
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_ldap_provisions_user() {
+    let email: String = ldap_email();
+    let stub = LdapStub::start(vec![LdapStubEntry::person(
+      &ldap_uid(),
+      &email,
+      "Jane Doe",
+      "directory-secret",
+      &[LDAP_STUB_ADMIN_GROUP],
+    )])
+    .await;
+    let (test_client, repository) = ldap_setup(&stub).await;
+
+    let payload = AuthSignInRequest {
+      email: email.to_uppercase(),
+      password: "directory-secret".to_string(),
+    };
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let token: SignInResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+
+    let user: UserEntity = repository.by_email(email).await.unwrap().unwrap();
+    assert_eq!(user.name, Some("Jane Doe".to_string()));
+    assert_eq!(user.role, UserRole::Admin);
+    assert!(matches!(user.status, UserStatus::Verified));
+    assert!(user.password.is_none());
+    let identities: Vec<UserIdentityEntity> = repository.by_user_id(user.id).await.unwrap();
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].provider, OAuth2Provider::Ldap);
+
+    let claims: Claims = JwtManager::new(APP_CONFIG.jwt.clone())
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    assert_eq!(claims.sub, user.id);
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_ldap_syncs_role() {
+    let email: String = ldap_email();
+    let stub = LdapStub::start(vec![LdapStubEntry::person(
+      &ldap_uid(),
+      &email,
+      "John Roe",
+      "directory-secret",
+      &[],
+    )])
+    .await;
+    let (test_client, repository) = ldap_setup(&stub).await;
+
+    let payload = AuthSignInRequest {
+      email: email.clone(),
+      password: "directory-secret".to_string(),
+    };
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let user: UserEntity = repository.by_email(email.clone()).await.unwrap().unwrap();
+    repository
+      .update_role(user.id, UserRole::Admin)
+      .await
+      .unwrap();
+
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let user: UserEntity = repository.by_email(email).await.unwrap().unwrap();
+    assert_eq!(user.role, UserRole::User);
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_ldap_falls_back_to_local_account() {
+    let email: String = ldap_email();
+    let stub = LdapStub::start(vec![LdapStubEntry::person(
+      &ldap_uid(),
+      &email,
+      "John Roe",
+      "directory-secret",
+      &[LDAP_STUB_ADMIN_GROUP],
+    )])
+    .await;
+    let (test_client, repository) = ldap_setup(&stub).await;
+
+    let local_email: String = ldap_email();
+    for email in [&email, &local_email] {
+      UserRepository::create(
+        &repository,
+        email.clone(),
+        Some(HashUtils::hash_password("local-secret")),
+        None,
+      )
+      .await
+      .unwrap();
+    }
+
+    for email in [&email, &local_email] {
+      let payload = AuthSignInRequest {
+        email: email.clone(),
+        password: "local-secret".to_string(),
+      };
+      let response: Response = test_client
+        .get_response(AuthClient::sign_in(&payload))
+        .await;
+      assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let payload = AuthSignInRequest {
+      email: email.clone(),
+      password: "directory-secret".to_string(),
+    };
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let user: UserEntity = repository.by_email(email).await.unwrap().unwrap();
+    assert_eq!(user.role, UserRole::User);
+    assert!(repository.by_user_id(user.id).await.unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_ldap_invalid_password() {
+    let email: String = ldap_email();
+    let stub = LdapStub::start(vec![LdapStubEntry::person(
+      &ldap_uid(),
+      &email,
+      "Jane Doe",
+      "directory-secret",
+      &[],
+    )])
+    .await;
+    let (test_client, repository) = ldap_setup(&stub).await;
+
+    for password in ["wrong-secret", ""] {
+      let payload = AuthSignInRequest {
+        email: email.clone(),
+        password: password.to_string(),
+      };
+      let response: Response = test_client
+        .get_response(AuthClient::sign_in(&payload))
+        .await;
+      assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+      let body: Value = test_client.get_body(response).await;
+      assert_eq!(body, json!({"error": "incorrect password", "code": 401}));
+    }
+    assert!(repository.by_email(email).await.unwrap().is_none());
+  }
+
+  #[tokio::test]
+  async fn test_auth_sign_in_ldap_user_not_found() {
+    let stub = LdapStub::start(Vec::new()).await;
+    let (test_client, _) = ldap_setup(&stub).await;
+
+    let payload = AuthSignInRequest {
+      email: ldap_email(),
+      password: "directory-secret".to_string(),
+    };
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body, json!({"error": "user not found", "code": 404}));
+  }
 }
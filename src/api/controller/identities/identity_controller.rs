@@ -0,0 +1,364 @@
+use crate::api::client::auth::request::auth_request::OAuth2SignInRequest;
+use crate::api::client::identities::response::identity_response::IdentityResponse;
+use crate::api::manager::auth::auth_manager::AuthManager;
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use crate::api::state::auth::auth_state::AuthState;
+use crate::core::error::error::AppError;
+use crate::core::response::constant::BaseMessage;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use axum::extract::{Path, State};
+use axum::routing::post;
+use axum::{response::IntoResponse, Json, Router};
+use std::sync::Arc;
+
+pub struct IdentityController;
+
+impl IdentityController {
+  pub(crate) fn configure(state: Arc<AuthState>) -> Router {
+    Router::new()
+      .nest(
+        "/users/me/identities",
+        Router::new().route("/:provider", post(Self::link).delete(Self::unlink)),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl IdentityController {
+  async fn link(
+    State(state): State<Arc<AuthState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Path(provider): Path<OAuth2Provider>,
+    Json(payload): Json<OAuth2SignInRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    if token_data.is_scoped() || token_data.act.is_some() {
+      return Err(AppError::PermissionDenied);
+    }
+    let identity: IdentityResponse = state
+      .manager
+      .link_identity(token_data.sub, provider, payload)
+      .await?;
+    Ok(SuccessResponse {
+      data: identity.into(),
+    })
+  }
+
+  async fn unlink(
+    State(state): State<Arc<AuthState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Path(provider): Path<OAuth2Provider>,
+  ) -> Result<impl IntoResponse, AppError> {
+    if token_data.is_scoped() || token_data.act.is_some() {
+      return Err(AppError::PermissionDenied);
+    }
+    state
+      .manager
+      .unlink_identity(token_data.sub, provider)
+      .await?;
+    Ok(SuccessResponse {
+      data: BaseMessage::Success.into(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::auth::auth_client::AuthClient;
+  use crate::api::client::auth::response::auth_response::SignInResponse;
+  use crate::api::client::identities::identity_client::IdentityClient;
+  use crate::api::controller::auth::auth_controller::AuthController;
+  use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentityEntity;
+  use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::oidc_stub::StubIssuer;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::{Claims, JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::body::Body;
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use faker_rand::en_us::internet::Email;
+  use reqwest::Url;
+  use serde_json::{json, Value};
+  use uuid::Uuid;
+
+  const PROVIDER: OAuth2Provider = OAuth2Provider::Oidc;
+
+  async fn init_setup() -> (TestClient, Arc<StubIssuer>, RepositoryImpl) {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let mut config: AppConfig = APP_CONFIG.clone();
+    config
+      .oauth2
+      .insert(PROVIDER.as_str().to_string(), stub.config());
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let state: Arc<AuthState> = Arc::new(
+      AuthState::new(
+        &db_pool,
+        JwtManager::new(config.jwt),
+        &config.aws,
+        &config.oauth2,
+        &config.magic_link,
+        &config.saml,
+        &config.ldap,
+      )
+      .await,
+    );
+    let app: Router = Router::new()
+      .merge(AuthController::configure(state.clone()))
+      .merge(IdentityController::configure(state))
+      .layer(Extension(Arc::new(
+        UserState::new(&db_pool, &config.aws).await,
+      )));
+    (TestClient::new(app), stub, RepositoryImpl::new(db_pool))
+  }
+
+  /// Starts the authorization flow and lets the stub issue an id_token for it.
+  async fn authorize(
+    test_client: &TestClient,
+    stub: &StubIssuer,
+    claims: Value,
+  ) -> OAuth2SignInRequest {
+    let response: Response = test_client
+      .get_response(AuthClient::sign_in_oauth2_get_redirect_uri(&PROVIDER))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let redirect_url: Url = Url::parse(body.get("data").unwrap().as_str().unwrap()).unwrap();
+    let query_param = |name: &str| -> String {
+      redirect_url
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.to_string())
+        .unwrap()
+    };
+
+    let mut claims: Value = claims;
+    claims["nonce"] = json!(query_param("nonce"));
+    stub.issue(StubIssuer::sign(&claims));
+    OAuth2SignInRequest {
+      code: "code".to_string(),
+      state: query_param("state"),
+    }
+  }
+
+  async fn sign_in(test_client: &TestClient, payload: &OAuth2SignInRequest) -> Response {
+    test_client
+      .get_response(AuthClient::sign_in_oauth2(&PROVIDER, payload))
+      .await
+  }
+
+  fn subject_of(response_body: Value) -> Uuid {
+    let token: SignInResponse =
+      serde_json::from_value(response_body.get("data").unwrap().clone()).unwrap();
+    let config: AppConfig = APP_CONFIG.clone();
+    let claims: Claims = JwtManager::new(config.jwt)
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    claims.sub
+  }
+
+  #[tokio::test]
+  async fn test_link_identity_success() {
+    let (test_client, stub, repository) = init_setup().await;
+    let (user, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let subject: String = Uuid::new_v4().to_string();
+
+    let claims: Value = stub.claims(&subject, &rand::random::<Email>().to_string(), "");
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims.clone()).await;
+    let response: Response = test_client
+      .get_response(IdentityClient::link(&PROVIDER, &payload, access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let identity: IdentityResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(identity.provider, PROVIDER);
+
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = sign_in(&test_client, &payload).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(subject_of(test_client.get_body(response).await), user.id);
+
+    let identities: Vec<UserIdentityEntity> = repository.by_user_id(user.id).await.unwrap();
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].subject, subject);
+  }
+
+  #[tokio::test]
+  async fn test_link_identity_already_linked() {
+    let (test_client, stub, _) = init_setup().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    for expected_status in [StatusCode::OK, StatusCode::CONFLICT] {
+      let claims: Value = stub.claims(
+        &Uuid::new_v4().to_string(),
+        &rand::random::<Email>().to_string(),
+        "",
+      );
+      let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+      let response: Response = test_client
+        .get_response(IdentityClient::link(
+          &PROVIDER,
+          &payload,
+          access_token.clone(),
+        ))
+        .await;
+      assert_eq!(response.status(), expected_status);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_link_identity_invalid_state() {
+    let (test_client, _, _) = init_setup().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OAuth2SignInRequest {
+      code: "code".to_string(),
+      state: "fake_state".to_string(),
+    };
+    test_client
+      .call_request(
+        |token| IdentityClient::link(&PROVIDER, &payload, token),
+        access_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid oauth2 state",
+            "code": 400
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_sign_in_oauth2_creates_user() {
+    let (test_client, stub, repository) = init_setup().await;
+    let email: String = rand::random::<Email>().to_string().to_lowercase();
+
+    let claims: Value = stub.claims(&Uuid::new_v4().to_string(), &email, "");
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = sign_in(&test_client, &payload).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let user_id: Uuid = subject_of(test_client.get_body(response).await);
+    let user: UserEntity = repository.by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(user.email, email);
+    assert!(user.password.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_sign_in_oauth2_links_verified_email() {
+    let (test_client, stub, repository) = init_setup().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let claims: Value = stub.claims(&Uuid::new_v4().to_string(), &user.email, "");
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = sign_in(&test_client, &payload).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(subject_of(test_client.get_body(response).await), user.id);
+
+    let identities: Vec<UserIdentityEntity> = repository.by_user_id(user.id).await.unwrap();
+    assert_eq!(identities.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_sign_in_oauth2_unverified_email_conflict() {
+    let (test_client, stub, repository) = init_setup().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let mut claims: Value = stub.claims(&Uuid::new_v4().to_string(), &user.email, "");
+    claims["email_verified"] = json!(false);
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = sign_in(&test_client, &payload).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "existing email",
+          "code": 409
+      })
+    );
+    assert!(repository.by_user_id(user.id).await.unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_unlink_identity_success() {
+    let (test_client, stub, _) = init_setup().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let claims: Value = stub.claims(
+      &Uuid::new_v4().to_string(),
+      &rand::random::<Email>().to_string(),
+      "",
+    );
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = test_client
+      .get_response(IdentityClient::link(
+        &PROVIDER,
+        &payload,
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let request: Request<Body> = IdentityClient::unlink(&PROVIDER, access_token.clone());
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    test_client
+      .call_request(
+        |token| IdentityClient::unlink(&PROVIDER, token),
+        access_token,
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "identity not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_unlink_last_sign_in_method_not_allowed() {
+    let (test_client, stub, _) = init_setup().await;
+
+    let claims: Value = stub.claims(
+      &Uuid::new_v4().to_string(),
+      &rand::random::<Email>().to_string(),
+      "",
+    );
+    let payload: OAuth2SignInRequest = authorize(&test_client, &stub, claims).await;
+    let response: Response = sign_in(&test_client, &payload).await;
+    let token: SignInResponse = serde_json::from_value(
+      test_client
+        .get_body(response)
+        .await
+        .get("data")
+        .unwrap()
+        .clone(),
+    )
+    .unwrap();
+
+    test_client
+      .call_request(
+        |token| IdentityClient::unlink(&PROVIDER, token),
+        token.access_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "cannot unlink the last sign-in method",
+            "code": 400
+        }),
+      )
+      .await;
+  }
+}
@@ -0,0 +1 @@
+pub(crate) mod identity_controller;
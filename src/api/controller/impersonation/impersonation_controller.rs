@@ -0,0 +1,248 @@
+use crate::api::client::impersonation::response::impersonation_response::ImpersonationResponse;
+use crate::api::manager::impersonation::impersonation_manager::ImpersonationManager;
+use crate::api::state::impersonation::impersonation_state::ImpersonationState;
+use crate::core::error::error::AppError;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::authorization::Authorization;
+use axum::extract::{Path, State};
+use axum::routing::post;
+use axum::{response::IntoResponse, Router};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ImpersonationController;
+
+impl ImpersonationController {
+  pub(crate) fn configure(state: Arc<ImpersonationState>) -> Router {
+    Router::new()
+      .nest(
+        "/admin/users",
+        Router::new().route(
+          "/:id/impersonate",
+          post(Self::impersonate).layer(Authorization::require_permission("users:impersonate")),
+        ),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl ImpersonationController {
+  async fn impersonate(
+    State(state): State<Arc<ImpersonationState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let impersonation: ImpersonationResponse = state.manager.impersonate(token_data, id).await?;
+    Ok(SuccessResponse {
+      data: impersonation.into(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::api_keys::api_key_client::ApiKeyClient;
+  use crate::api::client::api_keys::request::api_key_request::ApiKeyCreateRequest;
+  use crate::api::client::impersonation::impersonation_client::ImpersonationClient;
+  use crate::api::client::users::response::user_response::UserResponse;
+  use crate::api::client::users::user_client::UserClient;
+  use crate::api::controller::api_keys::api_key_controller::ApiKeyController;
+  use crate::api::controller::users::user_controller::UserController;
+  use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::state::api_keys::api_key_state::ApiKeyState;
+  use crate::api::state::roles::role_state::RoleState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::{Claims, JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::body::Body;
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use serde_json::{json, Value};
+
+  async fn init_setup() -> (Router, PostgresDatabase) {
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let jwt_manager: JwtManager = JwtManager::new(config.jwt);
+    let state: Arc<ImpersonationState> =
+      Arc::new(ImpersonationState::new(&db_pool, jwt_manager).await);
+    let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
+    let api_key_state: Arc<ApiKeyState> = Arc::new(ApiKeyState::new(&db_pool).await);
+    let app: Router = Router::new()
+      .merge(ImpersonationController::configure(state.clone()))
+      .merge(UserController::configure(user_state.clone()))
+      .merge(ApiKeyController::configure(api_key_state))
+      .layer(Extension(Arc::new(RoleState::new(&db_pool).await)))
+      .layer(Extension(state))
+      .layer(Extension(user_state));
+    (app, db_pool)
+  }
+
+  fn claims_of(access_token: &str) -> Claims {
+    let config: AppConfig = APP_CONFIG.clone();
+    JwtManager::new(config.jwt)
+      .validate_jwt(access_token, TokenType::AccessToken)
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_impersonate_success() {
+    let (app, db_pool) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+    let admin_id: Uuid = claims_of(&access_token).sub;
+    let request: Request<Body> = ImpersonationClient::impersonate(user.id, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let impersonation: ImpersonationResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(impersonation.user_id, user.id);
+    assert!(impersonation.expires_at.is_some());
+
+    let claims: Claims = claims_of(&impersonation.access_token);
+    assert_eq!(claims.sub, user.id);
+    assert_eq!(claims.act.map(|act| act.sub), Some(admin_id));
+
+    let response: Response = test_client
+      .get_response(UserClient::me(impersonation.access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    let me: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(me.id, user.id);
+
+    let audits: Vec<(Uuid, Uuid)> =
+      sqlx::query_as("SELECT user_id, jti FROM impersonation_audits WHERE actor_id = $1")
+        .bind(admin_id)
+        .fetch_all(db_pool.primary())
+        .await
+        .unwrap();
+    assert!(audits.contains(&(user.id, claims.jti)));
+
+    let actions: Vec<(Uuid, Uuid, String, String)> = sqlx::query_as(
+      "SELECT actor_id, user_id, method, path FROM impersonation_actions WHERE jti = $1",
+    )
+    .bind(claims.jti)
+    .fetch_all(db_pool.primary())
+    .await
+    .unwrap();
+    assert_eq!(
+      actions,
+      vec![(
+        admin_id,
+        user.id,
+        "GET".to_string(),
+        "/users/me".to_string()
+      )]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_impersonation_cannot_create_api_key() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(ImpersonationClient::impersonate(
+        user.id,
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let impersonation: ImpersonationResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+
+    let payload = ApiKeyCreateRequest {
+      name: "ci".to_string(),
+      scopes: vec![ApiKeyScope::Read],
+      expires_at: None,
+    };
+    test_client
+      .call_permission_denied_request(
+        |token| ApiKeyClient::create(&payload, token),
+        impersonation.access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_impersonate_admin_not_allowed() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+    let admin_id: Uuid = claims_of(&access_token).sub;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| ImpersonationClient::impersonate(admin_id, token),
+        access_token,
+        json!({
+            "error": "impersonation not allowed",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_impersonate_user_not_found() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| ImpersonationClient::impersonate(Uuid::new_v4(), token),
+        access_token,
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "user not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_impersonate_permission_denied() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| ImpersonationClient::impersonate(user.id, token),
+        access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+}
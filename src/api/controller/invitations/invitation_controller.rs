@@ -0,0 +1,415 @@
+use crate::api::client::invitations::request::invitation_request::InvitationCreateRequest;
+use crate::api::manager::invitations::invitation_manager::InvitationManager;
+use crate::api::state::invitations::invitation_state::InvitationState;
+use crate::core::error::error::AppError;
+use crate::core::response::constant::BaseMessage;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::authorization::Authorization;
+use axum::extract::{Path, State};
+use axum::routing::{delete, get};
+use axum::{response::IntoResponse, Json, Router};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct InvitationController;
+
+impl InvitationController {
+  pub(crate) fn configure(state: Arc<InvitationState>) -> Router {
+    Router::new()
+      .nest(
+        "/users/invitations",
+        Router::new()
+          .route(
+            "/",
+            get(Self::all)
+              .post(Self::create)
+              .layer(Authorization::require_permission("users:invite")),
+          )
+          .route(
+            "/:id",
+            delete(Self::revoke).layer(Authorization::require_permission("users:invite")),
+          ),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl InvitationController {
+  async fn all(
+    State(state): State<Arc<InvitationState>>,
+    AccessTokenAuth(_): AccessTokenAuth,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.all().await?.into(),
+    })
+  }
+
+  async fn create(
+    State(state): State<Arc<InvitationState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Json(payload): Json<InvitationCreateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.create(token_data.sub, payload).await?.into(),
+    })
+  }
+
+  async fn revoke(
+    State(state): State<Arc<InvitationState>>,
+    AccessTokenAuth(_): AccessTokenAuth,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.revoke(id).await?;
+    Ok(SuccessResponse {
+      data: BaseMessage::Success.into(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::auth::auth_client::AuthClient;
+  use crate::api::client::auth::request::auth_request::AcceptInviteRequest;
+  use crate::api::client::auth::response::auth_response::SignInResponse;
+  use crate::api::client::invitations::invitation_client::InvitationClient;
+  use crate::api::client::invitations::response::invitation_response::InvitationResponse;
+  use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::state::roles::role_state::RoleState;
+  use crate::api::test::test_context::{
+    AuthSetup, TestClient, ADMIN_DEFAULT_EMAIL, USER_DEFAULT_EMAIL,
+  };
+  use crate::core::security::jwt_manager::{
+    Claims, InvitationClaims, JwtManager, MagicLinkClaims, TokenPurpose, TokenType,
+  };
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::body::Body;
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use chrono::Duration;
+  use faker_rand::en_us::internet::Email;
+  use faker_rand::fr_fr::names::FullName;
+  use serde_json::{json, Value};
+
+  async fn init_setup() -> (Router, PostgresDatabase) {
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let jwt_manager: JwtManager = JwtManager::new(config.jwt);
+    let state: Arc<InvitationState> =
+      Arc::new(InvitationState::new(&db_pool, jwt_manager, &config.aws, &config.invitation).await);
+    let (auth_app, _, user_state) = AuthSetup::init().await;
+    let app: Router = Router::new()
+      .merge(InvitationController::configure(state))
+      .merge(auth_app)
+      .layer(Extension(user_state))
+      .layer(Extension(Arc::new(RoleState::new(&db_pool).await)));
+    (app, db_pool)
+  }
+
+  async fn create_invitation(
+    test_client: &TestClient,
+    email: String,
+    role: UserRole,
+  ) -> InvitationResponse {
+    let payload = InvitationCreateRequest { email, role };
+    let response: Response = test_client
+      .get_response(InvitationClient::create(
+        &payload,
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    serde_json::from_value(body.get("data").unwrap().clone()).unwrap()
+  }
+
+  fn invitation_token(invitation: &InvitationResponse) -> String {
+    JwtManager::new(APP_CONFIG.jwt.clone()).sign(&InvitationClaims {
+      jti: invitation.id,
+      typ: TokenPurpose::Invitation,
+      email: invitation.email.clone(),
+      exp: invitation.expires_at.timestamp() as usize,
+    })
+  }
+
+  fn accept_payload(token: String) -> AcceptInviteRequest {
+    AcceptInviteRequest {
+      token,
+      password: rand::random::<FullName>().to_string(),
+      name: Some(rand::random::<FullName>().to_string()),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_invitation_create_and_list() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let email: String = rand::random::<Email>().to_string();
+    let invitation: InvitationResponse =
+      create_invitation(&test_client, email.to_uppercase(), UserRole::Moderator).await;
+    assert_eq!(invitation.email, email.to_lowercase());
+    assert_eq!(invitation.role, UserRole::Moderator);
+    assert!(invitation.invited_by.is_some());
+
+    let expected: i64 = APP_CONFIG.invitation.expiration_seconds;
+    let lifetime: Duration = invitation.expires_at - invitation.created_at.unwrap();
+    assert!((lifetime.num_seconds() - expected).abs() <= 5);
+
+    let response: Response = test_client
+      .get_response(InvitationClient::all(AuthSetup::get_admin_access_token()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    let invitations: Vec<InvitationResponse> =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(invitations
+      .iter()
+      .any(|pending| pending.id == invitation.id));
+  }
+
+  #[tokio::test]
+  async fn test_invitation_create_permission_denied() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let payload = InvitationCreateRequest {
+      email: rand::random::<Email>().to_string(),
+      role: UserRole::User,
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| InvitationClient::create(&payload, token),
+        AuthSetup::get_user_access_token(),
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_invitation_create_existing_user() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let payload = InvitationCreateRequest {
+      email: ADMIN_DEFAULT_EMAIL.clone(),
+      role: UserRole::User,
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| InvitationClient::create(&payload, token),
+        AuthSetup::get_admin_access_token(),
+        StatusCode::CONFLICT,
+        json!({
+            "error": "existing email",
+            "code": 409
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_invitation_create_existing_invitation() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let email: String = rand::random::<Email>().to_string();
+    create_invitation(&test_client, email.clone(), UserRole::User).await;
+
+    let payload = InvitationCreateRequest {
+      email,
+      role: UserRole::User,
+    };
+    test_client
+      .call_request(
+        |token| InvitationClient::create(&payload, token),
+        AuthSetup::get_admin_access_token(),
+        StatusCode::CONFLICT,
+        json!({
+            "error": "existing invitation",
+            "code": 409
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_invitation_accept_creates_verified_user() {
+    let (app, db_pool) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let invitation: InvitationResponse = create_invitation(
+      &test_client,
+      rand::random::<Email>().to_string(),
+      UserRole::Moderator,
+    )
+    .await;
+    let payload: AcceptInviteRequest = accept_payload(invitation_token(&invitation));
+    let response: Response = test_client
+      .get_response(AuthClient::accept_invite(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let token: SignInResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(token.refresh_token.is_some());
+    let claims: Claims = JwtManager::new(APP_CONFIG.jwt.clone())
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    assert_eq!(claims.role, UserRole::Moderator);
+
+    let user: UserEntity = RepositoryImpl::new(db_pool)
+      .by_email(invitation.email.clone())
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(user.id, claims.sub);
+    assert_eq!(user.role, UserRole::Moderator);
+    assert_eq!(user.status.as_str(), UserStatus::Verified.as_str());
+
+    let response: Response = test_client
+      .get_response(InvitationClient::all(AuthSetup::get_admin_access_token()))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let invitations: Vec<InvitationResponse> =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(!invitations
+      .iter()
+      .any(|pending| pending.id == invitation.id));
+  }
+
+  #[tokio::test]
+  async fn test_invitation_accept_single_use() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let invitation: InvitationResponse = create_invitation(
+      &test_client,
+      rand::random::<Email>().to_string(),
+      UserRole::User,
+    )
+    .await;
+    let payload: AcceptInviteRequest = accept_payload(invitation_token(&invitation));
+    let response: Response = test_client
+      .get_response(AuthClient::accept_invite(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(AuthClient::accept_invite(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+  }
+
+  #[tokio::test]
+  async fn test_invitation_revoke() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let invitation: InvitationResponse = create_invitation(
+      &test_client,
+      rand::random::<Email>().to_string(),
+      UserRole::User,
+    )
+    .await;
+    test_client
+      .call_request(
+        |token| InvitationClient::revoke(invitation.id, token),
+        AuthSetup::get_admin_access_token(),
+        StatusCode::OK,
+        json!({ "data": "success" }),
+      )
+      .await;
+    test_client
+      .call_request(
+        |token| InvitationClient::revoke(invitation.id, token),
+        AuthSetup::get_admin_access_token(),
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "invitation not found",
+            "code": 404
+        }),
+      )
+      .await;
+
+    let payload: AcceptInviteRequest = accept_payload(invitation_token(&invitation));
+    let response: Response = test_client
+      .get_response(AuthClient::accept_invite(&payload))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid invitation",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_invitation_accept_invalid_token() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let expired = InvitationResponse {
+      id: Uuid::new_v4(),
+      email: USER_DEFAULT_EMAIL.clone(),
+      role: UserRole::Admin,
+      invited_by: None,
+      expires_at: chrono::Utc::now() - Duration::hours(1),
+      created_at: None,
+    };
+    for token in [invitation_token(&expired), "invalid".to_string()] {
+      let request: Request<Body> = AuthClient::accept_invite(&accept_payload(token));
+      let response: Response = test_client.get_response(request).await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_invitation_accept_rejects_magic_link_token() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let invitation: InvitationResponse = create_invitation(
+      &test_client,
+      rand::random::<Email>().to_string(),
+      UserRole::User,
+    )
+    .await;
+    let token: String = JwtManager::new(APP_CONFIG.jwt.clone()).sign(&MagicLinkClaims {
+      jti: invitation.id,
+      typ: TokenPurpose::MagicLink,
+      email: invitation.email.clone(),
+      exp: invitation.expires_at.timestamp() as usize,
+    });
+    let request: Request<Body> = AuthClient::accept_invite(&accept_payload(token));
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let request: Request<Body> =
+      AuthClient::accept_invite(&accept_payload(invitation_token(&invitation)));
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+}
@@ -0,0 +1,960 @@
+use crate::api::client::oauth::request::oauth_request::{
+  OAuthAuthorizeRequest, OAuthClientCreateRequest, OAuthTokenGrantRequest, OAuthTokenRequest,
+};
+use crate::api::client::oauth::response::oauth_response::{
+  OAuthAuthorizeResponse, OAuthClientResponse, OAuthIntrospectResponse, OAuthTokenResponse,
+  OidcUserInfoResponse,
+};
+use crate::api::manager::oauth::oauth_manager::OAuthManager;
+use crate::api::state::oauth::oauth_state::OAuthState;
+use crate::core::error::error::AppError;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::{AccessTokenAuth, ClientBasicAuth, UserInfoAuth};
+use crate::core::security::authorization::Authorization;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{response::IntoResponse, Form, Json, Router};
+use std::sync::Arc;
+
+pub struct OAuthController;
+
+impl OAuthController {
+  pub(crate) fn configure(state: Arc<OAuthState>) -> Router {
+    Router::new()
+      .nest(
+        "/oauth",
+        Router::new()
+          .route(
+            "/clients",
+            post(Self::create_client)
+              .layer(Authorization::require_permission("oauth_clients:write")),
+          )
+          .route("/token", post(Self::token))
+          .route("/introspect", post(Self::introspect))
+          .route("/revoke", post(Self::revoke))
+          .route("/authorize", get(Self::authorize))
+          .route("/userinfo", get(Self::user_info))
+          .route("/jwks", get(Self::jwks)),
+      )
+      .route("/.well-known/openid-configuration", get(Self::discovery))
+      .with_state(state.clone())
+  }
+}
+
+impl OAuthController {
+  async fn create_client(
+    State(state): State<Arc<OAuthState>>,
+    AccessTokenAuth(_): AccessTokenAuth,
+    Json(payload): Json<OAuthClientCreateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let client: OAuthClientResponse = state.manager.create_client(payload).await?;
+    Ok(
+      SuccessResponse {
+        data: client.into(),
+      }
+      .into_response(),
+    )
+  }
+
+  async fn token(
+    State(state): State<Arc<OAuthState>>,
+    credentials: ClientBasicAuth,
+    Form(payload): Form<OAuthTokenGrantRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token: OAuthTokenResponse = state.manager.token(credentials, payload).await?;
+    Ok(Json(token).into_response())
+  }
+
+  async fn introspect(
+    State(state): State<Arc<OAuthState>>,
+    credentials: ClientBasicAuth,
+    Form(payload): Form<OAuthTokenRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let introspection: OAuthIntrospectResponse =
+      state.manager.introspect(credentials, payload).await?;
+    Ok(Json(introspection).into_response())
+  }
+
+  async fn revoke(
+    State(state): State<Arc<OAuthState>>,
+    credentials: ClientBasicAuth,
+    Form(payload): Form<OAuthTokenRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.revoke(credentials, payload).await?;
+    Ok(StatusCode::OK.into_response())
+  }
+
+  async fn authorize(
+    State(state): State<Arc<OAuthState>>,
+    AccessTokenAuth(claims): AccessTokenAuth,
+    Query(payload): Query<OAuthAuthorizeRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let authorization: OAuthAuthorizeResponse = state.manager.authorize(claims, payload).await?;
+    Ok(
+      SuccessResponse {
+        data: authorization.into(),
+      }
+      .into_response(),
+    )
+  }
+
+  async fn user_info(
+    State(state): State<Arc<OAuthState>>,
+    UserInfoAuth(claims): UserInfoAuth,
+  ) -> Result<impl IntoResponse, AppError> {
+    let user_info: OidcUserInfoResponse = state.manager.user_info(claims).await?;
+    Ok(Json(user_info).into_response())
+  }
+
+  async fn jwks(State(state): State<Arc<OAuthState>>) -> Result<impl IntoResponse, AppError> {
+    Ok(Json(state.manager.jwks()).into_response())
+  }
+
+  async fn discovery(State(state): State<Arc<OAuthState>>) -> Result<impl IntoResponse, AppError> {
+    Ok(Json(state.manager.discovery()).into_response())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::oauth::oauth_client::OAuthClient;
+  use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::state::roles::role_state::RoleState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::{Claims, IdTokenClaims, JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::body::Body;
+  use axum::http::Request;
+  use axum::response::Response;
+  use axum::Extension;
+  use faker_rand::fr_fr::names::FullName;
+  use jsonwebtoken::jwk::JwkSet;
+  use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+  use oauth2::{PkceCodeChallenge, PkceCodeVerifier};
+  use reqwest::Url;
+  use serde_json::{json, Value};
+  use uuid::Uuid;
+
+  const REDIRECT_URI: &str = "https://app.example.com/callback";
+
+  async fn init_setup() -> (Router, Arc<OAuthState>) {
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+    let jwt_manager: JwtManager = JwtManager::new(config.jwt);
+    let state: Arc<OAuthState> =
+      Arc::new(OAuthState::new(&db_pool, jwt_manager, &config.oidc_provider).await);
+    let role_state: Arc<RoleState> = Arc::new(RoleState::new(&db_pool).await);
+    let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
+    let app: Router = OAuthController::configure(state.clone())
+      .layer(Extension(role_state))
+      .layer(Extension(user_state));
+    (app, state)
+  }
+
+  async fn init_client(state: &Arc<OAuthState>) -> OAuthClientResponse {
+    init_client_with_scopes(state, vec![ApiKeyScope::Read]).await
+  }
+
+  async fn init_client_with_scopes(
+    state: &Arc<OAuthState>,
+    scopes: Vec<ApiKeyScope>,
+  ) -> OAuthClientResponse {
+    state
+      .manager
+      .create_client(OAuthClientCreateRequest {
+        name: rand::random::<FullName>().to_string(),
+        scopes,
+        redirect_uris: vec![],
+      })
+      .await
+      .unwrap()
+  }
+
+  async fn init_service_token(client: &OAuthClientResponse, scope: Option<&str>) -> String {
+    let (app, _) = init_setup().await;
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "client_credentials".to_string(),
+      scope: scope.map(str::to_string),
+      code: None,
+      redirect_uri: None,
+      code_verifier: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::token(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    let body: Value = test_client.get_body(response).await;
+    let token: OAuthTokenResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+    token.access_token
+  }
+
+  async fn init_oidc_client(state: &Arc<OAuthState>) -> OAuthClientResponse {
+    state
+      .manager
+      .create_client(OAuthClientCreateRequest {
+        name: rand::random::<FullName>().to_string(),
+        scopes: vec![],
+        redirect_uris: vec![REDIRECT_URI.to_string()],
+      })
+      .await
+      .unwrap()
+  }
+
+  fn authorize_payload(
+    client: &OAuthClientResponse,
+    challenge: &PkceCodeChallenge,
+  ) -> OAuthAuthorizeRequest {
+    OAuthAuthorizeRequest {
+      response_type: "code".to_string(),
+      client_id: client.client_id.clone(),
+      redirect_uri: REDIRECT_URI.to_string(),
+      scope: "openid email profile".to_string(),
+      state: Some("state-value".to_string()),
+      nonce: Some("nonce-value".to_string()),
+      code_challenge: challenge.as_str().to_string(),
+      code_challenge_method: Some("S256".to_string()),
+    }
+  }
+
+  /// Runs `/oauth/authorize` as the user and returns the code from the redirect.
+  async fn init_code(
+    app: &Router,
+    client: &OAuthClientResponse,
+    access_token: String,
+  ) -> (String, PkceCodeVerifier) {
+    let (challenge, verifier) = PkceCodeChallenge::new_random_sha256();
+    let request: Request<Body> =
+      OAuthClient::authorize(&authorize_payload(client, &challenge), access_token);
+
+    let test_client: TestClient = TestClient::new(app.clone());
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let authorization: OAuthAuthorizeResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    let redirect_uri: Url = Url::parse(&authorization.redirect_uri).unwrap();
+    assert!(authorization.redirect_uri.starts_with(REDIRECT_URI));
+    let param = |name: &str| {
+      redirect_uri
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.to_string())
+    };
+    assert_eq!(param("state"), Some("state-value".to_string()));
+    (param("code").unwrap(), verifier)
+  }
+
+  fn exchange_code(client: &OAuthClientResponse, code: &str, code_verifier: &str) -> Request<Body> {
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "authorization_code".to_string(),
+      scope: None,
+      code: Some(code.to_string()),
+      redirect_uri: Some(REDIRECT_URI.to_string()),
+      code_verifier: Some(code_verifier.to_string()),
+    };
+    OAuthClient::token(&payload, &client.client_id, &client.client_secret)
+  }
+
+  #[tokio::test]
+  async fn test_oauth_create_client_success() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let payload = OAuthClientCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![ApiKeyScope::Read],
+      redirect_uris: vec![],
+    };
+
+    let access_token: String = AuthSetup::get_admin_access_token();
+    let request: Request<Body> = OAuthClient::create_client(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+
+    if let Some(data) = body.get("data") {
+      let data_value: OAuthClientResponse =
+        serde_json::from_value(data.clone()).expect("Failed to deserialize data");
+      assert!(!data_value.client_id.is_empty());
+      assert!(!data_value.client_secret.is_empty());
+      assert_eq!(data_value.name, payload.name);
+    } else {
+      panic!("Expected 'data' field not found in response body");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_oauth_create_client_permission_denied() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let access_token: String = AuthSetup::get_user_access_token();
+    let payload = OAuthClientCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![ApiKeyScope::Read],
+      redirect_uris: vec![],
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| OAuthClient::create_client(&payload, token),
+        access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_active_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OAuthTokenRequest {
+      token: access_token,
+      token_type_hint: Some("access_token".to_string()),
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let introspection: OAuthIntrospectResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+
+    assert!(introspection.active);
+    assert!(introspection.sub.is_some());
+    assert!(introspection.jti.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_refresh_token_with_wrong_hint() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let (_, _, refresh_token, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OAuthTokenRequest {
+      token: refresh_token,
+      token_type_hint: Some("access_token".to_string()),
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body.get("active"), Some(&json!(true)));
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_unknown_hint() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let (_, _, refresh_token, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OAuthTokenRequest {
+      token: refresh_token,
+      token_type_hint: Some("id_token".to_string()),
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body.get("active"), Some(&json!(true)));
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_invalid_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenRequest {
+      token: "fake_token".to_string(),
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body, json!({ "active": false }));
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_invalid_client() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenRequest {
+      token: "fake_token".to_string(),
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, "fake_secret");
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid client",
+          "code": 401
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oauth_revoke_ignores_user_session() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let (_, access_token, refresh_token, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OAuthTokenRequest {
+      token: refresh_token,
+      token_type_hint: Some("refresh_token".to_string()),
+    };
+    let request: Request<Body> =
+      OAuthClient::revoke(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let payload = OAuthTokenRequest {
+      token: access_token,
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+    let response: Response = test_client.get_response(request).await;
+    let body: Value = test_client.get_body(response).await;
+
+    assert_eq!(body.get("active"), Some(&json!(true)));
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_impersonation_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let (admin, _, _, _) = AuthSetup::init_user(UserRole::Admin).await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (access_token, _) = JwtManager::new(APP_CONFIG.jwt.clone()).generate_impersonation_jwt(
+      Uuid::new_v4(),
+      user.id,
+      user.role,
+      admin.id,
+    );
+
+    let payload = OAuthTokenRequest {
+      token: access_token,
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    let body: Value = test_client.get_body(response).await;
+    let introspection: OAuthIntrospectResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+    assert!(introspection.active);
+    assert_eq!(introspection.sub, Some(user.id));
+  }
+
+  #[tokio::test]
+  async fn test_oauth_revoke_invalid_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenRequest {
+      token: "fake_token".to_string(),
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::revoke(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_oauth_token_client_credentials_success() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse =
+      init_client_with_scopes(&state, vec![ApiKeyScope::Read, ApiKeyScope::Write]).await;
+
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "client_credentials".to_string(),
+      scope: Some("read".to_string()),
+      code: None,
+      redirect_uri: None,
+      code_verifier: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::token(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let token: OAuthTokenResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+    assert_eq!(token.token_type, "Bearer");
+    assert_eq!(token.scope, "read");
+
+    let config: AppConfig = APP_CONFIG.clone();
+    let claims: Claims = JwtManager::new(config.jwt)
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    assert_eq!(claims.client_id, Some(client.client_id));
+    assert_eq!(claims.role, UserRole::User);
+  }
+
+  #[tokio::test]
+  async fn test_oauth_token_invalid_scope() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "client_credentials".to_string(),
+      scope: Some("read admin".to_string()),
+      code: None,
+      redirect_uri: None,
+      code_verifier: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::token(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid scope",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oauth_token_unsupported_grant_type() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "password".to_string(),
+      scope: None,
+      code: None,
+      redirect_uri: None,
+      code_verifier: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::token(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "unsupported grant type",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oauth_token_invalid_client() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+
+    let payload = OAuthTokenGrantRequest {
+      grant_type: "client_credentials".to_string(),
+      scope: None,
+      code: None,
+      redirect_uri: None,
+      code_verifier: None,
+    };
+    let request: Request<Body> = OAuthClient::token(&payload, &client.client_id, "fake_secret");
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+  }
+
+  #[tokio::test]
+  async fn test_oauth_service_token_calls_admin_endpoint() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client_with_scopes(
+      &state,
+      vec![ApiKeyScope::Read, ApiKeyScope::Write, ApiKeyScope::Admin],
+    )
+    .await;
+    let access_token: String = init_service_token(&client, None).await;
+
+    let payload = OAuthClientCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![],
+      redirect_uris: vec![],
+    };
+    let request: Request<Body> = OAuthClient::create_client(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_oauth_service_token_without_admin_scope_denied() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse =
+      init_client_with_scopes(&state, vec![ApiKeyScope::Write, ApiKeyScope::Admin]).await;
+    let access_token: String = init_service_token(&client, Some("write")).await;
+
+    let payload = OAuthClientCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+      scopes: vec![],
+      redirect_uris: vec![],
+    };
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| OAuthClient::create_client(&payload, token),
+        access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_oauth_introspect_service_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let access_token: String = init_service_token(&client, None).await;
+
+    let payload = OAuthTokenRequest {
+      token: access_token,
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let introspection: OAuthIntrospectResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+
+    assert!(introspection.active);
+    assert_eq!(introspection.client_id, Some(client.client_id));
+    assert_eq!(introspection.scope, Some("read".to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_authorization_code_flow() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (user, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (code, verifier) = init_code(&app, &client, access_token).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(exchange_code(&client, &code, verifier.secret()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let token: OAuthTokenResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+    assert_eq!(token.scope, "openid email profile");
+
+    let response: Response = test_client.get_response(OAuthClient::jwks()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let jwks: JwkSet = serde_json::from_value(test_client.get_body(response).await).unwrap();
+
+    let id_token: String = token.id_token.expect("Expected an id_token");
+    let kid: String = decode_header(&id_token).unwrap().kid.unwrap();
+    let key: DecodingKey = DecodingKey::from_jwk(jwks.find(&kid).unwrap()).unwrap();
+    let mut validation: Validation = Validation::new(Algorithm::RS512);
+    validation.set_audience(&[&client.client_id]);
+    validation.set_issuer(&[&APP_CONFIG.oidc_provider.issuer]);
+    let claims: IdTokenClaims = decode::<IdTokenClaims>(&id_token, &key, &validation)
+      .unwrap()
+      .claims;
+    assert_eq!(claims.sub, user.id);
+    assert_eq!(claims.email, user.email);
+    assert_eq!(claims.name, user.name);
+    assert_eq!(claims.nonce, Some("nonce-value".to_string()));
+    assert!(!claims.email_verified);
+
+    let response: Response = test_client
+      .get_response(OAuthClient::user_info(token.access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "sub": user.id,
+          "email": user.email,
+          "email_verified": false,
+          "name": user.name
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oidc_code_token_limited_to_user_info() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (code, verifier) = init_code(&app, &client, access_token).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(exchange_code(&client, &code, verifier.secret()))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let token: OAuthTokenResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+
+    let payload = OAuthTokenRequest {
+      token: token.access_token.clone(),
+      token_type_hint: None,
+    };
+    let request: Request<Body> =
+      OAuthClient::introspect(&payload, &client.client_id, &client.client_secret);
+    let response: Response = test_client.get_response(request).await;
+    let body: Value = test_client.get_body(response).await;
+    let introspection: OAuthIntrospectResponse =
+      serde_json::from_value(body).expect("Failed to deserialize body");
+    assert!(introspection.active);
+    assert_eq!(introspection.client_id, Some(client.client_id.clone()));
+
+    let (challenge, _) = PkceCodeChallenge::new_random_sha256();
+    test_client
+      .call_permission_denied_request(
+        |token| OAuthClient::authorize(&authorize_payload(&client, &challenge), token),
+        token.access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_oidc_user_info_rejects_service_token() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_client(&state).await;
+    let access_token: String = init_service_token(&client, None).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        OAuthClient::user_info,
+        access_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_oidc_token_invalid_code_verifier() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (code, _) = init_code(&app, &client, access_token).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let (_, verifier) = PkceCodeChallenge::new_random_sha256();
+    let response: Response = test_client
+      .get_response(exchange_code(&client, &code, verifier.secret()))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid grant",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oidc_token_code_reused() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (code, verifier) = init_code(&app, &client, access_token).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(exchange_code(&client, &code, verifier.secret()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(exchange_code(&client, &code, verifier.secret()))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+  }
+
+  #[tokio::test]
+  async fn test_oidc_token_code_of_another_client() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let other_client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (code, verifier) = init_code(&app, &client, access_token).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(exchange_code(&other_client, &code, verifier.secret()))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+  }
+
+  #[tokio::test]
+  async fn test_oidc_authorize_invalid_redirect_uri() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let (challenge, _) = PkceCodeChallenge::new_random_sha256();
+    let payload = OAuthAuthorizeRequest {
+      redirect_uri: "https://evil.example.com/callback".to_string(),
+      ..authorize_payload(&client, &challenge)
+    };
+    let request: Request<Body> = OAuthClient::authorize(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid redirect uri",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oidc_authorize_requires_pkce() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let (challenge, _) = PkceCodeChallenge::new_random_sha256();
+    let payload = OAuthAuthorizeRequest {
+      code_challenge_method: None,
+      ..authorize_payload(&client, &challenge)
+    };
+    let request: Request<Body> = OAuthClient::authorize(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "invalid authorization request",
+          "code": 400
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_oidc_authorize_requires_openid_scope() {
+    let (app, state) = init_setup().await;
+    let client: OAuthClientResponse = init_oidc_client(&state).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let (challenge, _) = PkceCodeChallenge::new_random_sha256();
+    let payload = OAuthAuthorizeRequest {
+      scope: "email".to_string(),
+      ..authorize_payload(&client, &challenge)
+    };
+    let request: Request<Body> = OAuthClient::authorize(&payload, access_token);
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+  }
+
+  #[tokio::test]
+  async fn test_oidc_discovery() {
+    let (app, _) = init_setup().await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(OAuthClient::discovery()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let issuer: &str = APP_CONFIG.oidc_provider.issuer.as_str();
+    assert_eq!(body.get("issuer"), Some(&json!(issuer)));
+    assert_eq!(
+      body.get("jwks_uri"),
+      Some(&json!(format!("{}/oauth/jwks", issuer)))
+    );
+    assert_eq!(
+      body.get("code_challenge_methods_supported"),
+      Some(&json!(["S256"]))
+    );
+  }
+}
@@ -0,0 +1,646 @@
+use crate::api::client::organizations::request::organization_request::{
+  OrganizationCreateRequest, OrganizationInviteRequest, OrganizationTransferRequest,
+};
+use crate::api::manager::organizations::organization_manager::OrganizationManager;
+use crate::api::state::organizations::organization_state::OrganizationState;
+use crate::core::error::error::AppError;
+use crate::core::response::constant::BaseMessage;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::organization::OrgContext;
+use axum::extract::{Path, State};
+use axum::routing::{delete, get, post};
+use axum::{response::IntoResponse, Json, Router};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct OrganizationController;
+
+impl OrganizationController {
+  pub(crate) fn configure(state: Arc<OrganizationState>) -> Router {
+    Router::new()
+      .nest(
+        "/organizations",
+        Router::new()
+          .route("/", get(Self::all).post(Self::create))
+          .route("/:org_id", get(Self::get))
+          .route("/:org_id/members", get(Self::members).post(Self::invite))
+          .route("/:org_id/members/:user_id", delete(Self::remove))
+          .route("/:org_id/accept", post(Self::accept))
+          .route("/:org_id/transfer", post(Self::transfer)),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl OrganizationController {
+  async fn all(
+    State(state): State<Arc<OrganizationState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.all(token_data.sub).await?.into(),
+    })
+  }
+
+  async fn create(
+    State(state): State<Arc<OrganizationState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Json(payload): Json<OrganizationCreateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.create(token_data.sub, payload).await?.into(),
+    })
+  }
+
+  async fn get(
+    State(state): State<Arc<OrganizationState>>,
+    context: OrgContext,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.get(context).await?.into(),
+    })
+  }
+
+  async fn members(
+    State(state): State<Arc<OrganizationState>>,
+    context: OrgContext,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.members(context).await?.into(),
+    })
+  }
+
+  async fn invite(
+    State(state): State<Arc<OrganizationState>>,
+    context: OrgContext,
+    Json(payload): Json<OrganizationInviteRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.invite(context, payload).await?.into(),
+    })
+  }
+
+  /// Invited users have not joined yet, so this is the one route without an `OrgContext`.
+  async fn accept(
+    State(state): State<Arc<OrganizationState>>,
+    AccessTokenAuth(token_data): AccessTokenAuth,
+    Path(organization_id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state
+        .manager
+        .accept(token_data.sub, organization_id)
+        .await?
+        .into(),
+    })
+  }
+
+  async fn remove(
+    State(state): State<Arc<OrganizationState>>,
+    context: OrgContext,
+    Path((_, user_id)): Path<(Uuid, Uuid)>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.remove(context, user_id).await?;
+    Ok(SuccessResponse {
+      data: BaseMessage::Success.into(),
+    })
+  }
+
+  async fn transfer(
+    State(state): State<Arc<OrganizationState>>,
+    context: OrgContext,
+    Json(payload): Json<OrganizationTransferRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.transfer(context, payload).await?;
+    Ok(SuccessResponse {
+      data: BaseMessage::Success.into(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::organizations::organization_client::OrganizationClient;
+  use crate::api::client::organizations::response::organization_response::{
+    OrganizationMemberResponse, OrganizationResponse,
+  };
+  use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::state::api_keys::api_key_state::ApiKeyState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::organization::ORGANIZATION_ID_HEADER;
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use axum::body::Body;
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use faker_rand::fr_fr::names::FullName;
+  use http::header::AUTHORIZATION;
+  use serde_json::{json, Value};
+
+  async fn init_setup() -> (Router, Arc<OrganizationState>) {
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(APP_CONFIG.postgres.clone())
+      .await
+      .unwrap();
+    let state: Arc<OrganizationState> = Arc::new(OrganizationState::new(&db_pool).await);
+    let app: Router = Router::new()
+      .merge(OrganizationController::configure(state.clone()))
+      .route(
+        "/scoped",
+        get(|context: OrgContext| async move { Json(context.transaction.organization_id()) }),
+      )
+      .layer(Extension(state.clone()))
+      .layer(Extension(Arc::new(ApiKeyState::new(&db_pool).await)))
+      .layer(Extension(Arc::new(
+        UserState::new(&db_pool, &APP_CONFIG.aws).await,
+      )));
+    (app, state)
+  }
+
+  async fn init_organization(state: &Arc<OrganizationState>, owner: &UserEntity) -> Uuid {
+    let organization: OrganizationResponse = state
+      .manager
+      .create(
+        owner.id,
+        OrganizationCreateRequest {
+          name: rand::random::<FullName>().to_string(),
+        },
+      )
+      .await
+      .unwrap();
+    organization.id
+  }
+
+  /// A fresh context per manager call, as each one commits the transaction it carries.
+  async fn context_of(
+    state: &Arc<OrganizationState>,
+    user: &UserEntity,
+    organization_id: Uuid,
+  ) -> OrgContext {
+    state
+      .manager
+      .context(user.id, organization_id)
+      .await
+      .unwrap()
+  }
+
+  async fn init_member(
+    state: &Arc<OrganizationState>,
+    owner: &UserEntity,
+    organization_id: Uuid,
+    role: OrganizationRole,
+  ) -> (UserEntity, String) {
+    let (user, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    state
+      .manager
+      .invite(
+        context_of(state, owner, organization_id).await,
+        OrganizationInviteRequest {
+          email: user.email.clone(),
+          role,
+        },
+      )
+      .await
+      .unwrap();
+    state
+      .manager
+      .accept(user.id, organization_id)
+      .await
+      .unwrap();
+    (user, access_token)
+  }
+
+  fn scoped_request(organization_id: Option<String>, access_token: String) -> Request<Body> {
+    let mut builder = Request::builder()
+      .method("GET")
+      .uri("/scoped")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token));
+    if let Some(organization_id) = organization_id {
+      builder = builder.header(ORGANIZATION_ID_HEADER, organization_id);
+    }
+    builder.body(Body::empty()).unwrap()
+  }
+
+  async fn members_of(
+    state: &Arc<OrganizationState>,
+    user: &UserEntity,
+    organization_id: Uuid,
+  ) -> Vec<OrganizationMemberResponse> {
+    state
+      .manager
+      .members(context_of(state, user, organization_id).await)
+      .await
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_organization_create_makes_caller_owner() {
+    let (app, state) = init_setup().await;
+    let (user, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OrganizationCreateRequest {
+      name: rand::random::<FullName>().to_string(),
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(OrganizationClient::create(&payload, access_token.clone()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let organization: OrganizationResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(organization.name, payload.name);
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::all(access_token.clone()))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let organizations: Vec<OrganizationResponse> =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(organizations
+      .iter()
+      .any(|listed| listed.id == organization.id));
+
+    let context: OrgContext = state
+      .manager
+      .context(user.id, organization.id)
+      .await
+      .unwrap();
+    assert_eq!(context.role, OrganizationRole::Owner);
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::get(organization.id, access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_organization_create_without_name() {
+    let (app, _) = init_setup().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let payload = OrganizationCreateRequest {
+      name: " ".to_string(),
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| OrganizationClient::create(&payload, token),
+        access_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid organization request",
+            "code": 400
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_org_context_rejects_non_members() {
+    let (app, state) = init_setup().await;
+    let (owner, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| OrganizationClient::members(organization_id, token),
+        access_token,
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "organization not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_org_context_from_header() {
+    let (app, state) = init_setup().await;
+    let (owner, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(scoped_request(
+        Some(organization_id.to_string()),
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body, json!(organization_id));
+
+    for organization_id in [None, Some("not-a-uuid".to_string())] {
+      test_client
+        .call_request(
+          |token| scoped_request(organization_id.clone(), token),
+          access_token.clone(),
+          StatusCode::BAD_REQUEST,
+          json!({
+              "error": "invalid organization id",
+              "code": 400
+          }),
+        )
+        .await;
+    }
+
+    test_client
+      .call_unauthorized_request(
+        |token| scoped_request(Some(organization_id.to_string()), token),
+        "invalid".to_string(),
+        json!({
+            "error": "invalid jwt token",
+            "code": 401
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_organization_invite_and_accept() {
+    let (app, state) = init_setup().await;
+    let (owner, owner_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (user, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+
+    let payload = OrganizationInviteRequest {
+      email: user.email.to_uppercase(),
+      role: OrganizationRole::Member,
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client
+      .get_response(OrganizationClient::invite(
+        organization_id,
+        &payload,
+        owner_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let member: OrganizationMemberResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(member.user_id, user.id);
+    assert_eq!(member.invited_by, Some(owner.id));
+    assert!(member.accepted_at.is_none());
+
+    test_client
+      .call_request(
+        |token| OrganizationClient::invite(organization_id, &payload, token),
+        owner_token,
+        StatusCode::CONFLICT,
+        json!({
+            "error": "existing organization member",
+            "code": 409
+        }),
+      )
+      .await;
+
+    test_client
+      .call_request(
+        |token| OrganizationClient::get(organization_id, token),
+        access_token.clone(),
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "organization not found",
+            "code": 404
+        }),
+      )
+      .await;
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::accept(
+        organization_id,
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::members(
+        organization_id,
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    test_client
+      .call_request(
+        |token| OrganizationClient::accept(organization_id, token),
+        access_token,
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "organization invitation not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_organization_invite_requires_admin() {
+    let (app, state) = init_setup().await;
+    let (owner, owner_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (invitee, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+    let (_, member_token) =
+      init_member(&state, &owner, organization_id, OrganizationRole::Member).await;
+
+    let payload = OrganizationInviteRequest {
+      email: invitee.email.clone(),
+      role: OrganizationRole::Member,
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| OrganizationClient::invite(organization_id, &payload, token),
+        member_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+
+    let payload = OrganizationInviteRequest {
+      email: invitee.email,
+      role: OrganizationRole::Owner,
+    };
+    test_client
+      .call_request(
+        |token| OrganizationClient::invite(organization_id, &payload, token),
+        owner_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid organization request",
+            "code": 400
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_organization_remove_member() {
+    let (app, state) = init_setup().await;
+    let (owner, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+    let (admin, admin_token) =
+      init_member(&state, &owner, organization_id, OrganizationRole::Admin).await;
+    let (member, member_token) =
+      init_member(&state, &owner, organization_id, OrganizationRole::Member).await;
+    let (leaving, leaving_token) =
+      init_member(&state, &owner, organization_id, OrganizationRole::Member).await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| OrganizationClient::remove(organization_id, admin.id, token),
+        member_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+
+    test_client
+      .call_request(
+        |token| OrganizationClient::remove(organization_id, owner.id, token),
+        admin_token.clone(),
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid organization request",
+            "code": 400
+        }),
+      )
+      .await;
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::remove(
+        organization_id,
+        member.id,
+        admin_token,
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::remove(
+        organization_id,
+        leaving.id,
+        leaving_token,
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let members: Vec<OrganizationMemberResponse> =
+      members_of(&state, &owner, organization_id).await;
+    assert!(!members
+      .iter()
+      .any(|listed| listed.user_id == member.id || listed.user_id == leaving.id));
+  }
+
+  #[tokio::test]
+  async fn test_organization_transfer_ownership() {
+    let (app, state) = init_setup().await;
+    let (owner, owner_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+    let (admin, admin_token) =
+      init_member(&state, &owner, organization_id, OrganizationRole::Admin).await;
+
+    let payload = OrganizationTransferRequest { user_id: admin.id };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| OrganizationClient::transfer(organization_id, &payload, token),
+        admin_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+
+    let response: Response = test_client
+      .get_response(OrganizationClient::transfer(
+        organization_id,
+        &payload,
+        owner_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let members: Vec<OrganizationMemberResponse> =
+      members_of(&state, &owner, organization_id).await;
+    let role_of = |user_id: Uuid| {
+      members
+        .iter()
+        .find(|member| member.user_id == user_id)
+        .map(|member| member.role)
+    };
+    assert_eq!(role_of(admin.id), Some(OrganizationRole::Owner));
+    assert_eq!(role_of(owner.id), Some(OrganizationRole::Admin));
+
+    test_client
+      .call_permission_denied_request(
+        |token| OrganizationClient::transfer(organization_id, &payload, token),
+        owner_token,
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_organization_transfer_requires_joined_member() {
+    let (app, state) = init_setup().await;
+    let (owner, owner_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let (invitee, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let organization_id: Uuid = init_organization(&state, &owner).await;
+    state
+      .manager
+      .invite(
+        context_of(&state, &owner, organization_id).await,
+        OrganizationInviteRequest {
+          email: invitee.email.clone(),
+          role: OrganizationRole::Admin,
+        },
+      )
+      .await
+      .unwrap();
+
+    let payload = OrganizationTransferRequest {
+      user_id: invitee.id,
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| OrganizationClient::transfer(organization_id, &payload, token),
+        owner_token,
+        StatusCode::BAD_REQUEST,
+        json!({
+            "error": "invalid organization request",
+            "code": 400
+        }),
+      )
+      .await;
+
+    let members: Vec<OrganizationMemberResponse> =
+      members_of(&state, &owner, organization_id).await;
+    assert!(members
+      .iter()
+      .any(|member| member.user_id == owner.id && member.role == OrganizationRole::Owner));
+  }
+}
@@ -0,0 +1,531 @@
+use crate::api::client::auth::request::auth_request::{
+  SamlAcsRequest, SamlTenantConfigureRequest, SamlTenantCreateRequest,
+};
+use crate::api::client::auth::response::auth_response::{SamlTenantResponse, SignInResponse};
+use crate::api::manager::auth::auth_manager::AuthManager;
+use crate::api::state::auth::auth_state::AuthState;
+use crate::core::error::error::AppError;
+use crate::core::response::response::SuccessResponse;
+use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::authorization::Authorization;
+use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::routing::{get, post, put};
+use axum::{response::IntoResponse, Form, Json, Router};
+use std::sync::Arc;
+
+pub struct SamlController;
+
+impl SamlController {
+  pub(crate) fn configure(state: Arc<AuthState>) -> Router {
+    Router::new()
+      .nest(
+        "/saml",
+        Router::new()
+          .route(
+            "/tenants",
+            post(Self::create_tenant)
+              .layer(Authorization::require_permission("saml_tenants:write")),
+          )
+          .route(
+            "/tenants/:tenant",
+            put(Self::configure_tenant)
+              .layer(Authorization::require_permission("saml_tenants:write")),
+          )
+          .route("/:tenant/metadata", get(Self::metadata))
+          .route("/:tenant/acs", post(Self::acs)),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl SamlController {
+  async fn metadata(
+    State(state): State<Arc<AuthState>>,
+    Path(tenant): Path<String>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let metadata: String = state.manager.saml_metadata(tenant).await?;
+    Ok(([(CONTENT_TYPE, "application/samlmetadata+xml")], metadata).into_response())
+  }
+
+  async fn acs(
+    State(state): State<Arc<AuthState>>,
+    Path(tenant): Path<String>,
+    Form(payload): Form<SamlAcsRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let token: SignInResponse = state.manager.sign_in_saml(tenant, payload).await?;
+    Ok(SuccessResponse { data: token.into() }.into_response())
+  }
+
+  async fn create_tenant(
+    State(state): State<Arc<AuthState>>,
+    AccessTokenAuth(_): AccessTokenAuth,
+    Json(payload): Json<SamlTenantCreateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let tenant: SamlTenantResponse = state.manager.create_saml_tenant(payload).await?;
+    Ok(
+      SuccessResponse {
+        data: tenant.into(),
+      }
+      .into_response(),
+    )
+  }
+
+  async fn configure_tenant(
+    State(state): State<Arc<AuthState>>,
+    AccessTokenAuth(_): AccessTokenAuth,
+    Path(tenant): Path<String>,
+    Json(payload): Json<SamlTenantConfigureRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let tenant: SamlTenantResponse = state.manager.configure_saml_tenant(tenant, payload).await?;
+    Ok(
+      SuccessResponse {
+        data: tenant.into(),
+      }
+      .into_response(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::saml::saml_client::SamlClient;
+  use crate::api::repository::saml_tenants::entity::saml_tenant_entity::SamlTenantEntity;
+  use crate::api::repository::saml_tenants::repository::saml_tenant_repository::SamlTenantRepository;
+  use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentityEntity;
+  use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+  use crate::api::repository::users::constant::user_constant::{OAuth2Provider, UserRole};
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::state::roles::role_state::RoleState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::saml_stub::SamlIdp;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::{Claims, JwtManager, TokenType};
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::body::{Body, Bytes};
+  use axum::http::{Request, StatusCode};
+  use axum::response::Response;
+  use axum::Extension;
+  use faker_rand::fr_fr::names::FullName;
+  use http_body_util::BodyExt;
+  use serde_json::{json, Value};
+  use uuid::Uuid;
+
+  const ALLOWED_DOMAIN: &str = "saml.example.com";
+
+  struct SamlSetup {
+    client: TestClient,
+    repository: RepositoryImpl,
+    idp: SamlIdp,
+    tenant: SamlTenantEntity,
+  }
+
+  impl SamlSetup {
+    async fn init() -> Self {
+      let config: AppConfig = APP_CONFIG.clone();
+      let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+      let state: Arc<AuthState> = Arc::new(
+        AuthState::new(
+          &db_pool,
+          JwtManager::new(config.jwt),
+          &config.aws,
+          &config.oauth2,
+          &config.magic_link,
+          &config.saml,
+          &config.ldap,
+        )
+        .await,
+      );
+      let role_state: Arc<RoleState> = Arc::new(RoleState::new(&db_pool).await);
+      let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
+      let repository: RepositoryImpl = RepositoryImpl::new(db_pool);
+      let idp: SamlIdp = SamlIdp::generate();
+      let tenant: SamlTenantEntity = SamlTenantRepository::create(
+        &repository,
+        format!("tenant-{}", Uuid::new_v4()),
+        idp.entity_id.clone(),
+        format!("{}/sso", idp.entity_id),
+        idp.certificate_pem(),
+        Some("email".to_string()),
+        Some("displayName".to_string()),
+        vec![ALLOWED_DOMAIN.to_string()],
+      )
+      .await
+      .unwrap();
+      let app: Router = SamlController::configure(state)
+        .layer(Extension(role_state))
+        .layer(Extension(user_state));
+      Self {
+        client: TestClient::new(app),
+        repository,
+        idp,
+        tenant,
+      }
+    }
+
+    fn email() -> String {
+      format!("{}@{}", Uuid::new_v4(), ALLOWED_DOMAIN)
+    }
+
+    fn entity_id(&self) -> String {
+      format!("{}/{}/metadata", APP_CONFIG.saml.url, self.tenant.slug)
+    }
+
+    fn acs_url(&self) -> String {
+      format!("{}/{}/acs", APP_CONFIG.saml.url, self.tenant.slug)
+    }
+
+    fn assertion(&self, email: &str, name: &str) -> String {
+      self
+        .idp
+        .assertion(&self.entity_id(), &self.acs_url(), email, name)
+    }
+
+    async fn acs(&self, saml_response: String) -> Response {
+      let request: Request<Body> =
+        SamlClient::acs(&self.tenant.slug, &SamlAcsRequest { saml_response });
+      self.client.get_response(request).await
+    }
+
+    async fn assert_rejected(&self, saml_response: String) {
+      let response: Response = self.acs(saml_response).await;
+      assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+      let body: Value = self.client.get_body(response).await;
+      assert_eq!(body, json!({"error": "invalid saml response", "code": 401}));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_saml_metadata_success() {
+    let setup: SamlSetup = SamlSetup::init().await;
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::metadata(&setup.tenant.slug))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(CONTENT_TYPE).unwrap(),
+      "application/samlmetadata+xml"
+    );
+
+    let body: Bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let metadata: String = String::from_utf8(body.to_vec()).unwrap();
+    assert!(metadata.contains(&format!(r#"entityID="{}""#, setup.entity_id())));
+    assert!(metadata.contains(&format!(r#"Location="{}""#, setup.acs_url())));
+    assert!(metadata.contains(r#"WantAssertionsSigned="true""#));
+  }
+
+  #[tokio::test]
+  async fn test_saml_metadata_tenant_not_found() {
+    let setup: SamlSetup = SamlSetup::init().await;
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::metadata("unknown-tenant"))
+      .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: Value = setup.client.get_body(response).await;
+    assert_eq!(body, json!({"error": "saml tenant not found", "code": 404}));
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_success() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+    let name: String = rand::random::<FullName>().to_string();
+
+    let assertion: String = setup.idp.sign(&setup.assertion(&email, &name));
+    let response: Response = setup
+      .acs(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = setup.client.get_body(response).await;
+    let token: SignInResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(token.refresh_token.is_some());
+
+    let user: UserEntity = setup
+      .repository
+      .by_email(email.to_lowercase())
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(user.name, Some(name));
+    assert!(user.password.is_none());
+    assert!(user.logged_in_at.is_some());
+
+    let claims: Claims = JwtManager::new(APP_CONFIG.jwt.clone())
+      .validate_jwt(&token.access_token, TokenType::AccessToken)
+      .unwrap();
+    assert_eq!(claims.sub, user.id);
+
+    let identities: Vec<UserIdentityEntity> = setup.repository.by_user_id(user.id).await.unwrap();
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].provider, OAuth2Provider::Saml);
+    assert_eq!(
+      identities[0].subject,
+      format!("{}:{}", setup.tenant.id, email)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_signs_in_linked_user() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    for _ in 0..2 {
+      let assertion: String = setup.idp.sign(&setup.assertion(&email, "Returning"));
+      let response: Response = setup
+        .acs(setup.idp.response(&setup.acs_url(), &assertion))
+        .await;
+      assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let user: UserEntity = setup.repository.by_email(email).await.unwrap().unwrap();
+    let identities: Vec<UserIdentityEntity> = setup.repository.by_user_id(user.id).await.unwrap();
+    assert_eq!(identities.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_does_not_link_by_email() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+    let user: UserEntity = UserRepository::create(&setup.repository, email.clone(), None, None)
+      .await
+      .unwrap();
+
+    let assertion: String = setup.idp.sign(&setup.assertion(&email, "Takeover"));
+    let response: Response = setup
+      .acs(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert!(setup
+      .repository
+      .by_user_id(user.id)
+      .await
+      .unwrap()
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_disallowed_domain() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = format!("{}@another.example.com", Uuid::new_v4());
+
+    let assertion: String = setup.idp.sign(&setup.assertion(&email, "Outsider"));
+    setup
+      .assert_rejected(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_replay_rejected() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    let assertion: String = setup.idp.sign(&setup.assertion(&email, "Replay"));
+    let saml_response: String = setup.idp.response(&setup.acs_url(), &assertion);
+    assert_eq!(
+      setup.acs(saml_response.clone()).await.status(),
+      StatusCode::OK
+    );
+    setup.assert_rejected(saml_response).await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_tampered_assertion() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    let assertion: String = setup
+      .idp
+      .sign(&setup.assertion(&email, "Tampered"))
+      .replace(&email, "attacker@example.com");
+    setup
+      .assert_rejected(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_untrusted_certificate() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    let mut impostor: SamlIdp = SamlIdp::generate();
+    impostor.entity_id = setup.idp.entity_id.clone();
+    let assertion: String = impostor.sign(&setup.assertion(&email, "Impostor"));
+    setup
+      .assert_rejected(impostor.response(&setup.acs_url(), &assertion))
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_unsigned_assertion() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    let assertion: String = setup.assertion(&email, "Unsigned");
+    setup
+      .assert_rejected(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_acs_wrong_audience() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let email: String = SamlSetup::email();
+
+    let assertion: String = setup.idp.sign(&setup.idp.assertion(
+      "https://another-sp.example.com/metadata",
+      &setup.acs_url(),
+      &email,
+      "Audience",
+    ));
+    setup
+      .assert_rejected(setup.idp.response(&setup.acs_url(), &assertion))
+      .await;
+  }
+
+  fn tenant_payload(idp: &SamlIdp) -> SamlTenantCreateRequest {
+    SamlTenantCreateRequest {
+      slug: format!("tenant-{}", Uuid::new_v4()),
+      idp_entity_id: idp.entity_id.clone(),
+      idp_sso_url: format!("{}/sso", idp.entity_id),
+      idp_certificate: idp.certificate_pem(),
+      email_attribute: None,
+      name_attribute: None,
+      allowed_domains: vec!["Acme.Example.com".to_string()],
+    }
+  }
+
+  #[tokio::test]
+  async fn test_saml_create_tenant_success() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::Admin).await;
+    let payload: SamlTenantCreateRequest = tenant_payload(&SamlIdp::generate());
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::create_tenant(&payload, access_token))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = setup.client.get_body(response).await;
+    let tenant: SamlTenantResponse =
+      serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(tenant.slug, payload.slug);
+    assert_eq!(tenant.allowed_domains, vec!["acme.example.com".to_string()]);
+    assert_eq!(
+      tenant.acs_url,
+      format!("{}/{}/acs", APP_CONFIG.saml.url, payload.slug)
+    );
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::metadata(&payload.slug))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_saml_create_tenant_permission_denied() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let payload: SamlTenantCreateRequest = tenant_payload(&SamlIdp::generate());
+
+    setup
+      .client
+      .call_permission_denied_request(
+        |token| SamlClient::create_tenant(&payload, token),
+        access_token,
+        json!({"error": "permission denied", "code": 403}),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_saml_create_tenant_invalid() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::Admin).await;
+    let valid: SamlTenantCreateRequest = tenant_payload(&SamlIdp::generate());
+
+    for payload in [
+      SamlTenantCreateRequest {
+        idp_certificate: "not a certificate".to_string(),
+        ..valid.clone()
+      },
+      SamlTenantCreateRequest {
+        allowed_domains: vec![],
+        ..valid.clone()
+      },
+      SamlTenantCreateRequest {
+        slug: "tenants".to_string(),
+        ..valid.clone()
+      },
+    ] {
+      let response: Response = setup
+        .client
+        .get_response(SamlClient::create_tenant(&payload, access_token.clone()))
+        .await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+      let body: Value = setup.client.get_body(response).await;
+      assert_eq!(body, json!({"error": "invalid saml tenant", "code": 400}));
+    }
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::create_tenant(
+        &SamlTenantCreateRequest {
+          slug: setup.tenant.slug.clone(),
+          ..valid
+        },
+        access_token,
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+  }
+
+  #[tokio::test]
+  async fn test_saml_configure_tenant_success() {
+    let setup: SamlSetup = SamlSetup::init().await;
+    let (_, access_token, _, _) = AuthSetup::init_user(UserRole::Admin).await;
+    let email: String = format!("{}@acme.example.com", Uuid::new_v4());
+    let assertion: String = setup.idp.sign(&setup.assertion(&email, "Configured"));
+    let saml_response: String = setup.idp.response(&setup.acs_url(), &assertion);
+
+    let payload = SamlTenantConfigureRequest {
+      idp_entity_id: setup.tenant.idp_entity_id.clone(),
+      idp_sso_url: setup.tenant.idp_sso_url.clone(),
+      idp_certificate: setup.tenant.idp_certificate.clone(),
+      email_attribute: setup.tenant.email_attribute.clone(),
+      name_attribute: setup.tenant.name_attribute.clone(),
+      allowed_domains: vec!["acme.example.com".to_string()],
+    };
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::configure_tenant(
+        &setup.tenant.slug,
+        &payload,
+        access_token.clone(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(setup.acs(saml_response).await.status(), StatusCode::OK);
+
+    let response: Response = setup
+      .client
+      .get_response(SamlClient::configure_tenant(
+        "unknown-tenant",
+        &payload,
+        access_token,
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+  }
+}
@@ -0,0 +1,634 @@
+use crate::api::client::scim::request::scim_request::{
+  ScimListRequest, ScimPatchRequest, ScimUserRequest,
+};
+use crate::api::client::scim::response::scim_response::ScimUserResponse;
+use crate::api::manager::scim::scim_manager::ScimManager;
+use crate::api::state::scim::scim_state::ScimState;
+use crate::core::error::error::AppError;
+use crate::core::security::authentication::ScimBearerAuth;
+use axum::extract::{Path, Query, State};
+use axum::http::header::{CONTENT_TYPE, LOCATION};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{response::IntoResponse, Json, Router};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+pub struct ScimController;
+
+impl ScimController {
+  pub(crate) fn configure(state: Arc<ScimState>) -> Router {
+    Router::new()
+      .nest(
+        "/scim/v2",
+        Router::new()
+          .route("/ServiceProviderConfig", get(Self::service_provider_config))
+          .route("/Users", get(Self::all).post(Self::create))
+          .route(
+            "/Users/:id",
+            get(Self::get)
+              .put(Self::replace)
+              .patch(Self::patch)
+              .delete(Self::delete),
+          ),
+      )
+      .with_state(state.clone())
+  }
+}
+
+impl ScimController {
+  async fn service_provider_config(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok((
+      [(CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+      Json(state.manager.service_provider_config()),
+    ))
+  }
+
+  async fn all(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Query(payload): Query<ScimListRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok((
+      [(CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+      Json(state.manager.all(payload).await?),
+    ))
+  }
+
+  async fn get(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok((
+      [(CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+      Json(state.manager.get(id).await?),
+    ))
+  }
+
+  async fn create(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Json(payload): Json<ScimUserRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    let user: ScimUserResponse = state.manager.create(payload).await?;
+    Ok((
+      StatusCode::CREATED,
+      [
+        (CONTENT_TYPE, SCIM_CONTENT_TYPE.to_string()),
+        (LOCATION, user.meta.location.clone()),
+      ],
+      Json(user),
+    ))
+  }
+
+  async fn replace(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ScimUserRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok((
+      [(CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+      Json(state.manager.replace(id, payload).await?),
+    ))
+  }
+
+  async fn patch(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ScimPatchRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok((
+      [(CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+      Json(state.manager.patch(id, payload).await?),
+    ))
+  }
+
+  async fn delete(
+    State(state): State<Arc<ScimState>>,
+    _: ScimBearerAuth,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    state.manager.delete(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::scim::request::scim_request::ScimPatchOperation;
+  use crate::api::client::scim::response::scim_response::{
+    ScimListResponse, ScimName, ScimServiceProviderConfigResponse,
+  };
+  use crate::api::client::scim::scim_client::ScimClient;
+  use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+  use crate::api::repository::users::constant::user_constant::{
+    OAuth2Provider, UserRole, UserStatus,
+  };
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::test::test_context::TestClient;
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::{AppConfig, ScimConfig};
+  use crate::utils::hash_utils::HashUtils;
+  use axum::body::Body;
+  use axum::http::Request;
+  use axum::response::Response;
+  use serde_json::{json, Value};
+
+  struct ScimSetup {
+    client: TestClient,
+    repository: RepositoryImpl,
+    secret: String,
+  }
+
+  impl ScimSetup {
+    async fn init() -> Self {
+      Self::init_with_secret("scim-test-secret").await
+    }
+
+    async fn init_with_secret(secret: &str) -> Self {
+      let config: AppConfig = APP_CONFIG.clone();
+      let scim_config: ScimConfig = ScimConfig {
+        secret: secret.to_string(),
+        ..config.scim
+      };
+      let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
+      let state: Arc<ScimState> = Arc::new(ScimState::new(&db_pool, &scim_config).await);
+      Self {
+        client: TestClient::new(ScimController::configure(state)),
+        repository: RepositoryImpl::new(db_pool),
+        secret: scim_config.secret,
+      }
+    }
+
+    async fn call(&self, request: Request<Body>, status: StatusCode) -> Value {
+      let response: Response = self.client.get_response(request).await;
+      assert_eq!(response.status(), status);
+      self.client.get_body(response).await
+    }
+
+    async fn create(&self, payload: &ScimUserRequest) -> ScimUserResponse {
+      let body: Value = self
+        .call(
+          ScimClient::create(payload, &self.secret),
+          StatusCode::CREATED,
+        )
+        .await;
+      serde_json::from_value(body).unwrap()
+    }
+  }
+
+  fn user_request(email: &str, name: &str) -> ScimUserRequest {
+    ScimUserRequest {
+      schemas: vec!["urn:ietf:params:scim:schemas:core:2.0:User".to_string()],
+      user_name: email.to_string(),
+      name: Some(ScimName {
+        formatted: Some(name.to_string()),
+      }),
+      display_name: None,
+      active: Some(true),
+      password: None,
+    }
+  }
+
+  fn scim_email() -> String {
+    format!("scim-{}@example.com", Uuid::new_v4())
+  }
+
+  fn patch_request(operations: Vec<ScimPatchOperation>) -> ScimPatchRequest {
+    ScimPatchRequest {
+      schemas: vec!["urn:ietf:params:scim:api:messages:2.0:PatchOp".to_string()],
+      operations,
+    }
+  }
+
+  fn operation(op: &str, path: Option<&str>, value: Value) -> ScimPatchOperation {
+    ScimPatchOperation {
+      op: op.to_string(),
+      path: path.map(str::to_string),
+      value: Some(value),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_scim_service_provider_config_success() {
+    let setup: ScimSetup = ScimSetup::init().await;
+
+    let response: Response = setup
+      .client
+      .get_response(ScimClient::service_provider_config(&setup.secret))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(CONTENT_TYPE).unwrap(),
+      SCIM_CONTENT_TYPE
+    );
+
+    let body: Value = setup.client.get_body(response).await;
+    let config: ScimServiceProviderConfigResponse = serde_json::from_value(body).unwrap();
+    assert!(config.patch.supported);
+    assert!(config.filter.supported);
+    assert!(!config.bulk.supported);
+    assert_eq!(
+      config.authentication_schemes[0].scheme_type,
+      "oauthbearertoken"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_scim_invalid_token() {
+    let setup: ScimSetup = ScimSetup::init().await;
+
+    for secret in ["", "wrong-secret"] {
+      let body: Value = setup
+        .call(
+          ScimClient::service_provider_config(secret),
+          StatusCode::UNAUTHORIZED,
+        )
+        .await;
+      assert_eq!(body, json!({"error": "invalid scim token", "code": 401}));
+    }
+    setup
+      .call(
+        ScimClient::get(Uuid::new_v4(), "wrong-secret"),
+        StatusCode::UNAUTHORIZED,
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_scim_unset_secret_disables_api() {
+    for secret in ["", "__REPLACE_ME__"] {
+      let setup: ScimSetup = ScimSetup::init_with_secret(secret).await;
+
+      let body: Value = setup
+        .call(
+          ScimClient::service_provider_config(secret),
+          StatusCode::UNAUTHORIZED,
+        )
+        .await;
+      assert_eq!(body, json!({"error": "invalid scim token", "code": 401}));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_scim_create_user_success() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let email: String = scim_email();
+
+    let mut payload: ScimUserRequest = user_request(&email.to_uppercase(), "Jane Doe");
+    payload.password = Some("provisioned-secret".to_string());
+    let response: Response = setup
+      .client
+      .get_response(ScimClient::create(&payload, &setup.secret))
+      .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let location: String = response
+      .headers()
+      .get(LOCATION)
+      .unwrap()
+      .to_str()
+      .unwrap()
+      .to_string();
+
+    let body: Value = setup.client.get_body(response).await;
+    let user: ScimUserResponse = serde_json::from_value(body.clone()).unwrap();
+    assert_eq!(user.user_name, email);
+    assert_eq!(user.display_name, Some("Jane Doe".to_string()));
+    assert_eq!(user.emails[0].value, email);
+    assert!(user.active);
+    assert_eq!(user.meta.location, location);
+    assert_eq!(
+      location,
+      format!("{}/Users/{}", APP_CONFIG.scim.url, user.id)
+    );
+    assert_eq!(body["name"], json!({"formatted": "Jane Doe"}));
+    assert_eq!(body["meta"]["resourceType"], "User");
+
+    let entity: UserEntity = setup.repository.by_email(email).await.unwrap().unwrap();
+    assert_eq!(entity.id, user.id);
+    assert!(matches!(entity.status, UserStatus::Verified));
+    assert!(HashUtils::verify_password(
+      "provisioned-secret",
+      entity.password.as_deref().unwrap()
+    ));
+
+    let body: Value = setup
+      .call(ScimClient::get(user.id, &setup.secret), StatusCode::OK)
+      .await;
+    assert_eq!(body["userName"], user.user_name);
+  }
+
+  #[tokio::test]
+  async fn test_scim_create_user_existing_email() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let email: String = scim_email();
+    setup.create(&user_request(&email, "Jane Doe")).await;
+
+    let body: Value = setup
+      .call(
+        ScimClient::create(&user_request(&email, "Jane Doe"), &setup.secret),
+        StatusCode::CONFLICT,
+      )
+      .await;
+    assert_eq!(body, json!({"error": "existing email", "code": 409}));
+  }
+
+  #[tokio::test]
+  async fn test_scim_list_users_with_filter() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let email: String = scim_email();
+    let user: ScimUserResponse = setup.create(&user_request(&email, "Jane Doe")).await;
+
+    let payload = ScimListRequest {
+      filter: Some(format!(r#"userName eq "{}""#, email.to_uppercase())),
+      start_index: None,
+      count: None,
+    };
+    let body: Value = setup
+      .call(ScimClient::all(&payload, &setup.secret), StatusCode::OK)
+      .await;
+    let list: ScimListResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(list.total_results, 1);
+    assert_eq!(list.start_index, 1);
+    assert_eq!(list.items_per_page, 1);
+    assert_eq!(list.resources[0].id, user.id);
+
+    let payload = ScimListRequest {
+      filter: Some(format!(r#"emails.value eq "{}""#, email)),
+      start_index: Some(2),
+      count: Some(10),
+    };
+    let body: Value = setup
+      .call(ScimClient::all(&payload, &setup.secret), StatusCode::OK)
+      .await;
+    let list: ScimListResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(list.total_results, 1);
+    assert!(list.resources.is_empty());
+
+    let payload = ScimListRequest {
+      filter: None,
+      start_index: None,
+      count: Some(0),
+    };
+    let body: Value = setup
+      .call(ScimClient::all(&payload, &setup.secret), StatusCode::OK)
+      .await;
+    let list: ScimListResponse = serde_json::from_value(body).unwrap();
+    assert!(list.total_results >= 1);
+    assert!(list.resources.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_scim_list_users_invalid_filter() {
+    let setup: ScimSetup = ScimSetup::init().await;
+
+    for filter in [
+      r#"userName co "jane""#,
+      r#"externalId eq "42""#,
+      "userName eq jane@example.com",
+    ] {
+      let payload = ScimListRequest {
+        filter: Some(filter.to_string()),
+        start_index: None,
+        count: None,
+      };
+      let body: Value = setup
+        .call(
+          ScimClient::all(&payload, &setup.secret),
+          StatusCode::BAD_REQUEST,
+        )
+        .await;
+      assert_eq!(body, json!({"error": "invalid scim filter", "code": 400}));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_scim_replace_user_success() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let user: ScimUserResponse = setup.create(&user_request(&scim_email(), "Jane Doe")).await;
+    let email: String = scim_email();
+
+    let mut payload: ScimUserRequest = user_request(&email, "Jane Roe");
+    payload.display_name = Some("Jane R.".to_string());
+    let body: Value = setup
+      .call(
+        ScimClient::replace(user.id, &payload, &setup.secret),
+        StatusCode::OK,
+      )
+      .await;
+    let replaced: ScimUserResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(replaced.id, user.id);
+    assert_eq!(replaced.user_name, email);
+    assert_eq!(replaced.display_name, Some("Jane R.".to_string()));
+
+    let body: Value = setup
+      .call(
+        ScimClient::replace(Uuid::new_v4(), &payload, &setup.secret),
+        StatusCode::NOT_FOUND,
+      )
+      .await;
+    assert_eq!(body, json!({"error": "user not found", "code": 404}));
+  }
+
+  #[tokio::test]
+  async fn test_scim_patch_user_success() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let user: ScimUserResponse = setup.create(&user_request(&scim_email(), "Jane Doe")).await;
+    let email: String = scim_email();
+
+    let payload: ScimPatchRequest = patch_request(vec![
+      operation("Replace", Some("name.givenName"), json!("Jane")),
+      operation(
+        "replace",
+        None,
+        json!({"userName": email, "displayName": "Jane Roe"}),
+      ),
+    ]);
+    let body: Value = setup
+      .call(
+        ScimClient::patch(user.id, &payload, &setup.secret),
+        StatusCode::OK,
+      )
+      .await;
+    let patched: ScimUserResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(patched.user_name, email);
+    assert_eq!(patched.display_name, Some("Jane Roe".to_string()));
+    assert!(patched.active);
+
+    let payload: ScimPatchRequest = patch_request(vec![ScimPatchOperation {
+      op: "remove".to_string(),
+      path: Some("displayName".to_string()),
+      value: None,
+    }]);
+    let body: Value = setup
+      .call(
+        ScimClient::patch(user.id, &payload, &setup.secret),
+        StatusCode::OK,
+      )
+      .await;
+    assert!(body.get("displayName").is_none());
+    assert!(body.get("name").is_none());
+
+    for operations in [
+      vec![operation("replace", Some("active"), json!(1))],
+      vec![operation("copy", Some("active"), json!(true))],
+      vec![ScimPatchOperation {
+        op: "remove".to_string(),
+        path: Some("userName".to_string()),
+        value: None,
+      }],
+    ] {
+      let body: Value = setup
+        .call(
+          ScimClient::patch(user.id, &patch_request(operations), &setup.secret),
+          StatusCode::BAD_REQUEST,
+        )
+        .await;
+      assert_eq!(body, json!({"error": "invalid scim request", "code": 400}));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_scim_deactivate_and_reactivate_user() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let email: String = scim_email();
+    let user: ScimUserResponse = setup.create(&user_request(&email, "Jane Doe")).await;
+
+    let payload: ScimPatchRequest =
+      patch_request(vec![operation("Replace", Some("active"), json!("False"))]);
+    let body: Value = setup
+      .call(
+        ScimClient::patch(user.id, &payload, &setup.secret),
+        StatusCode::OK,
+      )
+      .await;
+    assert_eq!(body["active"], false);
+    let body: Value = setup
+      .call(ScimClient::get(user.id, &setup.secret), StatusCode::OK)
+      .await;
+    assert_eq!(body["active"], false);
+    assert!(setup
+      .repository
+      .by_email(email.clone())
+      .await
+      .unwrap()
+      .is_none());
+
+    let payload: ScimPatchRequest =
+      patch_request(vec![operation("replace", Some("active"), json!(true))]);
+    let body: Value = setup
+      .call(
+        ScimClient::patch(user.id, &payload, &setup.secret),
+        StatusCode::OK,
+      )
+      .await;
+    assert_eq!(body["active"], true);
+    assert!(setup.repository.by_email(email).await.unwrap().is_some());
+  }
+
+  #[tokio::test]
+  async fn test_scim_reprovision_user_resets_account() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let email: String = scim_email();
+    let user: ScimUserResponse = setup.create(&user_request(&email, "Jane Doe")).await;
+    setup
+      .repository
+      .update_role(user.id, UserRole::Admin)
+      .await
+      .unwrap();
+    UserIdentityRepository::create(
+      &setup.repository,
+      user.id,
+      OAuth2Provider::Google,
+      Uuid::new_v4().to_string(),
+      Some(email.clone()),
+    )
+    .await
+    .unwrap();
+    let response: Response = setup
+      .client
+      .get_response(ScimClient::delete(user.id, &setup.secret))
+      .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let restored: ScimUserResponse = setup.create(&user_request(&email, "Jane Roe")).await;
+    assert_eq!(restored.id, user.id);
+    assert_eq!(restored.display_name, Some("Jane Roe".to_string()));
+    assert!(restored.active);
+
+    let entity: UserEntity = setup.repository.by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(entity.role, UserRole::User);
+    assert!(entity.password.is_none());
+    assert!(setup
+      .repository
+      .by_user_id(user.id)
+      .await
+      .unwrap()
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_scim_rename_to_deleted_user_email() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let user: ScimUserResponse = setup.create(&user_request(&scim_email(), "Jane Doe")).await;
+    let deleted_email: String = scim_email();
+    let deleted: ScimUserResponse = setup
+      .create(&user_request(&deleted_email, "John Doe"))
+      .await;
+    let response: Response = setup
+      .client
+      .get_response(ScimClient::delete(deleted.id, &setup.secret))
+      .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let payload: ScimPatchRequest = patch_request(vec![operation(
+      "replace",
+      Some("userName"),
+      json!(deleted_email),
+    )]);
+    let body: Value = setup
+      .call(
+        ScimClient::patch(user.id, &payload, &setup.secret),
+        StatusCode::CONFLICT,
+      )
+      .await;
+    assert_eq!(body, json!({"error": "existing email", "code": 409}));
+  }
+
+  #[tokio::test]
+  async fn test_scim_delete_user_success() {
+    let setup: ScimSetup = ScimSetup::init().await;
+    let user: ScimUserResponse = setup.create(&user_request(&scim_email(), "Jane Doe")).await;
+
+    let response: Response = setup
+      .client
+      .get_response(ScimClient::delete(user.id, &setup.secret))
+      .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let entity: Option<UserEntity> = setup.repository.by_id(user.id).await.unwrap();
+    assert!(entity.is_none());
+    let body: Value = setup
+      .call(ScimClient::get(user.id, &setup.secret), StatusCode::OK)
+      .await;
+    assert_eq!(body["active"], false);
+    setup
+      .call(
+        ScimClient::delete(user.id, &setup.secret),
+        StatusCode::NOT_FOUND,
+      )
+      .await;
+  }
+}
@@ -1,18 +1,23 @@
-use crate::api::client::users::request::user_request::UserUpdateRoleRequest;
+use crate::api::client::users::request::user_request::{UserUpdateRequest, UserUpdateRoleRequest};
+use crate::api::client::users::response::user_response::UserResponse;
 use crate::api::manager::users::user_manager::UserManager;
+use crate::api::repository::pagination::Page;
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::api::repository::users::entity::user_entity::{UserFilter, Users};
 use crate::api::state::users::user_state::UserState;
 use crate::core::error::error::AppError;
+use crate::core::request::filter::Filter;
 use crate::core::request::pagination::Pagination;
 use crate::core::request::sorting::Sorting;
 use crate::core::response::constant::BaseMessage;
-use crate::core::response::response::SuccessResponse;
-use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::response::response::{PaginatedResponse, SuccessResponse};
+use crate::core::security::authentication::ApiKeyAuth;
 use crate::core::security::authorization::Authorization;
-use axum::extract::{Multipart, State};
-use axum::middleware::from_fn;
-use axum::routing::{get, post, put};
+use axum::extract::{Multipart, OriginalUri, Path, State};
+use axum::routing::{get, patch, post, put};
 use axum::{response::IntoResponse, Json, Router};
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct UserController;
 
@@ -22,12 +27,27 @@ impl UserController {
       .nest(
         "/users",
         Router::new()
-          .route("/", get(Self::all).layer(from_fn(Authorization::admin)))
-          .route("/me", get(Self::me))
-          .route("/profile", post(Self::upload_profile_url))
+          .route(
+            "/",
+            get(Self::all).layer(Authorization::require_permission("users:read")),
+          )
+          .route(
+            "/me",
+            get(Self::me).layer(Authorization::require_min_role(UserRole::User)),
+          )
+          .route(
+            "/profile",
+            post(Self::upload_profile_url).layer(Authorization::require_min_role(UserRole::User)),
+          )
           .route(
             "/role",
-            put(Self::update_role).layer(from_fn(Authorization::admin)),
+            put(Self::update_role).layer(Authorization::require_permission("users:write")),
+          )
+          .route(
+            "/:id",
+            get(Self::get)
+              .layer(Authorization::require_permission("users:read"))
+              .merge(patch(Self::update).layer(Authorization::require_permission("users:write"))),
           ),
       )
       .with_state(state.clone())
@@ -37,27 +57,55 @@ impl UserController {
 impl UserController {
   async fn all(
     State(state): State<Arc<UserState>>,
-    AccessTokenAuth(_): AccessTokenAuth,
+    ApiKeyAuth(_): ApiKeyAuth,
+    OriginalUri(uri): OriginalUri,
     pagination: Pagination,
-    sorting: Sorting,
+    sorting: Sorting<Users>,
+    Filter(filter): Filter<UserFilter>,
   ) -> Result<impl IntoResponse, AppError> {
-    Ok(SuccessResponse {
-      data: state.manager.all(pagination, sorting).await?.into(),
-    })
+    let users: Page<UserResponse> = state
+      .manager
+      .all(pagination.clone(), sorting, filter)
+      .await?;
+    Ok(PaginatedResponse::new(users, pagination, uri))
   }
 
   async fn me(
     State(state): State<Arc<UserState>>,
-    AccessTokenAuth(token_data): AccessTokenAuth,
+    ApiKeyAuth(token_data): ApiKeyAuth,
   ) -> Result<impl IntoResponse, AppError> {
     Ok(SuccessResponse {
       data: state.manager.me(token_data.sub).await?.into(),
     })
   }
 
+  async fn get(
+    State(state): State<Arc<UserState>>,
+    Path(id): Path<Uuid>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state.manager.get(id).await?.into(),
+    })
+  }
+
+  async fn update(
+    State(state): State<Arc<UserState>>,
+    ApiKeyAuth(token_data): ApiKeyAuth,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UserUpdateRequest>,
+  ) -> Result<impl IntoResponse, AppError> {
+    Ok(SuccessResponse {
+      data: state
+        .manager
+        .update(token_data.sub, id, payload)
+        .await?
+        .into(),
+    })
+  }
+
   async fn upload_profile_url(
     State(state): State<Arc<UserState>>,
-    AccessTokenAuth(token_data): AccessTokenAuth,
+    ApiKeyAuth(token_data): ApiKeyAuth,
     file: Multipart,
   ) -> Result<impl IntoResponse, AppError> {
     Ok(
@@ -73,7 +121,7 @@ impl UserController {
 
   async fn update_role(
     State(state): State<Arc<UserState>>,
-    AccessTokenAuth(_): AccessTokenAuth,
+    ApiKeyAuth(_): ApiKeyAuth,
     Json(payload): Json<UserUpdateRoleRequest>,
   ) -> Result<impl IntoResponse, AppError> {
     state.manager.update_role(payload).await?;
@@ -86,31 +134,76 @@ impl UserController {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::api::client::users::response::user_response::UserResponse;
+  use crate::api::client::auth::auth_client::AuthClient;
+  use crate::api::client::auth::request::auth_request::AuthSignUpRequest;
   use crate::api::client::users::user_client::UserClient;
-  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::manager::auth::auth_manager::AuthManager;
+  use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+  use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+  use crate::api::repository::users::constant::user_constant::UserStatus;
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::api::repository::RepositoryImpl;
+  use crate::api::state::roles::role_state::RoleState;
   use crate::api::test::test_context::{
     AuthSetup, TestClient, USER_DEFAULT_EMAIL, USER_DEFAULT_USER_ID,
   };
+  use crate::core::security::jwt_manager::{JwtManager, TokenType};
   use crate::db::db::PostgresDatabase;
   use crate::launcher::APP_CONFIG;
   use crate::resources::config::AppConfig;
+  use crate::utils::datetime_utils::TimeUtils;
   use axum::body::Body;
   use axum::http::Request;
   use axum::response::Response;
+  use axum::Extension;
+  use chrono::{Duration, SecondsFormat};
+  use faker_rand::en_us::internet::Email;
+  use faker_rand::fr_fr::names::FullName;
   use http::StatusCode;
   use serde_json::{json, Value};
   use std::sync::Arc;
   use uuid::Uuid;
 
+  async fn filter_users(test_client: &TestClient, query: &str) -> Vec<UserResponse> {
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        query,
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = test_client.get_body(response).await;
+    serde_json::from_value(body.get("data").unwrap().clone()).unwrap()
+  }
+
   async fn init_setup() -> (Router, Arc<UserState>) {
     let config: AppConfig = APP_CONFIG.clone();
     let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
     let state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
-    let app: Router = UserController::configure(state.clone());
+    let role_state: Arc<RoleState> = Arc::new(RoleState::new(&db_pool).await);
+    let app: Router = UserController::configure(state.clone())
+      .layer(Extension(role_state))
+      .layer(Extension(state.clone()));
     (app, state)
   }
 
+  async fn update_user(
+    test_client: &TestClient,
+    id: Uuid,
+    payload: &UserUpdateRequest,
+  ) -> (StatusCode, Value) {
+    let response: Response = test_client
+      .get_response(UserClient::update(
+        id,
+        payload,
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    let status: StatusCode = response.status();
+    (status, test_client.get_body(response).await)
+  }
+
   #[tokio::test]
   async fn test_user_all_success() {
     let (app, _) = init_setup().await;
@@ -339,4 +432,606 @@ mod tests {
       )
       .await;
   }
+
+  #[tokio::test]
+  async fn test_user_get_success() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let request: Request<Body> = UserClient::get(user.id, AuthSetup::get_admin_access_token());
+    let test_client: TestClient = TestClient::new(app);
+    let response: Response = test_client.get_response(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = test_client.get_body(response).await;
+    let data: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(data.id, user.id);
+    assert_eq!(data.email, user.email);
+    assert!(data.status_reason.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_user_get_not_found() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_request(
+        |token| UserClient::get(Uuid::new_v4(), token),
+        AuthSetup::get_admin_access_token(),
+        StatusCode::NOT_FOUND,
+        json!({
+            "error": "user not found",
+            "code": 404
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_user_update_success() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+
+    let name: String = rand::random::<FullName>().to_string();
+    let payload = UserUpdateRequest {
+      name: Some(name.clone()),
+      role: Some(UserRole::Moderator),
+      ..Default::default()
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let (status, body) = update_user(&test_client, user.id, &payload).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let data: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(data.name, Some(name));
+    assert_eq!(data.role, UserRole::Moderator);
+  }
+
+  #[tokio::test]
+  async fn test_user_suspend_rejects_tokens() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, access_token, _, credentials) = AuthSetup::init_user(UserRole::User).await;
+    let (_, auth_state, _) = AuthSetup::init().await;
+
+    let until = TimeUtils::utc_now() + Duration::days(1);
+    let payload = UserUpdateRequest {
+      status: Some(UserStatus::Suspended),
+      status_reason: Some("spam".to_string()),
+      status_until: Some(until),
+      ..Default::default()
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let (status, body) = update_user(&test_client, user.id, &payload).await;
+    assert_eq!(status, StatusCode::OK);
+    let data: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert_eq!(data.status, UserStatus::Suspended);
+    assert_eq!(data.status_reason, Some("spam".to_string()));
+    assert_eq!(
+      data.status_until.map(|until| until.timestamp()),
+      Some(until.timestamp())
+    );
+
+    test_client
+      .call_permission_denied_request(
+        UserClient::me,
+        access_token.clone(),
+        json!({
+            "error": "user suspended",
+            "code": 403
+        }),
+      )
+      .await;
+    assert!(matches!(
+      auth_state.manager.sign_in(credentials).await,
+      Err(AppError::UserSuspended)
+    ));
+    assert!(matches!(
+      auth_state
+        .manager
+        .refresh_token(Uuid::new_v4(), user.id, user.role)
+        .await,
+      Err(AppError::UserSuspended)
+    ));
+
+    let payload = UserUpdateRequest {
+      status: Some(UserStatus::Verified),
+      ..Default::default()
+    };
+    let (status, body) = update_user(&test_client, user.id, &payload).await;
+    assert_eq!(status, StatusCode::OK);
+    let data: UserResponse = serde_json::from_value(body.get("data").unwrap().clone()).unwrap();
+    assert!(data.status_reason.is_none() && data.status_until.is_none());
+
+    let response: Response = test_client.get_response(UserClient::me(access_token)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_user_ban_rejects_tokens() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, access_token, _, credentials) = AuthSetup::init_user(UserRole::User).await;
+    let (_, auth_state, _) = AuthSetup::init().await;
+
+    let payload = UserUpdateRequest {
+      status: Some(UserStatus::Banned),
+      status_reason: Some("fraud".to_string()),
+      ..Default::default()
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let (status, _) = update_user(&test_client, user.id, &payload).await;
+    assert_eq!(status, StatusCode::OK);
+
+    test_client
+      .call_permission_denied_request(
+        UserClient::me,
+        access_token,
+        json!({
+            "error": "user banned",
+            "code": 403
+        }),
+      )
+      .await;
+    assert!(matches!(
+      auth_state.manager.sign_in(credentials).await,
+      Err(AppError::UserBanned)
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_user_delete_rejects_tokens() {
+    let (app, _) = init_setup().await;
+    let (auth_app, _, _) = AuthSetup::init().await;
+    let (user, access_token, refresh_token, _) = AuthSetup::init_user(UserRole::User).await;
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(APP_CONFIG.postgres.clone())
+      .await
+      .unwrap();
+    assert!(
+      UserRepository::delete(&RepositoryImpl::new(db_pool), user.id)
+        .await
+        .unwrap()
+    );
+
+    TestClient::new(app)
+      .call_unauthorized_request(
+        UserClient::me,
+        access_token,
+        json!({
+            "error": "invalid jwt token",
+            "code": 401
+        }),
+      )
+      .await;
+    TestClient::new(auth_app)
+      .call_unauthorized_request(
+        AuthClient::refresh_token,
+        refresh_token,
+        json!({
+            "error": "invalid jwt token",
+            "code": 401
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_user_update_invalid_restriction() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let payloads: [UserUpdateRequest; 3] = [
+      UserUpdateRequest {
+        status: Some(UserStatus::Banned),
+        status_until: Some(TimeUtils::utc_now() + Duration::days(1)),
+        ..Default::default()
+      },
+      UserUpdateRequest {
+        status: Some(UserStatus::Suspended),
+        status_until: Some(TimeUtils::utc_now() - Duration::days(1)),
+        ..Default::default()
+      },
+      UserUpdateRequest {
+        status_reason: Some("spam".to_string()),
+        ..Default::default()
+      },
+    ];
+    for payload in payloads {
+      let (status, body) = update_user(&test_client, user.id, &payload).await;
+      assert_eq!(status, StatusCode::BAD_REQUEST);
+      assert_eq!(
+        body,
+        json!({
+            "error": "invalid user request",
+            "code": 400
+        })
+      );
+    }
+  }
+
+  #[tokio::test]
+  async fn test_user_update_self_suspend_not_allowed() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let admin_id: Uuid = JwtManager::new(APP_CONFIG.jwt.clone())
+      .validate_jwt(&AuthSetup::get_admin_access_token(), TokenType::AccessToken)
+      .unwrap()
+      .sub;
+
+    let payload = UserUpdateRequest {
+      status: Some(UserStatus::Suspended),
+      ..Default::default()
+    };
+    let test_client: TestClient = TestClient::new(app);
+    let (status, _) = update_user(&test_client, admin_id, &payload).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+  }
+
+  #[tokio::test]
+  async fn test_user_update_permission_denied() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let payload = UserUpdateRequest {
+      status: Some(UserStatus::Banned),
+      ..Default::default()
+    };
+    let test_client: TestClient = TestClient::new(app);
+    test_client
+      .call_permission_denied_request(
+        |token| UserClient::update(Uuid::new_v4(), &payload, token),
+        AuthSetup::get_user_access_token(),
+        json!({
+            "error": "permission denied",
+            "code": 403
+        }),
+      )
+      .await;
+  }
+
+  #[tokio::test]
+  async fn test_user_filter_q() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let query: String = format!("q={}", user.email.to_uppercase());
+    let users: Vec<UserResponse> = filter_users(&test_client, &query).await;
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].id, user.id);
+
+    let users: Vec<UserResponse> = filter_users(&test_client, "q=%25").await;
+    assert!(users.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_user_filter_role_status_and_provider() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let payload = UserUpdateRequest {
+      role: Some(UserRole::Moderator),
+      status: Some(UserStatus::Banned),
+      ..Default::default()
+    };
+    let (status, _) = update_user(&test_client, user.id, &payload).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let query: String = format!("q={}&role=moderator&status=banned", user.email);
+    let users: Vec<UserResponse> = filter_users(&test_client, &query).await;
+    assert_eq!(users.len(), 1);
+
+    let query: String = format!("q={}&role=admin", user.email);
+    assert!(filter_users(&test_client, &query).await.is_empty());
+
+    let query: String = format!("q={}&auth_provider=github", user.email);
+    assert!(filter_users(&test_client, &query).await.is_empty());
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(APP_CONFIG.postgres.clone())
+      .await
+      .unwrap();
+    UserIdentityRepository::create(
+      &RepositoryImpl::new(db_pool),
+      user.id,
+      OAuth2Provider::Github,
+      Uuid::new_v4().to_string(),
+      Some(user.email.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(filter_users(&test_client, &query).await.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_user_filter_date_range() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let created_at = user.created_at.unwrap();
+    let from: String =
+      (created_at - Duration::minutes(1)).to_rfc3339_opts(SecondsFormat::Secs, true);
+    let to: String = (created_at + Duration::minutes(1)).to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let query: String = format!("q={}&created_from={}&created_to={}", user.email, from, to);
+    assert_eq!(filter_users(&test_client, &query).await.len(), 1);
+
+    let query: String = format!("q={}&created_from={}", user.email, to);
+    assert!(filter_users(&test_client, &query).await.is_empty());
+
+    let query: String = format!("q={}&logged_in_from={}", user.email, from);
+    assert!(filter_users(&test_client, &query).await.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_user_filter_invalid_parameters() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    for query in ["emial=a@b.c", "role=owner", "created_from=yesterday"] {
+      let response: Response = test_client
+        .get_response(UserClient::filter(
+          query,
+          AuthSetup::get_admin_access_token(),
+        ))
+        .await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+      let body: Value = test_client.get_body(response).await;
+      assert_eq!(body["error"], json!("invalid filter parameters"));
+      assert!(body["details"].is_string());
+    }
+  }
+
+  #[tokio::test]
+  async fn test_user_sort_invalid_parameters() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        "sort=-password",
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+        "error": "invalid sorting parameters",
+        "code": 400,
+        "details": "unknown sort field `password`, allowed fields: name, created_at, updated_at"
+      })
+    );
+
+    for query in [
+      "order_by=password",
+      "order_direction=up",
+      "sort=name,-name",
+      "sort=name,",
+      "sort=name&order_by=name",
+    ] {
+      let response: Response = test_client
+        .get_response(UserClient::filter(
+          query,
+          AuthSetup::get_admin_access_token(),
+        ))
+        .await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+      let body: Value = test_client.get_body(response).await;
+      assert_eq!(body["error"], json!("invalid sorting parameters"));
+    }
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        "page=first",
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body["error"], json!("invalid pagination parameters"));
+  }
+
+  #[tokio::test]
+  async fn test_user_all_pagination_metadata() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    AuthSetup::init_user(UserRole::User).await;
+    AuthSetup::init_user(UserRole::User).await;
+    let test_client: TestClient = TestClient::new(app);
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        "page=2&per_page=1",
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let link: String = response
+      .headers()
+      .get(http::header::LINK)
+      .unwrap()
+      .to_str()
+      .unwrap()
+      .to_string();
+    assert!(link.contains("</users?page=1&per_page=1>; rel=\"prev\""));
+    assert!(link.contains("</users?page=3&per_page=1>; rel=\"next\""));
+
+    let body: Value = test_client.get_body(response).await;
+    let total: u64 = body["total"].as_u64().unwrap();
+    assert!(total >= 2);
+    assert_eq!(body["page"], json!(2));
+    assert_eq!(body["per_page"], json!(1));
+    assert_eq!(body["total_pages"], json!(total));
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        &format!("page={}&per_page=1", total + 1),
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+    assert!(body["total"].as_u64().unwrap() >= total);
+  }
+
+  #[tokio::test]
+  async fn test_user_all_cursor_pagination() {
+    let (app, _) = init_setup().await;
+    let (_, auth_state, _) = AuthSetup::init().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let marker: String = Uuid::new_v4().simple().to_string();
+    let mut ids: Vec<Uuid> = Vec::new();
+    for name in ["a", "a", "b"] {
+      let user: UserEntity = auth_state
+        .manager
+        .sign_up(AuthSignUpRequest {
+          email: rand::random::<Email>().to_string().to_lowercase(),
+          password: rand::random::<FullName>().to_string(),
+          name: Some(format!("{} {}", marker, name)),
+        })
+        .await
+        .unwrap();
+      ids.push(user.id);
+    }
+    let test_client: TestClient = TestClient::new(app);
+
+    let query: String = format!("q={}&sort=name,-created_at&per_page=1", marker);
+    let mut cursor: Option<String> = None;
+    let mut seen: Vec<Uuid> = Vec::new();
+    loop {
+      let page: String = match &cursor {
+        Some(cursor) => format!("{}&cursor={}", query, cursor),
+        None => query.clone(),
+      };
+      let response: Response = test_client
+        .get_response(UserClient::filter(
+          &page,
+          AuthSetup::get_admin_access_token(),
+        ))
+        .await;
+      assert_eq!(response.status(), StatusCode::OK);
+      let body: Value = test_client.get_body(response).await;
+      assert_eq!(body["page"].is_null(), cursor.is_some());
+      for user in body["data"].as_array().unwrap() {
+        seen.push(serde_json::from_value(user["id"].clone()).unwrap());
+      }
+      cursor = body["next_cursor"].as_str().map(str::to_string);
+      if cursor.is_none() {
+        break;
+      }
+      assert!(seen.len() <= ids.len());
+    }
+    assert_eq!(seen, vec![ids[1], ids[0], ids[2]]);
+  }
+
+  #[tokio::test]
+  async fn test_user_all_cursor_invalid() {
+    let (app, _) = init_setup().await;
+    let (_, auth_state, _) = AuthSetup::init().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let marker: String = Uuid::new_v4().simple().to_string();
+    for _ in 0..2 {
+      auth_state
+        .manager
+        .sign_up(AuthSignUpRequest {
+          email: rand::random::<Email>().to_string().to_lowercase(),
+          password: rand::random::<FullName>().to_string(),
+          name: Some(marker.clone()),
+        })
+        .await
+        .unwrap();
+    }
+    let test_client: TestClient = TestClient::new(app);
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        &format!("q={}&per_page=1", marker),
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    let cursor: String = body["next_cursor"].as_str().unwrap().to_string();
+
+    for query in [
+      format!("q={}&page=1&cursor={}", marker, cursor),
+      format!("q={}&cursor={}x", marker, cursor),
+      format!("q={}&cursor=abc", marker),
+    ] {
+      let response: Response = test_client
+        .get_response(UserClient::filter(
+          &query,
+          AuthSetup::get_admin_access_token(),
+        ))
+        .await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        &format!("q={}&order_direction=desc&cursor={}", marker, cursor),
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(body, json!({"error": "invalid cursor", "code": 400}));
+  }
+
+  #[tokio::test]
+  async fn test_user_pagination_bounds() {
+    let (app, _) = init_setup().await;
+    AuthSetup::initialize_admin_tokens().await;
+    let test_client: TestClient = TestClient::new(app);
+    let max_per_page: usize = APP_CONFIG.pagination.max_per_page;
+
+    for (query, details) in [
+      ("per_page=0".to_string(), "per_page"),
+      (format!("per_page={}", max_per_page + 1), "per_page"),
+      ("page=0".to_string(), "page"),
+      ("per_page=-1".to_string(), "query string"),
+    ] {
+      let response: Response = test_client
+        .get_response(UserClient::filter(
+          &query,
+          AuthSetup::get_admin_access_token(),
+        ))
+        .await;
+      assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+      let body: Value = test_client.get_body(response).await;
+      assert_eq!(body["error"], json!("invalid pagination parameters"));
+      assert_eq!(body["code"], json!(400));
+      assert!(body["details"].as_str().unwrap().contains(details));
+    }
+
+    let response: Response = test_client
+      .get_response(UserClient::filter(
+        &format!("per_page={}", max_per_page),
+        AuthSetup::get_admin_access_token(),
+      ))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response: Response = test_client
+      .get_response(UserClient::filter("", AuthSetup::get_admin_access_token()))
+      .await;
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body["per_page"],
+      json!(APP_CONFIG.pagination.default_per_page)
+    );
+  }
 }
@@ -0,0 +1,141 @@
+use crate::api::client::api_keys::request::api_key_request::ApiKeyCreateRequest;
+use crate::api::client::api_keys::response::api_key_response::{
+  ApiKeyCreatedResponse, ApiKeyResponse,
+};
+use crate::api::manager::api_keys::api_key_response_converter::ApiKeyResponseConverter;
+use crate::api::repository::api_keys::constant::api_key_constant::{ApiKeyScope, API_KEY_PREFIX};
+use crate::api::repository::api_keys::entity::api_key_entity::ApiKeyEntity;
+use crate::api::repository::api_keys::repository::api_key_repository::ApiKeyRepository;
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::core::security::jwt_manager::{Claims, TokenType};
+use crate::utils::datetime_utils::TimeUtils;
+use crate::utils::hash_utils::HashUtils;
+use crate::utils::random_utils::RandomUtils;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const API_KEY_SECRET_LENGTH: usize = 40;
+
+pub trait ApiKeyManager: Send + Sync + 'static {
+  async fn all(&self, user_id: Uuid) -> Result<Vec<ApiKeyResponse>, AppError>;
+  async fn create(
+    &self,
+    user_id: Uuid,
+    payload: ApiKeyCreateRequest,
+  ) -> Result<ApiKeyCreatedResponse, AppError>;
+  async fn revoke(&self, user_id: Uuid, id: Uuid) -> Result<(), AppError>;
+  async fn authenticate(&self, key: &str) -> Result<Claims, AppError>;
+}
+
+#[derive(Clone)]
+pub struct ApiKeyManagerImpl {
+  api_key_repository: Arc<dyn ApiKeyRepository>,
+  user_repository: Arc<dyn UserRepository>,
+}
+
+impl ApiKeyManagerImpl {
+  pub fn new(
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    user_repository: Arc<dyn UserRepository>,
+  ) -> Self {
+    Self {
+      api_key_repository,
+      user_repository,
+    }
+  }
+
+  /// Keys have the form `pat_{id}_{secret}` so the row can be looked up by id
+  /// before the secret is checked against its digest.
+  fn parse_key(key: &str) -> Option<(Uuid, &str)> {
+    let (id, secret) = key.strip_prefix(API_KEY_PREFIX)?.split_once('_')?;
+    Some((Uuid::parse_str(id).ok()?, secret))
+  }
+}
+
+impl ApiKeyManager for ApiKeyManagerImpl {
+  async fn all(&self, user_id: Uuid) -> Result<Vec<ApiKeyResponse>, AppError> {
+    let api_keys: Vec<ApiKeyEntity> = self.api_key_repository.by_user_id(user_id).await?;
+    Ok(
+      api_keys
+        .into_iter()
+        .map(Self::api_key_response_converter)
+        .collect(),
+    )
+  }
+
+  async fn create(
+    &self,
+    user_id: Uuid,
+    payload: ApiKeyCreateRequest,
+  ) -> Result<ApiKeyCreatedResponse, AppError> {
+    if payload.scopes.is_empty() {
+      return Err(AppError::ApiKeyInvalidRequest);
+    }
+    if matches!(payload.expires_at, Some(expires_at) if expires_at <= TimeUtils::utc_now()) {
+      return Err(AppError::ApiKeyInvalidRequest);
+    }
+
+    let id: Uuid = Uuid::new_v4();
+    let secret: String = RandomUtils::alphanumeric(API_KEY_SECRET_LENGTH);
+    let key: String = format!("{}{}_{}", API_KEY_PREFIX, id.simple(), secret);
+
+    let api_key: ApiKeyEntity = self
+      .api_key_repository
+      .create(
+        id,
+        user_id,
+        payload.name,
+        HashUtils::hash_secret(&secret),
+        ApiKeyScope::to_strings(&payload.scopes),
+        payload.expires_at,
+      )
+      .await?;
+    Ok(Self::api_key_created_response_converter(api_key, key))
+  }
+
+  async fn revoke(&self, user_id: Uuid, id: Uuid) -> Result<(), AppError> {
+    match self.api_key_repository.revoke(id, user_id).await? {
+      true => Ok(()),
+      false => Err(AppError::ApiKeyNotFound),
+    }
+  }
+
+  async fn authenticate(&self, key: &str) -> Result<Claims, AppError> {
+    let (id, secret) = Self::parse_key(key).ok_or(AppError::InvalidApiKey)?;
+
+    let api_key: ApiKeyEntity = match self.api_key_repository.by_id(id).await? {
+      Some(api_key) if HashUtils::verify_secret(secret, &api_key.key_hash) => api_key,
+      _ => return Err(AppError::InvalidApiKey),
+    };
+    if matches!(api_key.expires_at, Some(expires_at) if expires_at <= TimeUtils::utc_now()) {
+      return Err(AppError::InvalidApiKey);
+    }
+
+    let user: UserEntity = self
+      .user_repository
+      .me(api_key.user_id)
+      .await
+      .map_err(|_| AppError::InvalidApiKey)?;
+    self
+      .api_key_repository
+      .update_last_used_at(api_key.id)
+      .await?;
+
+    Ok(Claims {
+      jti: api_key.id,
+      token_type: TokenType::AccessToken,
+      sub: user.id,
+      exp: api_key
+        .expires_at
+        .map(|expires_at| expires_at.timestamp() as usize)
+        .unwrap_or(0),
+      role: user.role,
+      scopes: Some(api_key.scopes),
+      client_id: None,
+      act: None,
+      azp: None,
+    })
+  }
+}
@@ -0,0 +1,36 @@
+use crate::api::client::api_keys::response::api_key_response::{
+  ApiKeyCreatedResponse, ApiKeyResponse,
+};
+use crate::api::manager::api_keys::api_key_manager::ApiKeyManagerImpl;
+use crate::api::repository::api_keys::entity::api_key_entity::ApiKeyEntity;
+
+pub trait ApiKeyResponseConverter {
+  fn api_key_response_converter(api_key: ApiKeyEntity) -> ApiKeyResponse;
+  fn api_key_created_response_converter(
+    api_key: ApiKeyEntity,
+    key: String,
+  ) -> ApiKeyCreatedResponse;
+}
+
+impl ApiKeyResponseConverter for ApiKeyManagerImpl {
+  fn api_key_response_converter(api_key: ApiKeyEntity) -> ApiKeyResponse {
+    ApiKeyResponse {
+      id: api_key.id,
+      name: api_key.name,
+      scopes: api_key.scopes,
+      expires_at: api_key.expires_at,
+      last_used_at: api_key.last_used_at,
+      created_at: api_key.created_at,
+    }
+  }
+
+  fn api_key_created_response_converter(
+    api_key: ApiKeyEntity,
+    key: String,
+  ) -> ApiKeyCreatedResponse {
+    ApiKeyCreatedResponse {
+      api_key: Self::api_key_response_converter(api_key),
+      key,
+    }
+  }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod api_key_manager;
+mod api_key_response_converter;
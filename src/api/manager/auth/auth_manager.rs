@@ -1,31 +1,56 @@
 use crate::api::client::auth::request::auth_request::{
-  AuthSignInRequest, AuthSignUpRequest, OAuth2SignInRequest,
+  AcceptInviteRequest, AuthSignInRequest, AuthSignUpRequest, MagicLinkRequest,
+  MagicLinkVerifyRequest, OAuth2SignInRequest, SamlAcsRequest, SamlTenantConfigureRequest,
+  SamlTenantCreateRequest,
 };
-use crate::api::client::auth::response::auth_response::SignInResponse;
+use crate::api::client::auth::response::auth_response::{SamlTenantResponse, SignInResponse};
+use crate::api::client::identities::response::identity_response::IdentityResponse;
 use crate::api::client::oauth2::response::oauth2_response::OAuth2UserInfo;
 use crate::api::manager::auth::auth_response_converter::AuthResponseConverter;
+use crate::api::manager::auth::password_authenticator::PasswordAuthenticator;
+use crate::api::repository::magic_links::entity::magic_link_entity::MagicLinkEntity;
+use crate::api::repository::magic_links::repository::magic_link_repository::MagicLinkRepository;
+use crate::api::repository::oauth2_states::entity::oauth2_state_entity::OAuth2StateEntity;
+use crate::api::repository::oauth2_states::repository::oauth2_state_repository::OAuth2StateRepository;
 use crate::api::repository::refresh_tokens::entity::refresh_tokens_entity::RefreshTokenEntity;
 use crate::api::repository::refresh_tokens::repository::refresh_token_repository::RefreshTokenRepository;
+use crate::api::repository::saml_assertions::repository::saml_assertion_repository::SamlAssertionRepository;
+use crate::api::repository::saml_tenants::entity::saml_tenant_entity::SamlTenantEntity;
+use crate::api::repository::saml_tenants::repository::saml_tenant_repository::SamlTenantRepository;
+use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentityEntity;
+use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+use crate::api::repository::user_invitations::entity::user_invitation_entity::UserInvitationEntity;
+use crate::api::repository::user_invitations::repository::user_invitation_repository::UserInvitationRepository;
 use crate::api::repository::users::constant::user_constant::{OAuth2Provider, UserRole};
 use crate::api::repository::users::entity::user_entity::UserEntity;
 use crate::api::repository::users::repository::user_repository::UserRepository;
 use crate::core::error::error::AppError;
-use crate::core::security::jwt_manager::{JwtManager, TokenType};
+use crate::core::security::jwt_manager::{
+  InvitationClaims, JwtManager, MagicLinkClaims, TokenPurpose, TokenType,
+};
 use crate::job::event::event::JobEventType;
+use crate::job::event::mail::mail_event::JobMailMessage;
 use crate::job::event::users::user_event::JobUserMessage;
 use crate::job::kind::JobKind;
 use crate::job::message::JobMessage;
+use crate::resources::config::MagicLinkConfig;
 use crate::service::aws::sqs::producer::producer::SQSProducer;
-use crate::service::oauth2::oauth2::OAuth2Service;
+use crate::service::oauth2::oauth2::{OAuth2Authorization, OAuth2Service};
+use crate::service::saml::saml::{SamlAssertion, SamlService};
+use crate::utils::datetime_utils::TimeUtils;
 use crate::utils::hash_utils::HashUtils;
+use chrono::{DateTime, Duration, Utc};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
+const OAUTH2_STATE_EXPIRATION_SECONDS: i64 = 600;
+
 pub trait AuthManager: Send + Sync + 'static {
   async fn sign_up(&self, payload: AuthSignUpRequest) -> Result<UserEntity, AppError>;
   async fn sign_in(&self, payload: AuthSignInRequest) -> Result<SignInResponse, AppError>;
-  async fn send_verify_user_event(&self, user_id: Uuid) -> ();
+  async fn send_verify_user_event(&self, user_id: Uuid, picture_url: Option<String>) -> ();
   async fn generate_tokens(&self, user_id: Uuid, user_role: UserRole) -> (String, String);
   async fn sign_in_oauth2_get_redirect_uri(
     &self,
@@ -37,6 +62,13 @@ pub trait AuthManager: Send + Sync + 'static {
     provider: OAuth2Provider,
     payload: OAuth2SignInRequest,
   ) -> Result<SignInResponse, AppError>;
+  async fn link_identity(
+    &self,
+    user_id: Uuid,
+    provider: OAuth2Provider,
+    payload: OAuth2SignInRequest,
+  ) -> Result<IdentityResponse, AppError>;
+  async fn unlink_identity(&self, user_id: Uuid, provider: OAuth2Provider) -> Result<(), AppError>;
   async fn sign_out(&self, jti: Uuid) -> Result<(), AppError>;
   async fn refresh_token(
     &self,
@@ -44,6 +76,28 @@ pub trait AuthManager: Send + Sync + 'static {
     user_id: Uuid,
     user_role: UserRole,
   ) -> Result<SignInResponse, AppError>;
+  async fn create_magic_link(&self, email: String) -> Result<String, AppError>;
+  async fn send_magic_link(&self, payload: MagicLinkRequest) -> Result<(), AppError>;
+  async fn sign_in_magic_link(
+    &self,
+    payload: MagicLinkVerifyRequest,
+  ) -> Result<SignInResponse, AppError>;
+  async fn saml_metadata(&self, tenant: String) -> Result<String, AppError>;
+  async fn sign_in_saml(
+    &self,
+    tenant: String,
+    payload: SamlAcsRequest,
+  ) -> Result<SignInResponse, AppError>;
+  async fn create_saml_tenant(
+    &self,
+    payload: SamlTenantCreateRequest,
+  ) -> Result<SamlTenantResponse, AppError>;
+  async fn configure_saml_tenant(
+    &self,
+    tenant: String,
+    payload: SamlTenantConfigureRequest,
+  ) -> Result<SamlTenantResponse, AppError>;
+  async fn accept_invite(&self, payload: AcceptInviteRequest) -> Result<SignInResponse, AppError>;
 }
 
 #[derive(Clone)]
@@ -52,24 +106,112 @@ pub struct AuthManagerImpl {
   refresh_token_repository: Arc<dyn RefreshTokenRepository>,
   jwt_manager: JwtManager,
   sqs_producer: Arc<dyn SQSProducer>,
-  google_oauth2: Arc<dyn OAuth2Service>,
+  oauth2_services: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>>,
+  magic_link_repository: Arc<dyn MagicLinkRepository>,
+  magic_link_config: MagicLinkConfig,
+  oauth2_state_repository: Arc<dyn OAuth2StateRepository>,
+  user_identity_repository: Arc<dyn UserIdentityRepository>,
+  saml_tenant_repository: Arc<dyn SamlTenantRepository>,
+  saml_assertion_repository: Arc<dyn SamlAssertionRepository>,
+  saml_service: Arc<dyn SamlService>,
+  password_authenticator: Arc<dyn PasswordAuthenticator>,
+  user_invitation_repository: Arc<dyn UserInvitationRepository>,
 }
 
 impl AuthManagerImpl {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     user_repository: Arc<dyn UserRepository>,
     refresh_token_repository: Arc<dyn RefreshTokenRepository>,
     jwt_manager: JwtManager,
     sqs_producer: Arc<dyn SQSProducer>,
-    google_oauth2: Arc<dyn OAuth2Service>,
+    oauth2_services: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>>,
+    magic_link_repository: Arc<dyn MagicLinkRepository>,
+    magic_link_config: MagicLinkConfig,
+    oauth2_state_repository: Arc<dyn OAuth2StateRepository>,
+    user_identity_repository: Arc<dyn UserIdentityRepository>,
+    saml_tenant_repository: Arc<dyn SamlTenantRepository>,
+    saml_assertion_repository: Arc<dyn SamlAssertionRepository>,
+    saml_service: Arc<dyn SamlService>,
+    password_authenticator: Arc<dyn PasswordAuthenticator>,
+    user_invitation_repository: Arc<dyn UserInvitationRepository>,
   ) -> Self {
     Self {
       user_repository,
       refresh_token_repository,
       jwt_manager,
       sqs_producer,
-      google_oauth2,
+      oauth2_services,
+      magic_link_repository,
+      magic_link_config,
+      oauth2_state_repository,
+      user_identity_repository,
+      saml_tenant_repository,
+      saml_assertion_repository,
+      saml_service,
+      password_authenticator,
+      user_invitation_repository,
+    }
+  }
+
+  fn oauth2_service(&self, provider: OAuth2Provider) -> Result<Arc<dyn OAuth2Service>, AppError> {
+    self
+      .oauth2_services
+      .get(&provider)
+      .cloned()
+      .ok_or(AppError::InvalidOauth2Provider)
+  }
+
+  async fn saml_tenant(&self, tenant: String) -> Result<SamlTenantEntity, AppError> {
+    self
+      .saml_tenant_repository
+      .by_slug(tenant)
+      .await?
+      .ok_or(AppError::SamlTenantNotFound)
+  }
+
+  /// Checks the IdP certificate parses and returns the allowed email domains, lowercased.
+  fn saml_allowed_domains(
+    &self,
+    idp_certificate: &str,
+    allowed_domains: Vec<String>,
+  ) -> Result<Vec<String>, AppError> {
+    let allowed_domains: Vec<String> = allowed_domains
+      .iter()
+      .map(|domain| domain.trim().to_lowercase())
+      .collect();
+    if !self.saml_service.is_valid_certificate(idp_certificate)
+      || allowed_domains.is_empty()
+      || allowed_domains
+        .iter()
+        .any(|domain| domain.is_empty() || domain.contains(['@', ' ']))
+    {
+      return Err(AppError::InvalidSamlTenant);
     }
+    Ok(allowed_domains)
+  }
+
+  fn saml_tenant_response(&self, tenant: SamlTenantEntity) -> SamlTenantResponse {
+    let entity_id: String = self.saml_service.entity_id(&tenant);
+    let acs_url: String = self.saml_service.acs_url(&tenant);
+    Self::saml_tenant_response_converter(tenant, entity_id, acs_url)
+  }
+
+  /// Checks the stored state for this provider and exchanges the code for the user's profile.
+  async fn oauth2_user_info(
+    &self,
+    provider: OAuth2Provider,
+    payload: OAuth2SignInRequest,
+  ) -> Result<OAuth2UserInfo, AppError> {
+    let oauth2_state: OAuth2StateEntity = self
+      .oauth2_state_repository
+      .consume(payload.state, provider)
+      .await?
+      .ok_or(AppError::InvalidOauth2State)?;
+    self
+      .oauth2_service(provider)?
+      .sign_in(payload.code, oauth2_state.pkce_verifier, oauth2_state.nonce)
+      .await
   }
 }
 
@@ -89,40 +231,38 @@ impl AuthManager for AuthManagerImpl {
             lowercase_email,
             Some(HashUtils::hash_password(&payload.password)),
             payload.name,
-            None,
-            None,
           )
           .await?;
-        self.send_verify_user_event(new_user.id).await;
+        self.send_verify_user_event(new_user.id, None).await;
         Ok(new_user)
       }
     }
   }
 
   async fn sign_in(&self, payload: AuthSignInRequest) -> Result<SignInResponse, AppError> {
-    match self.user_repository.by_email(payload.email).await? {
-      Some(user)
-        if HashUtils::verify_password(&payload.password, user.password.as_ref().unwrap()) =>
-      {
-        let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
-        self.user_repository.update_logged_in_at(user.id).await?;
-        Ok(Self::auth_token_response_converter(
-          access_token,
-          Some(refresh_token),
-        ))
-      }
-      Some(_) => Err(AppError::UserPasswordIncorrect),
-      None => Err(AppError::UserNotFound),
-    }
+    let user: UserEntity = self
+      .password_authenticator
+      .authenticate(payload.email, payload.password)
+      .await?;
+    user.ensure_active()?;
+    let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
+    self.user_repository.update_logged_in_at(user.id).await?;
+    Ok(Self::auth_token_response_converter(
+      access_token,
+      Some(refresh_token),
+    ))
   }
 
-  async fn send_verify_user_event(&self, user_id: Uuid) -> () {
+  async fn send_verify_user_event(&self, user_id: Uuid, picture_url: Option<String>) -> () {
     let message: Value = JobMessage::new(
       JobEventType::UserEvent.as_str(),
-      JobUserMessage::new(user_id, true),
+      JobUserMessage::new(user_id, true, picture_url),
     )
     .to_value();
-    let _ = self.sqs_producer.send(JobKind::Local.as_str(), message).await;
+    let _ = self
+      .sqs_producer
+      .send(JobKind::Local.as_str(), message)
+      .await;
   }
 
   async fn generate_tokens(&self, user_id: Uuid, user_role: UserRole) -> (String, String) {
@@ -147,11 +287,19 @@ impl AuthManager for AuthManagerImpl {
     &self,
     provider: OAuth2Provider,
   ) -> Result<String, AppError> {
-    let oauth2: Arc<dyn OAuth2Service> = match provider {
-      OAuth2Provider::Google => self.google_oauth2.clone(),
-    };
-    let redirect_url: String = oauth2.get_redirect_url().await?;
-    Ok(redirect_url)
+    let oauth2: Arc<dyn OAuth2Service> = self.oauth2_service(provider)?;
+    let authorization: OAuth2Authorization = oauth2.get_redirect_url().await?;
+    self
+      .oauth2_state_repository
+      .create(
+        authorization.state,
+        provider,
+        authorization.pkce_verifier,
+        authorization.nonce,
+        TimeUtils::utc_now() + Duration::seconds(OAUTH2_STATE_EXPIRATION_SECONDS),
+      )
+      .await?;
+    Ok(authorization.url)
   }
 
   async fn sign_in_oauth2(
@@ -159,29 +307,41 @@ impl AuthManager for AuthManagerImpl {
     provider: OAuth2Provider,
     payload: OAuth2SignInRequest,
   ) -> Result<SignInResponse, AppError> {
-    let oauth2_service: Arc<dyn OAuth2Service> = self.google_oauth2.clone();
-    let user_info: OAuth2UserInfo = oauth2_service.sign_in(payload).await?;
+    let user_info: OAuth2UserInfo = self.oauth2_user_info(provider, payload).await?;
     let user: UserEntity = match self
-      .user_repository
-      .by_auth_provider(user_info.sub.clone(), provider)
+      .user_identity_repository
+      .by_subject(provider, user_info.sub.clone())
       .await?
     {
-      Some(existing_user) => existing_user,
+      Some(identity) => self
+        .user_repository
+        .by_id(identity.user_id)
+        .await?
+        .ok_or(AppError::UserNotFound)?,
       None => {
-        let new_user: UserEntity = self
-          .user_repository
-          .create(
-            user_info.email,
-            None,
-            user_info.name,
-            Some(user_info.sub),
-            Some(provider),
-          )
+        let email: String = user_info.email.to_lowercase();
+        let user: UserEntity = match self.user_repository.by_email(email.clone()).await? {
+          Some(_) if !user_info.email_verified => return Err(AppError::UserExistingEmail),
+          Some(existing_user) => existing_user,
+          None => {
+            let new_user: UserEntity = self
+              .user_repository
+              .create(email.clone(), None, user_info.name)
+              .await?;
+            self
+              .send_verify_user_event(new_user.id, user_info.picture)
+              .await;
+            new_user
+          }
+        };
+        self
+          .user_identity_repository
+          .create(user.id, provider, user_info.sub, Some(email))
           .await?;
-        self.send_verify_user_event(new_user.id).await;
-        new_user
+        user
       }
     };
+    user.ensure_active()?;
     let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
     self.user_repository.update_logged_in_at(user.id).await?;
     Ok(Self::auth_token_response_converter(
@@ -190,6 +350,63 @@ impl AuthManager for AuthManagerImpl {
     ))
   }
 
+  async fn link_identity(
+    &self,
+    user_id: Uuid,
+    provider: OAuth2Provider,
+    payload: OAuth2SignInRequest,
+  ) -> Result<IdentityResponse, AppError> {
+    let user_info: OAuth2UserInfo = self.oauth2_user_info(provider, payload).await?;
+    let linked: bool = self
+      .user_identity_repository
+      .by_subject(provider, user_info.sub.clone())
+      .await?
+      .is_some()
+      || self
+        .user_identity_repository
+        .by_user_id(user_id)
+        .await?
+        .iter()
+        .any(|identity| identity.provider == provider);
+    if linked {
+      return Err(AppError::IdentityAlreadyLinked);
+    }
+    let identity: UserIdentityEntity = self
+      .user_identity_repository
+      .create(
+        user_id,
+        provider,
+        user_info.sub,
+        Some(user_info.email.to_lowercase()),
+      )
+      .await?;
+    Ok(Self::identity_response_converter(identity))
+  }
+
+  async fn unlink_identity(&self, user_id: Uuid, provider: OAuth2Provider) -> Result<(), AppError> {
+    let user: UserEntity = self
+      .user_repository
+      .by_id(user_id)
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    let identities: Vec<UserIdentityEntity> =
+      self.user_identity_repository.by_user_id(user_id).await?;
+    if !identities
+      .iter()
+      .any(|identity| identity.provider == provider)
+    {
+      return Err(AppError::IdentityNotFound);
+    }
+    if user.password.is_none() && identities.len() == 1 {
+      return Err(AppError::IdentityUnlinkNotAllowed);
+    }
+    self
+      .user_identity_repository
+      .delete(user_id, provider)
+      .await?;
+    Ok(())
+  }
+
   async fn sign_out(&self, jti: Uuid) -> Result<(), AppError> {
     self.refresh_token_repository.delete(jti).await?;
     Ok(())
@@ -204,6 +421,13 @@ impl AuthManager for AuthManagerImpl {
     let refresh_token_entity: Option<RefreshTokenEntity> =
       self.refresh_token_repository.by_id(jti).await?;
 
+    self
+      .user_repository
+      .by_id(user_id)
+      .await?
+      .ok_or(AppError::InvalidJwtToken)?
+      .ensure_active()?;
+
     match refresh_token_entity {
       Some(_) => {
         let access_token: String =
@@ -216,4 +440,246 @@ impl AuthManager for AuthManagerImpl {
       None => Err(AppError::InvalidJwtToken),
     }
   }
+
+  async fn create_magic_link(&self, email: String) -> Result<String, AppError> {
+    let expires_at: DateTime<Utc> =
+      TimeUtils::utc_now() + Duration::seconds(self.magic_link_config.expiration_seconds);
+    let magic_link: MagicLinkEntity = self
+      .magic_link_repository
+      .create(email.to_lowercase(), expires_at)
+      .await?;
+
+    let token: String = self.jwt_manager.sign(&MagicLinkClaims {
+      jti: magic_link.id,
+      typ: TokenPurpose::MagicLink,
+      email: magic_link.email,
+      exp: magic_link.expires_at.timestamp() as usize,
+    });
+    Ok(format!("{}?token={}", self.magic_link_config.url, token))
+  }
+
+  async fn send_magic_link(&self, payload: MagicLinkRequest) -> Result<(), AppError> {
+    let email: String = payload.email.to_lowercase();
+    let link: String = self.create_magic_link(email.clone()).await?;
+    let message: Value = JobMessage::new(
+      JobEventType::MailEvent.as_str(),
+      JobMailMessage::new(
+        email,
+        "Your sign-in link".to_string(),
+        format!(
+          "Use the link below to sign in. It can only be used once.\n\n{}",
+          link
+        ),
+      ),
+    )
+    .to_value();
+    self
+      .sqs_producer
+      .send(JobKind::Local.as_str(), message)
+      .await
+  }
+
+  async fn sign_in_magic_link(
+    &self,
+    payload: MagicLinkVerifyRequest,
+  ) -> Result<SignInResponse, AppError> {
+    let claims: MagicLinkClaims = self
+      .jwt_manager
+      .verify(&payload.token)
+      .filter(|claims: &MagicLinkClaims| claims.typ == TokenPurpose::MagicLink)
+      .ok_or(AppError::InvalidMagicLink)?;
+    if !self.magic_link_repository.consume(claims.jti).await? {
+      return Err(AppError::InvalidMagicLink);
+    }
+
+    let user: UserEntity = match self.user_repository.by_email(claims.email.clone()).await? {
+      Some(existing_user) => existing_user,
+      None => {
+        let new_user: UserEntity = self
+          .user_repository
+          .create(claims.email, None, None)
+          .await?;
+        self.send_verify_user_event(new_user.id, None).await;
+        new_user
+      }
+    };
+    user.ensure_active()?;
+    let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
+    self.user_repository.update_logged_in_at(user.id).await?;
+    Ok(Self::auth_token_response_converter(
+      access_token,
+      Some(refresh_token),
+    ))
+  }
+
+  async fn saml_metadata(&self, tenant: String) -> Result<String, AppError> {
+    let tenant: SamlTenantEntity = self.saml_tenant(tenant).await?;
+    Ok(self.saml_service.metadata(&tenant))
+  }
+
+  async fn sign_in_saml(
+    &self,
+    tenant: String,
+    payload: SamlAcsRequest,
+  ) -> Result<SignInResponse, AppError> {
+    let tenant: SamlTenantEntity = self.saml_tenant(tenant).await?;
+    let assertion: SamlAssertion = self
+      .saml_service
+      .validate(&tenant, &payload.saml_response)?;
+    if !self
+      .saml_assertion_repository
+      .consume(tenant.id, assertion.id, assertion.expires_at)
+      .await?
+    {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    // Accounts are only reached through the identity this tenant and NameID were linked to,
+    // never through a matching email.
+    let subject: String = format!("{}:{}", tenant.id, assertion.name_id);
+    let user: UserEntity = match self
+      .user_identity_repository
+      .by_subject(OAuth2Provider::Saml, subject.clone())
+      .await?
+    {
+      Some(identity) => self
+        .user_repository
+        .by_id(identity.user_id)
+        .await?
+        .ok_or(AppError::UserNotFound)?,
+      None => {
+        if self
+          .user_repository
+          .by_email(assertion.email.clone())
+          .await?
+          .is_some()
+        {
+          return Err(AppError::UserExistingEmail);
+        }
+        let new_user: UserEntity = self
+          .user_repository
+          .create(assertion.email.clone(), None, assertion.name)
+          .await?;
+        self
+          .user_identity_repository
+          .create(
+            new_user.id,
+            OAuth2Provider::Saml,
+            subject,
+            Some(assertion.email),
+          )
+          .await?;
+        self.send_verify_user_event(new_user.id, None).await;
+        new_user
+      }
+    };
+    user.ensure_active()?;
+    let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
+    self.user_repository.update_logged_in_at(user.id).await?;
+    Ok(Self::auth_token_response_converter(
+      access_token,
+      Some(refresh_token),
+    ))
+  }
+
+  async fn create_saml_tenant(
+    &self,
+    payload: SamlTenantCreateRequest,
+  ) -> Result<SamlTenantResponse, AppError> {
+    // Slugs end up in the SP URLs, where `tenants` is taken by these admin routes.
+    if payload.slug == "tenants"
+      || payload.slug.is_empty()
+      || !payload
+        .slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+      return Err(AppError::InvalidSamlTenant);
+    }
+    let allowed_domains: Vec<String> =
+      self.saml_allowed_domains(&payload.idp_certificate, payload.allowed_domains)?;
+    if self
+      .saml_tenant_repository
+      .by_slug(payload.slug.clone())
+      .await?
+      .is_some()
+    {
+      return Err(AppError::SamlTenantExisting);
+    }
+    let tenant: SamlTenantEntity = self
+      .saml_tenant_repository
+      .create(
+        payload.slug,
+        payload.idp_entity_id,
+        payload.idp_sso_url,
+        payload.idp_certificate,
+        payload.email_attribute,
+        payload.name_attribute,
+        allowed_domains,
+      )
+      .await?;
+    Ok(self.saml_tenant_response(tenant))
+  }
+
+  async fn configure_saml_tenant(
+    &self,
+    tenant: String,
+    payload: SamlTenantConfigureRequest,
+  ) -> Result<SamlTenantResponse, AppError> {
+    let allowed_domains: Vec<String> =
+      self.saml_allowed_domains(&payload.idp_certificate, payload.allowed_domains)?;
+    let tenant: SamlTenantEntity = self
+      .saml_tenant_repository
+      .configure(
+        tenant,
+        payload.idp_entity_id,
+        payload.idp_sso_url,
+        payload.idp_certificate,
+        payload.email_attribute,
+        payload.name_attribute,
+        allowed_domains,
+      )
+      .await?
+      .ok_or(AppError::SamlTenantNotFound)?;
+    Ok(self.saml_tenant_response(tenant))
+  }
+
+  /// Invited accounts skip email verification, since following the emailed link proves the
+  /// address, and start with the role the admin picked.
+  async fn accept_invite(&self, payload: AcceptInviteRequest) -> Result<SignInResponse, AppError> {
+    let claims: InvitationClaims = self
+      .jwt_manager
+      .verify(&payload.token)
+      .filter(|claims: &InvitationClaims| claims.typ == TokenPurpose::Invitation)
+      .ok_or(AppError::InvalidInvitation)?;
+    let invitation: UserInvitationEntity = self
+      .user_invitation_repository
+      .by_id(claims.jti)
+      .await?
+      .filter(|invitation| invitation.email == claims.email)
+      .ok_or(AppError::InvalidInvitation)?;
+    if self
+      .user_repository
+      .by_email(invitation.email.clone())
+      .await?
+      .is_some()
+    {
+      return Err(AppError::UserExistingEmail);
+    }
+    let user: UserEntity = self
+      .user_invitation_repository
+      .accept(
+        &invitation,
+        HashUtils::hash_password(&payload.password),
+        payload.name,
+      )
+      .await?
+      .ok_or(AppError::InvalidInvitation)?;
+    let (access_token, refresh_token) = self.generate_tokens(user.id, user.role).await;
+    self.user_repository.update_logged_in_at(user.id).await?;
+    Ok(Self::auth_token_response_converter(
+      access_token,
+      Some(refresh_token),
+    ))
+  }
 }
@@ -1,11 +1,20 @@
-use crate::api::client::auth::response::auth_response::SignInResponse;
+use crate::api::client::auth::response::auth_response::{SamlTenantResponse, SignInResponse};
+use crate::api::client::identities::response::identity_response::IdentityResponse;
 use crate::api::manager::auth::auth_manager::AuthManagerImpl;
+use crate::api::repository::saml_tenants::entity::saml_tenant_entity::SamlTenantEntity;
+use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentityEntity;
 
 pub trait AuthResponseConverter {
   fn auth_token_response_converter(
     access_token: String,
     refresh_token: Option<String>,
   ) -> SignInResponse;
+  fn identity_response_converter(identity: UserIdentityEntity) -> IdentityResponse;
+  fn saml_tenant_response_converter(
+    tenant: SamlTenantEntity,
+    entity_id: String,
+    acs_url: String,
+  ) -> SamlTenantResponse;
 }
 
 impl AuthResponseConverter for AuthManagerImpl {
@@ -18,4 +27,32 @@ impl AuthResponseConverter for AuthManagerImpl {
       refresh_token,
     }
   }
+
+  fn identity_response_converter(identity: UserIdentityEntity) -> IdentityResponse {
+    IdentityResponse {
+      id: identity.id,
+      provider: identity.provider,
+      email: identity.email,
+      created_at: identity.created_at,
+    }
+  }
+
+  fn saml_tenant_response_converter(
+    tenant: SamlTenantEntity,
+    entity_id: String,
+    acs_url: String,
+  ) -> SamlTenantResponse {
+    SamlTenantResponse {
+      slug: tenant.slug,
+      entity_id,
+      acs_url,
+      idp_entity_id: tenant.idp_entity_id,
+      idp_sso_url: tenant.idp_sso_url,
+      email_attribute: tenant.email_attribute,
+      name_attribute: tenant.name_attribute,
+      allowed_domains: tenant.allowed_domains,
+      created_at: tenant.created_at,
+      updated_at: tenant.updated_at,
+    }
+  }
 }
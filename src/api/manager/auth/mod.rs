@@ -1,2 +1,3 @@
 pub(crate) mod auth_manager;
 mod auth_response_converter;
+pub(crate) mod password_authenticator;
@@ -0,0 +1,138 @@
+use crate::api::repository::user_identities::repository::user_identity_repository::UserIdentityRepository;
+use crate::api::repository::users::constant::user_constant::{OAuth2Provider, UserStatus};
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::service::ldap::ldap::{LdapService, LdapUser};
+use crate::utils::hash_utils::HashUtils;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Backend that checks an email and password pair for `sign_in`.
+#[async_trait]
+pub trait PasswordAuthenticator: Send + Sync + 'static {
+  async fn authenticate(&self, email: String, password: String) -> Result<UserEntity, AppError>;
+}
+
+/// Checks the bcrypt hash stored with the local account.
+pub struct LocalPasswordAuthenticator {
+  user_repository: Arc<dyn UserRepository>,
+}
+
+impl LocalPasswordAuthenticator {
+  pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+    Self { user_repository }
+  }
+}
+
+#[async_trait]
+impl PasswordAuthenticator for LocalPasswordAuthenticator {
+  async fn authenticate(&self, email: String, password: String) -> Result<UserEntity, AppError> {
+    match self.user_repository.by_email(email).await? {
+      Some(user)
+        if user
+          .password
+          .as_deref()
+          .is_some_and(|hash| HashUtils::verify_password(&password, hash)) =>
+      {
+        Ok(user)
+      }
+      Some(_) => Err(AppError::UserPasswordIncorrect),
+      None => Err(AppError::UserNotFound),
+    }
+  }
+}
+
+/// Binds against the directory first and falls back to the local password, so local accounts
+/// keep working with LDAP enabled. Only accounts linked to an LDAP identity are signed in
+/// through the directory: the first sign-in provisions and links one, and its role follows the
+/// directory groups on every sign-in. A local account with the same email is never taken over.
+pub struct LdapPasswordAuthenticator {
+  user_repository: Arc<dyn UserRepository>,
+  user_identity_repository: Arc<dyn UserIdentityRepository>,
+  ldap_service: Arc<dyn LdapService>,
+  local: LocalPasswordAuthenticator,
+}
+
+impl LdapPasswordAuthenticator {
+  pub fn new(
+    user_repository: Arc<dyn UserRepository>,
+    user_identity_repository: Arc<dyn UserIdentityRepository>,
+    ldap_service: Arc<dyn LdapService>,
+  ) -> Self {
+    Self {
+      local: LocalPasswordAuthenticator::new(user_repository.clone()),
+      user_repository,
+      user_identity_repository,
+      ldap_service,
+    }
+  }
+
+  /// The account linked to the directory entry, provisioning it on first sign-in. `None` when
+  /// the email belongs to an account that didn't come from LDAP.
+  async fn directory_user(&self, ldap_user: LdapUser) -> Result<Option<UserEntity>, AppError> {
+    let mut user: UserEntity = match self
+      .user_identity_repository
+      .by_subject(OAuth2Provider::Ldap, ldap_user.dn.clone())
+      .await?
+    {
+      Some(identity) => match self.user_repository.by_id(identity.user_id).await? {
+        Some(user) => user,
+        None => return Ok(None),
+      },
+      None => {
+        if self
+          .user_repository
+          .email_exists(ldap_user.email.clone())
+          .await?
+        {
+          return Ok(None);
+        }
+        let new_user: UserEntity = self
+          .user_repository
+          .create(ldap_user.email.clone(), None, ldap_user.name)
+          .await?;
+        self
+          .user_repository
+          .update_status(new_user.id, UserStatus::Verified)
+          .await?;
+        self
+          .user_identity_repository
+          .create(
+            new_user.id,
+            OAuth2Provider::Ldap,
+            ldap_user.dn,
+            Some(ldap_user.email),
+          )
+          .await?;
+        new_user
+      }
+    };
+    if user.role != ldap_user.role {
+      self
+        .user_repository
+        .update_role(user.id, ldap_user.role)
+        .await?;
+      user.role = ldap_user.role;
+    }
+    Ok(Some(user))
+  }
+}
+
+#[async_trait]
+impl PasswordAuthenticator for LdapPasswordAuthenticator {
+  async fn authenticate(&self, email: String, password: String) -> Result<UserEntity, AppError> {
+    let ldap_error: AppError = match self.ldap_service.authenticate(&email, &password).await {
+      Ok(ldap_user) => match self.directory_user(ldap_user).await? {
+        Some(user) => return Ok(user),
+        None => AppError::UserNotFound,
+      },
+      Err(err) => err,
+    };
+    // A directory entry with the wrong password still says more than a missing local account.
+    match self.local.authenticate(email, password).await {
+      Err(AppError::UserNotFound) => Err(ldap_error),
+      result => result,
+    }
+  }
+}
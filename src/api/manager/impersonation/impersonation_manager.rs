@@ -0,0 +1,100 @@
+use crate::api::client::impersonation::response::impersonation_response::ImpersonationResponse;
+use crate::api::repository::impersonation_audits::repository::impersonation_audit_repository::ImpersonationAuditRepository;
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::core::security::jwt_manager::{Claims, JwtManager};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub trait ImpersonationManager: Send + Sync + 'static {
+  async fn impersonate(
+    &self,
+    actor: Claims,
+    user_id: Uuid,
+  ) -> Result<ImpersonationResponse, AppError>;
+  /// Records a request that `actor_id` made with the impersonation token `claims`.
+  async fn audit(
+    &self,
+    actor_id: Uuid,
+    claims: &Claims,
+    method: &str,
+    path: &str,
+  ) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct ImpersonationManagerImpl {
+  user_repository: Arc<dyn UserRepository>,
+  impersonation_audit_repository: Arc<dyn ImpersonationAuditRepository>,
+  jwt_manager: JwtManager,
+}
+
+impl ImpersonationManagerImpl {
+  pub fn new(
+    user_repository: Arc<dyn UserRepository>,
+    impersonation_audit_repository: Arc<dyn ImpersonationAuditRepository>,
+    jwt_manager: JwtManager,
+  ) -> Self {
+    Self {
+      user_repository,
+      impersonation_audit_repository,
+      jwt_manager,
+    }
+  }
+}
+
+impl ImpersonationManager for ImpersonationManagerImpl {
+  async fn impersonate(
+    &self,
+    actor: Claims,
+    user_id: Uuid,
+  ) -> Result<ImpersonationResponse, AppError> {
+    // Only a human admin session may impersonate, never a key, a service or another impersonation.
+    if actor.is_scoped() || actor.act.is_some() {
+      return Err(AppError::ImpersonationNotAllowed);
+    }
+
+    let user: UserEntity = self
+      .user_repository
+      .by_id(user_id)
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    if user.role == UserRole::Admin {
+      return Err(AppError::ImpersonationNotAllowed);
+    }
+
+    let jti: Uuid = Uuid::new_v4();
+    let (access_token, exp) = self
+      .jwt_manager
+      .generate_impersonation_jwt(jti, user.id, user.role, actor.sub);
+    let expires_at: Option<DateTime<Utc>> =
+      DateTime::from_timestamp(exp as i64, 0).filter(|_| exp != 0);
+
+    self
+      .impersonation_audit_repository
+      .create(actor.sub, user.id, jti, expires_at)
+      .await?;
+
+    Ok(ImpersonationResponse {
+      access_token,
+      user_id: user.id,
+      expires_at,
+    })
+  }
+
+  async fn audit(
+    &self,
+    actor_id: Uuid,
+    claims: &Claims,
+    method: &str,
+    path: &str,
+  ) -> Result<(), AppError> {
+    self
+      .impersonation_audit_repository
+      .create_action(claims.jti, actor_id, claims.sub, method, path)
+      .await
+  }
+}
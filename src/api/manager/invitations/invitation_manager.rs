@@ -0,0 +1,141 @@
+use crate::api::client::invitations::request::invitation_request::InvitationCreateRequest;
+use crate::api::client::invitations::response::invitation_response::InvitationResponse;
+use crate::api::manager::invitations::invitation_response_converter::InvitationResponseConverter;
+use crate::api::repository::user_invitations::entity::user_invitation_entity::UserInvitationEntity;
+use crate::api::repository::user_invitations::repository::user_invitation_repository::UserInvitationRepository;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::core::security::jwt_manager::{InvitationClaims, JwtManager, TokenPurpose};
+use crate::job::event::event::JobEventType;
+use crate::job::event::mail::mail_event::JobMailMessage;
+use crate::job::kind::JobKind;
+use crate::job::message::JobMessage;
+use crate::resources::config::InvitationConfig;
+use crate::service::aws::sqs::producer::producer::SQSProducer;
+use crate::utils::datetime_utils::TimeUtils;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub trait InvitationManager: Send + Sync + 'static {
+  async fn all(&self) -> Result<Vec<InvitationResponse>, AppError>;
+  async fn create(
+    &self,
+    invited_by: Uuid,
+    payload: InvitationCreateRequest,
+  ) -> Result<InvitationResponse, AppError>;
+  async fn revoke(&self, id: Uuid) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct InvitationManagerImpl {
+  invitation_repository: Arc<dyn UserInvitationRepository>,
+  user_repository: Arc<dyn UserRepository>,
+  jwt_manager: JwtManager,
+  sqs_producer: Arc<dyn SQSProducer>,
+  invitation_config: InvitationConfig,
+}
+
+impl InvitationManagerImpl {
+  pub fn new(
+    invitation_repository: Arc<dyn UserInvitationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    jwt_manager: JwtManager,
+    sqs_producer: Arc<dyn SQSProducer>,
+    invitation_config: InvitationConfig,
+  ) -> Self {
+    Self {
+      invitation_repository,
+      user_repository,
+      jwt_manager,
+      sqs_producer,
+      invitation_config,
+    }
+  }
+
+  fn invitation_link(&self, invitation: &UserInvitationEntity) -> String {
+    let token: String = self.jwt_manager.sign(&InvitationClaims {
+      jti: invitation.id,
+      typ: TokenPurpose::Invitation,
+      email: invitation.email.clone(),
+      exp: invitation.expires_at.timestamp() as usize,
+    });
+    format!("{}?token={}", self.invitation_config.url, token)
+  }
+
+  async fn send_invitation(&self, invitation: &UserInvitationEntity) -> Result<(), AppError> {
+    let message: Value = JobMessage::new(
+      JobEventType::MailEvent.as_str(),
+      JobMailMessage::new(
+        invitation.email.clone(),
+        "You have been invited".to_string(),
+        format!(
+          "Use the link below to create your account. It expires on {}.\n\n{}",
+          invitation.expires_at.to_rfc2822(),
+          self.invitation_link(invitation)
+        ),
+      ),
+    )
+    .to_value();
+    self
+      .sqs_producer
+      .send(JobKind::Local.as_str(), message)
+      .await
+  }
+}
+
+impl InvitationManager for InvitationManagerImpl {
+  async fn all(&self) -> Result<Vec<InvitationResponse>, AppError> {
+    let invitations: Vec<UserInvitationEntity> = self.invitation_repository.pending().await?;
+    Ok(
+      invitations
+        .into_iter()
+        .map(Self::invitation_response_converter)
+        .collect(),
+    )
+  }
+
+  async fn create(
+    &self,
+    invited_by: Uuid,
+    payload: InvitationCreateRequest,
+  ) -> Result<InvitationResponse, AppError> {
+    let email: String = payload.email.trim().to_lowercase();
+    if email.is_empty() {
+      return Err(AppError::InvalidInvitation);
+    }
+    if self
+      .user_repository
+      .by_email(email.clone())
+      .await?
+      .is_some()
+    {
+      return Err(AppError::UserExistingEmail);
+    }
+    if self
+      .invitation_repository
+      .pending_by_email(email.clone())
+      .await?
+      .is_some()
+    {
+      return Err(AppError::InvitationExists);
+    }
+
+    let expires_at: DateTime<Utc> =
+      TimeUtils::utc_now() + Duration::seconds(self.invitation_config.expiration_seconds);
+    let invitation: UserInvitationEntity = self
+      .invitation_repository
+      .create(email, payload.role, invited_by, expires_at)
+      .await?;
+    self.send_invitation(&invitation).await?;
+    Ok(Self::invitation_response_converter(invitation))
+  }
+
+  async fn revoke(&self, id: Uuid) -> Result<(), AppError> {
+    match self.invitation_repository.revoke(id).await? {
+      true => Ok(()),
+      false => Err(AppError::InvitationNotFound),
+    }
+  }
+}
@@ -0,0 +1,20 @@
+use crate::api::client::invitations::response::invitation_response::InvitationResponse;
+use crate::api::manager::invitations::invitation_manager::InvitationManagerImpl;
+use crate::api::repository::user_invitations::entity::user_invitation_entity::UserInvitationEntity;
+
+pub trait InvitationResponseConverter {
+  fn invitation_response_converter(invitation: UserInvitationEntity) -> InvitationResponse;
+}
+
+impl InvitationResponseConverter for InvitationManagerImpl {
+  fn invitation_response_converter(invitation: UserInvitationEntity) -> InvitationResponse {
+    InvitationResponse {
+      id: invitation.id,
+      email: invitation.email,
+      role: invitation.role,
+      invited_by: invitation.invited_by,
+      expires_at: invitation.expires_at,
+      created_at: invitation.created_at,
+    }
+  }
+}
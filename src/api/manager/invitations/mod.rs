@@ -0,0 +1,2 @@
+pub(crate) mod invitation_manager;
+mod invitation_response_converter;
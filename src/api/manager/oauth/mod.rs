@@ -0,0 +1,2 @@
+pub(crate) mod oauth_manager;
+mod oauth_response_converter;
@@ -0,0 +1,506 @@
+use crate::api::client::oauth::request::oauth_request::{
+  OAuthAuthorizeRequest, OAuthClientCreateRequest, OAuthTokenGrantRequest, OAuthTokenRequest,
+};
+use crate::api::client::oauth::response::oauth_response::{
+  OAuthAuthorizeResponse, OAuthClientResponse, OAuthIntrospectResponse, OAuthTokenResponse,
+  OidcDiscoveryResponse, OidcUserInfoResponse,
+};
+use crate::api::manager::oauth::oauth_response_converter::OAuthResponseConverter;
+use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
+use crate::api::repository::oauth_authorization_codes::entity::oauth_authorization_code_entity::OAuthAuthorizationCodeEntity;
+use crate::api::repository::oauth_authorization_codes::repository::oauth_authorization_code_repository::OAuthAuthorizationCodeRepository;
+use crate::api::repository::oauth_clients::entity::oauth_client_entity::OAuthClientEntity;
+use crate::api::repository::oauth_clients::repository::oauth_client_repository::OAuthClientRepository;
+use crate::api::repository::refresh_tokens::repository::refresh_token_repository::RefreshTokenRepository;
+use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::core::security::authentication::ClientBasicAuth;
+use crate::core::security::jwt_manager::{Claims, IdTokenClaims, JwtManager, TokenType};
+use crate::resources::config::OidcProviderConfig;
+use crate::utils::datetime_utils::TimeUtils;
+use crate::utils::hash_utils::HashUtils;
+use crate::utils::random_utils::RandomUtils;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Duration;
+use jsonwebtoken::get_current_timestamp;
+use jsonwebtoken::jwk::JwkSet;
+use openssl::memcmp;
+use openssl::sha::sha256;
+use reqwest::Url;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const CLIENT_ID_LENGTH: usize = 24;
+const CLIENT_SECRET_LENGTH: usize = 48;
+const AUTHORIZATION_CODE_SECRET_LENGTH: usize = 48;
+const CLIENT_CREDENTIALS_GRANT_TYPE: &str = "client_credentials";
+const AUTHORIZATION_CODE_GRANT_TYPE: &str = "authorization_code";
+const CODE_RESPONSE_TYPE: &str = "code";
+const S256_CODE_CHALLENGE_METHOD: &str = "S256";
+const BEARER_TOKEN_TYPE: &str = "Bearer";
+const OPENID_SCOPE: &str = "openid";
+const OIDC_SCOPES: [&str; 3] = [OPENID_SCOPE, "email", "profile"];
+
+pub trait OAuthManager: Send + Sync + 'static {
+  async fn create_client(
+    &self,
+    payload: OAuthClientCreateRequest,
+  ) -> Result<OAuthClientResponse, AppError>;
+  async fn authenticate_client(
+    &self,
+    credentials: ClientBasicAuth,
+  ) -> Result<OAuthClientEntity, AppError>;
+  async fn introspect(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenRequest,
+  ) -> Result<OAuthIntrospectResponse, AppError>;
+  async fn revoke(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenRequest,
+  ) -> Result<(), AppError>;
+  async fn token(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenGrantRequest,
+  ) -> Result<OAuthTokenResponse, AppError>;
+  async fn authorize(
+    &self,
+    claims: Claims,
+    payload: OAuthAuthorizeRequest,
+  ) -> Result<OAuthAuthorizeResponse, AppError>;
+  async fn user_info(&self, claims: Claims) -> Result<OidcUserInfoResponse, AppError>;
+  fn discovery(&self) -> OidcDiscoveryResponse;
+  fn jwks(&self) -> JwkSet;
+}
+
+#[derive(Clone)]
+pub struct OAuthManagerImpl {
+  oauth_client_repository: Arc<dyn OAuthClientRepository>,
+  refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+  authorization_code_repository: Arc<dyn OAuthAuthorizationCodeRepository>,
+  user_repository: Arc<dyn UserRepository>,
+  jwt_manager: JwtManager,
+  oidc_provider_config: OidcProviderConfig,
+}
+
+impl OAuthManagerImpl {
+  pub fn new(
+    oauth_client_repository: Arc<dyn OAuthClientRepository>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+    authorization_code_repository: Arc<dyn OAuthAuthorizationCodeRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    jwt_manager: JwtManager,
+    oidc_provider_config: OidcProviderConfig,
+  ) -> Self {
+    Self {
+      oauth_client_repository,
+      refresh_token_repository,
+      authorization_code_repository,
+      user_repository,
+      jwt_manager,
+      oidc_provider_config,
+    }
+  }
+
+  fn validate_token(&self, token: &str, token_type_hint: Option<TokenType>) -> Option<Claims> {
+    let token_types: [TokenType; 2] = match token_type_hint {
+      Some(TokenType::RefreshToken) => [TokenType::RefreshToken, TokenType::AccessToken],
+      _ => [TokenType::AccessToken, TokenType::RefreshToken],
+    };
+    token_types
+      .into_iter()
+      .find_map(|token_type| self.jwt_manager.validate_jwt(token, token_type))
+  }
+
+  /// Without a `scope` parameter the client gets every scope it was registered with.
+  fn granted_scopes(
+    client: &OAuthClientEntity,
+    scope: Option<String>,
+  ) -> Result<Vec<String>, AppError> {
+    let scope: String = match scope {
+      Some(scope) => scope,
+      None => return Ok(client.scopes.clone()),
+    };
+
+    let mut scopes: Vec<String> = Vec::new();
+    for requested in scope.split_whitespace() {
+      if !client.scopes.iter().any(|allowed| allowed == requested) {
+        return Err(AppError::InvalidScope);
+      }
+      if !scopes.iter().any(|granted| granted == requested) {
+        scopes.push(requested.to_string());
+      }
+    }
+    Ok(scopes)
+  }
+
+  /// OpenID scopes are fixed for first-party clients and `openid` is required.
+  fn oidc_scopes(scope: &str) -> Result<Vec<String>, AppError> {
+    let mut scopes: Vec<String> = Vec::new();
+    for requested in scope.split_whitespace() {
+      if !OIDC_SCOPES.contains(&requested) {
+        return Err(AppError::InvalidScope);
+      }
+      if !scopes.iter().any(|granted| granted == requested) {
+        scopes.push(requested.to_string());
+      }
+    }
+    match scopes.iter().any(|granted| granted == OPENID_SCOPE) {
+      true => Ok(scopes),
+      false => Err(AppError::InvalidScope),
+    }
+  }
+
+  /// Codes have the form `{id}_{secret}` so the row can be looked up by id
+  /// before the secret is checked against its hash.
+  fn parse_code(code: &str) -> Option<(Uuid, &str)> {
+    let (id, secret) = code.split_once('_')?;
+    Some((Uuid::parse_str(id).ok()?, secret))
+  }
+
+  fn code_challenge(code_verifier: &str) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(sha256(code_verifier.as_bytes()))
+  }
+
+  fn verify_code_challenge(code_verifier: &str, code_challenge: &str) -> bool {
+    let expected: String = Self::code_challenge(code_verifier);
+    expected.len() == code_challenge.len()
+      && memcmp::eq(expected.as_bytes(), code_challenge.as_bytes())
+  }
+
+  fn endpoint(&self, path: &str) -> String {
+    format!(
+      "{}{}",
+      self.oidc_provider_config.issuer.trim_end_matches('/'),
+      path
+    )
+  }
+
+  fn client_credentials_token(
+    &self,
+    client: OAuthClientEntity,
+    payload: OAuthTokenGrantRequest,
+  ) -> Result<OAuthTokenResponse, AppError> {
+    let scopes: Vec<String> = Self::granted_scopes(&client, payload.scope)?;
+    let role: UserRole = match scopes.iter().any(|s| s == ApiKeyScope::Admin.as_str()) {
+      true => UserRole::Admin,
+      false => UserRole::User,
+    };
+    let access_token: String = self.jwt_manager.generate_service_jwt(
+      Uuid::new_v4(),
+      client.id,
+      client.client_id,
+      role,
+      scopes.clone(),
+    );
+
+    Ok(OAuthTokenResponse {
+      access_token,
+      token_type: BEARER_TOKEN_TYPE.to_string(),
+      expires_in: Some(self.jwt_manager.access_expiration_seconds()).filter(|s| *s != -1),
+      scope: scopes.join(" "),
+      id_token: None,
+    })
+  }
+
+  /// Redeems a code issued by [`OAuthManager::authorize`] for a user access token and an
+  /// id_token. Every mismatch is reported as `invalid grant`, as RFC 6749 asks.
+  async fn authorization_code_token(
+    &self,
+    client: OAuthClientEntity,
+    payload: OAuthTokenGrantRequest,
+  ) -> Result<OAuthTokenResponse, AppError> {
+    let (code, redirect_uri, code_verifier) =
+      match (payload.code, payload.redirect_uri, payload.code_verifier) {
+        (Some(code), Some(redirect_uri), Some(code_verifier)) => {
+          (code, redirect_uri, code_verifier)
+        }
+        _ => return Err(AppError::InvalidGrant),
+      };
+    let (id, secret) = Self::parse_code(&code).ok_or(AppError::InvalidGrant)?;
+    let authorization_code: OAuthAuthorizationCodeEntity =
+      match self.authorization_code_repository.by_id(id).await? {
+        Some(authorization_code)
+          if authorization_code.client_id == client.id
+            && authorization_code.redirect_uri == redirect_uri
+            && Self::verify_code_challenge(&code_verifier, &authorization_code.code_challenge)
+            && HashUtils::verify_password(secret, &authorization_code.code_hash) =>
+        {
+          authorization_code
+        }
+        _ => return Err(AppError::InvalidGrant),
+      };
+    if !self.authorization_code_repository.consume(id).await? {
+      return Err(AppError::InvalidGrant);
+    }
+
+    let user: UserEntity = self
+      .user_repository
+      .by_id(authorization_code.user_id)
+      .await?
+      .ok_or(AppError::InvalidGrant)?;
+    let access_token: String = self.jwt_manager.generate_userinfo_jwt(
+      Uuid::new_v4(),
+      user.id,
+      user.role,
+      client.client_id.clone(),
+      authorization_code.scopes.clone(),
+    );
+    let now: u64 = get_current_timestamp();
+    let id_token: String = self.jwt_manager.generate_id_token(&IdTokenClaims {
+      iss: self.oidc_provider_config.issuer.clone(),
+      sub: user.id,
+      aud: client.client_id,
+      exp: (now + self.oidc_provider_config.id_token_expiration_seconds as u64) as usize,
+      iat: now as usize,
+      nonce: authorization_code.nonce,
+      email: user.email,
+      email_verified: matches!(user.status, UserStatus::Verified),
+      name: user.name,
+    });
+
+    Ok(OAuthTokenResponse {
+      access_token,
+      token_type: BEARER_TOKEN_TYPE.to_string(),
+      expires_in: Some(self.jwt_manager.access_expiration_seconds()).filter(|s| *s != -1),
+      scope: authorization_code.scopes.join(" "),
+      id_token: Some(id_token),
+    })
+  }
+
+  /// Service tokens stay active as long as their client exists, userinfo tokens as long as
+  /// their client exists and their user is active, impersonation tokens as long as their user
+  /// is active, and session tokens as long as their refresh token is stored.
+  async fn is_active(&self, claims: &Claims) -> Result<bool, AppError> {
+    match (&claims.client_id, &claims.azp) {
+      (Some(client_id), _) => Ok(matches!(
+        self.oauth_client_repository.by_id(claims.sub).await?,
+        Some(client) if &client.client_id == client_id
+      )),
+      (None, Some(azp)) => Ok(
+        self
+          .oauth_client_repository
+          .by_client_id(azp.clone())
+          .await?
+          .is_some()
+          && self.is_user_active(claims.sub).await?,
+      ),
+      (None, None) if claims.act.is_some() => self.is_user_active(claims.sub).await,
+      (None, None) => Ok(
+        self
+          .refresh_token_repository
+          .by_id(claims.jti)
+          .await?
+          .is_some(),
+      ),
+    }
+  }
+
+  async fn is_user_active(&self, user_id: Uuid) -> Result<bool, AppError> {
+    Ok(matches!(
+      self.user_repository.by_id(user_id).await?,
+      Some(user) if user.ensure_active().is_ok()
+    ))
+  }
+}
+
+impl OAuthManager for OAuthManagerImpl {
+  async fn create_client(
+    &self,
+    payload: OAuthClientCreateRequest,
+  ) -> Result<OAuthClientResponse, AppError> {
+    if payload
+      .redirect_uris
+      .iter()
+      .any(|redirect_uri| Url::parse(redirect_uri).is_err())
+    {
+      return Err(AppError::InvalidRedirectUri);
+    }
+
+    let client_secret: String = RandomUtils::alphanumeric(CLIENT_SECRET_LENGTH);
+    let client: OAuthClientEntity = self
+      .oauth_client_repository
+      .create(
+        RandomUtils::alphanumeric(CLIENT_ID_LENGTH),
+        HashUtils::hash_password(&client_secret),
+        payload.name,
+        ApiKeyScope::to_strings(&payload.scopes),
+        payload.redirect_uris,
+      )
+      .await?;
+    Ok(Self::oauth_client_response_converter(client, client_secret))
+  }
+
+  async fn authenticate_client(
+    &self,
+    credentials: ClientBasicAuth,
+  ) -> Result<OAuthClientEntity, AppError> {
+    match self
+      .oauth_client_repository
+      .by_client_id(credentials.client_id)
+      .await?
+    {
+      Some(client)
+        if HashUtils::verify_password(&credentials.client_secret, &client.client_secret) =>
+      {
+        Ok(client)
+      }
+      _ => Err(AppError::InvalidClient),
+    }
+  }
+
+  async fn introspect(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenRequest,
+  ) -> Result<OAuthIntrospectResponse, AppError> {
+    self.authenticate_client(credentials).await?;
+
+    let claims: Claims = match self.validate_token(&payload.token, payload.token_type()) {
+      Some(claims) => claims,
+      None => return Ok(OAuthIntrospectResponse::default()),
+    };
+
+    match self.is_active(&claims).await? {
+      true => Ok(Self::oauth_introspect_response_converter(claims)),
+      false => Ok(OAuthIntrospectResponse::default()),
+    }
+  }
+
+  async fn revoke(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenRequest,
+  ) -> Result<(), AppError> {
+    let client: OAuthClientEntity = self.authenticate_client(credentials).await?;
+
+    // A client may only revoke tokens issued to it (RFC 7009 section 2.1); anything else,
+    // including user sessions, is left untouched.
+    if let Some(claims) = self.validate_token(&payload.token, payload.token_type()) {
+      if claims.client_id.as_ref().or(claims.azp.as_ref()) == Some(&client.client_id) {
+        self.refresh_token_repository.delete(claims.jti).await?;
+      }
+    }
+    Ok(())
+  }
+
+  async fn token(
+    &self,
+    credentials: ClientBasicAuth,
+    payload: OAuthTokenGrantRequest,
+  ) -> Result<OAuthTokenResponse, AppError> {
+    let client: OAuthClientEntity = self.authenticate_client(credentials).await?;
+    match payload.grant_type.as_str() {
+      CLIENT_CREDENTIALS_GRANT_TYPE => self.client_credentials_token(client, payload),
+      AUTHORIZATION_CODE_GRANT_TYPE => self.authorization_code_token(client, payload).await,
+      _ => Err(AppError::UnsupportedGrantType),
+    }
+  }
+
+  /// Clients are first-party, so a signed-in user is redirected back without a consent step.
+  async fn authorize(
+    &self,
+    claims: Claims,
+    payload: OAuthAuthorizeRequest,
+  ) -> Result<OAuthAuthorizeResponse, AppError> {
+    if claims.is_scoped() || claims.act.is_some() {
+      return Err(AppError::PermissionDenied);
+    }
+    let client: OAuthClientEntity = self
+      .oauth_client_repository
+      .by_client_id(payload.client_id)
+      .await?
+      .ok_or(AppError::InvalidClient)?;
+    if !client.redirect_uris.contains(&payload.redirect_uri) {
+      return Err(AppError::InvalidRedirectUri);
+    }
+    if payload.response_type != CODE_RESPONSE_TYPE
+      || payload.code_challenge_method.as_deref() != Some(S256_CODE_CHALLENGE_METHOD)
+      || payload.code_challenge.is_empty()
+    {
+      return Err(AppError::InvalidAuthorizationRequest);
+    }
+    let scopes: Vec<String> = Self::oidc_scopes(&payload.scope)?;
+
+    let id: Uuid = Uuid::new_v4();
+    let secret: String = RandomUtils::alphanumeric(AUTHORIZATION_CODE_SECRET_LENGTH);
+    let code: String = format!("{}_{}", id.simple(), secret);
+    self
+      .authorization_code_repository
+      .create(
+        id,
+        client.id,
+        claims.sub,
+        HashUtils::hash_password(&secret),
+        payload.redirect_uri.clone(),
+        scopes,
+        payload.nonce,
+        payload.code_challenge,
+        TimeUtils::utc_now() + Duration::seconds(self.oidc_provider_config.code_expiration_seconds),
+      )
+      .await?;
+
+    let mut redirect_uri: Url =
+      Url::parse(&payload.redirect_uri).map_err(|_| AppError::InvalidRedirectUri)?;
+    redirect_uri.query_pairs_mut().append_pair("code", &code);
+    if let Some(state) = payload.state {
+      redirect_uri.query_pairs_mut().append_pair("state", &state);
+    }
+    Ok(OAuthAuthorizeResponse {
+      redirect_uri: redirect_uri.to_string(),
+    })
+  }
+
+  async fn user_info(&self, claims: Claims) -> Result<OidcUserInfoResponse, AppError> {
+    if claims.is_scoped() && !claims.is_userinfo() {
+      return Err(AppError::PermissionDenied);
+    }
+    let user: UserEntity = self
+      .user_repository
+      .by_id(claims.sub)
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    Ok(Self::oidc_user_info_response_converter(user))
+  }
+
+  fn discovery(&self) -> OidcDiscoveryResponse {
+    let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+    OidcDiscoveryResponse {
+      issuer: self.oidc_provider_config.issuer.clone(),
+      authorization_endpoint: self.oidc_provider_config.authorization_url.clone(),
+      token_endpoint: self.endpoint("/oauth/token"),
+      userinfo_endpoint: self.endpoint("/oauth/userinfo"),
+      jwks_uri: self.endpoint("/oauth/jwks"),
+      response_types_supported: strings(&[CODE_RESPONSE_TYPE]),
+      grant_types_supported: strings(&[
+        AUTHORIZATION_CODE_GRANT_TYPE,
+        CLIENT_CREDENTIALS_GRANT_TYPE,
+      ]),
+      subject_types_supported: strings(&["public"]),
+      id_token_signing_alg_values_supported: vec![format!("{:?}", self.jwt_manager.algorithm())],
+      scopes_supported: strings(&OIDC_SCOPES),
+      claims_supported: strings(&[
+        "iss",
+        "sub",
+        "aud",
+        "exp",
+        "iat",
+        "nonce",
+        "email",
+        "email_verified",
+        "name",
+      ]),
+      token_endpoint_auth_methods_supported: strings(&["client_secret_basic"]),
+      code_challenge_methods_supported: strings(&[S256_CODE_CHALLENGE_METHOD]),
+    }
+  }
+
+  fn jwks(&self) -> JwkSet {
+    self.jwt_manager.jwk_set()
+  }
+}
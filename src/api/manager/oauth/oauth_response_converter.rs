@@ -0,0 +1,53 @@
+use crate::api::client::oauth::response::oauth_response::{
+  OAuthClientResponse, OAuthIntrospectResponse, OidcUserInfoResponse,
+};
+use crate::api::manager::oauth::oauth_manager::OAuthManagerImpl;
+use crate::api::repository::oauth_clients::entity::oauth_client_entity::OAuthClientEntity;
+use crate::api::repository::users::constant::user_constant::UserStatus;
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::core::security::jwt_manager::Claims;
+
+pub trait OAuthResponseConverter {
+  fn oauth_client_response_converter(
+    client: OAuthClientEntity,
+    client_secret: String,
+  ) -> OAuthClientResponse;
+  fn oauth_introspect_response_converter(claims: Claims) -> OAuthIntrospectResponse;
+  fn oidc_user_info_response_converter(user: UserEntity) -> OidcUserInfoResponse;
+}
+
+impl OAuthResponseConverter for OAuthManagerImpl {
+  fn oauth_client_response_converter(
+    client: OAuthClientEntity,
+    client_secret: String,
+  ) -> OAuthClientResponse {
+    OAuthClientResponse {
+      client_id: client.client_id,
+      client_secret,
+      name: client.name,
+      scopes: client.scopes,
+      redirect_uris: client.redirect_uris,
+    }
+  }
+
+  fn oauth_introspect_response_converter(claims: Claims) -> OAuthIntrospectResponse {
+    OAuthIntrospectResponse {
+      active: true,
+      sub: Some(claims.sub),
+      jti: Some(claims.jti),
+      exp: Some(claims.exp).filter(|exp| *exp != 0),
+      role: Some(claims.role),
+      scope: claims.scopes.map(|scopes| scopes.join(" ")),
+      client_id: claims.client_id.or(claims.azp),
+    }
+  }
+
+  fn oidc_user_info_response_converter(user: UserEntity) -> OidcUserInfoResponse {
+    OidcUserInfoResponse {
+      sub: user.id,
+      email: user.email,
+      email_verified: matches!(user.status, UserStatus::Verified),
+      name: user.name,
+    }
+  }
+}
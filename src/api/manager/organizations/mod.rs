@@ -0,0 +1,2 @@
+pub(crate) mod organization_manager;
+mod organization_response_converter;
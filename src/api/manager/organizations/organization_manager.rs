@@ -0,0 +1,270 @@
+use crate::api::client::organizations::request::organization_request::{
+  OrganizationCreateRequest, OrganizationInviteRequest, OrganizationTransferRequest,
+};
+use crate::api::client::organizations::response::organization_response::{
+  OrganizationMemberResponse, OrganizationResponse,
+};
+use crate::api::manager::organizations::organization_response_converter::OrganizationResponseConverter;
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use crate::api::repository::organizations::entity::organization_entity::{
+  OrganizationEntity, OrganizationMemberEntity,
+};
+use crate::api::repository::organizations::repository::organization_repository::OrganizationRepository;
+use crate::api::repository::tenant::TenantTransaction;
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::core::security::organization::OrgContext;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub trait OrganizationManager: Send + Sync + 'static {
+  async fn context(&self, user_id: Uuid, organization_id: Uuid) -> Result<OrgContext, AppError>;
+  async fn all(&self, user_id: Uuid) -> Result<Vec<OrganizationResponse>, AppError>;
+  async fn create(
+    &self,
+    user_id: Uuid,
+    payload: OrganizationCreateRequest,
+  ) -> Result<OrganizationResponse, AppError>;
+  async fn get(&self, context: OrgContext) -> Result<OrganizationResponse, AppError>;
+  async fn members(&self, context: OrgContext)
+    -> Result<Vec<OrganizationMemberResponse>, AppError>;
+  async fn invite(
+    &self,
+    context: OrgContext,
+    payload: OrganizationInviteRequest,
+  ) -> Result<OrganizationMemberResponse, AppError>;
+  async fn accept(
+    &self,
+    user_id: Uuid,
+    organization_id: Uuid,
+  ) -> Result<OrganizationMemberResponse, AppError>;
+  async fn remove(&self, context: OrgContext, user_id: Uuid) -> Result<(), AppError>;
+  async fn transfer(
+    &self,
+    context: OrgContext,
+    payload: OrganizationTransferRequest,
+  ) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct OrganizationManagerImpl {
+  organization_repository: Arc<dyn OrganizationRepository>,
+  user_repository: Arc<dyn UserRepository>,
+}
+
+impl OrganizationManagerImpl {
+  pub fn new(
+    organization_repository: Arc<dyn OrganizationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+  ) -> Self {
+    Self {
+      organization_repository,
+      user_repository,
+    }
+  }
+
+  async fn member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<OrganizationMemberEntity, AppError> {
+    self
+      .organization_repository
+      .member(transaction, user_id)
+      .await?
+      .ok_or(AppError::OrganizationMemberNotFound)
+  }
+}
+
+impl OrganizationManager for OrganizationManagerImpl {
+  /// Pending invitations and unknown organizations look the same, so ids of organizations the
+  /// caller has not joined are not disclosed.
+  async fn context(&self, user_id: Uuid, organization_id: Uuid) -> Result<OrgContext, AppError> {
+    let mut transaction: TenantTransaction =
+      self.organization_repository.begin(organization_id).await?;
+    match self
+      .organization_repository
+      .member(&mut transaction, user_id)
+      .await?
+    {
+      Some(member) if member.accepted_at.is_some() => Ok(OrgContext {
+        user_id,
+        role: member.role,
+        transaction,
+      }),
+      _ => Err(AppError::OrganizationNotFound),
+    }
+  }
+
+  async fn all(&self, user_id: Uuid) -> Result<Vec<OrganizationResponse>, AppError> {
+    let organizations: Vec<OrganizationEntity> =
+      self.organization_repository.by_member(user_id).await?;
+    Ok(
+      organizations
+        .into_iter()
+        .map(Self::organization_response_converter)
+        .collect(),
+    )
+  }
+
+  async fn create(
+    &self,
+    user_id: Uuid,
+    payload: OrganizationCreateRequest,
+  ) -> Result<OrganizationResponse, AppError> {
+    let name: String = payload.name.trim().to_string();
+    if name.is_empty() {
+      return Err(AppError::InvalidOrganizationRequest);
+    }
+
+    let mut transaction: TenantTransaction =
+      self.organization_repository.begin(Uuid::new_v4()).await?;
+    let organization: OrganizationEntity = self
+      .organization_repository
+      .create(&mut transaction, name)
+      .await?;
+    self
+      .organization_repository
+      .add_member(
+        &mut transaction,
+        user_id,
+        OrganizationRole::Owner,
+        None,
+        true,
+      )
+      .await?;
+    transaction.commit().await?;
+    Ok(Self::organization_response_converter(organization))
+  }
+
+  async fn get(&self, mut context: OrgContext) -> Result<OrganizationResponse, AppError> {
+    let organization: OrganizationEntity = self
+      .organization_repository
+      .by_id(&mut context.transaction)
+      .await?
+      .ok_or(AppError::OrganizationNotFound)?;
+    context.transaction.commit().await?;
+    Ok(Self::organization_response_converter(organization))
+  }
+
+  async fn members(
+    &self,
+    mut context: OrgContext,
+  ) -> Result<Vec<OrganizationMemberResponse>, AppError> {
+    let members: Vec<OrganizationMemberEntity> = self
+      .organization_repository
+      .members(&mut context.transaction)
+      .await?;
+    context.transaction.commit().await?;
+    Ok(
+      members
+        .into_iter()
+        .map(Self::organization_member_response_converter)
+        .collect(),
+    )
+  }
+
+  /// Admins invite members and other admins; ownership only changes hands through `transfer`.
+  async fn invite(
+    &self,
+    mut context: OrgContext,
+    payload: OrganizationInviteRequest,
+  ) -> Result<OrganizationMemberResponse, AppError> {
+    context.require_role(OrganizationRole::Admin)?;
+    if payload.role == OrganizationRole::Owner {
+      return Err(AppError::InvalidOrganizationRequest);
+    }
+
+    let user: UserEntity = self
+      .user_repository
+      .by_email(payload.email.to_lowercase())
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    if self
+      .organization_repository
+      .member(&mut context.transaction, user.id)
+      .await?
+      .is_some()
+    {
+      return Err(AppError::OrganizationMemberExists);
+    }
+
+    let member: OrganizationMemberEntity = self
+      .organization_repository
+      .add_member(
+        &mut context.transaction,
+        user.id,
+        payload.role,
+        Some(context.user_id),
+        false,
+      )
+      .await?;
+    context.transaction.commit().await?;
+    Ok(Self::organization_member_response_converter(member))
+  }
+
+  async fn accept(
+    &self,
+    user_id: Uuid,
+    organization_id: Uuid,
+  ) -> Result<OrganizationMemberResponse, AppError> {
+    let mut transaction: TenantTransaction =
+      self.organization_repository.begin(organization_id).await?;
+    if !self
+      .organization_repository
+      .accept(&mut transaction, user_id)
+      .await?
+    {
+      return Err(AppError::OrganizationInvitationNotFound);
+    }
+    let member: OrganizationMemberEntity = self.member(&mut transaction, user_id).await?;
+    transaction.commit().await?;
+    Ok(Self::organization_member_response_converter(member))
+  }
+
+  /// Members may leave on their own. Removing someone else, or revoking their invitation, takes
+  /// an admin whose role includes theirs. The owner has to transfer ownership before leaving.
+  async fn remove(&self, mut context: OrgContext, user_id: Uuid) -> Result<(), AppError> {
+    let member: OrganizationMemberEntity = self.member(&mut context.transaction, user_id).await?;
+    if member.role == OrganizationRole::Owner {
+      return Err(AppError::InvalidOrganizationRequest);
+    }
+    if user_id != context.user_id {
+      context.require_role(OrganizationRole::Admin)?;
+      context.require_role(member.role)?;
+    }
+
+    if !self
+      .organization_repository
+      .remove_member(&mut context.transaction, user_id)
+      .await?
+    {
+      return Err(AppError::OrganizationMemberNotFound);
+    }
+    context.transaction.commit().await
+  }
+
+  async fn transfer(
+    &self,
+    mut context: OrgContext,
+    payload: OrganizationTransferRequest,
+  ) -> Result<(), AppError> {
+    context.require_role(OrganizationRole::Owner)?;
+    let member: OrganizationMemberEntity = self
+      .member(&mut context.transaction, payload.user_id)
+      .await?;
+    if member.user_id == context.user_id || member.accepted_at.is_none() {
+      return Err(AppError::InvalidOrganizationRequest);
+    }
+
+    if !self
+      .organization_repository
+      .transfer_ownership(&mut context.transaction, context.user_id, member.user_id)
+      .await?
+    {
+      return Err(AppError::OrganizationMemberNotFound);
+    }
+    context.transaction.commit().await
+  }
+}
@@ -0,0 +1,37 @@
+use crate::api::client::organizations::response::organization_response::{
+  OrganizationMemberResponse, OrganizationResponse,
+};
+use crate::api::manager::organizations::organization_manager::OrganizationManagerImpl;
+use crate::api::repository::organizations::entity::organization_entity::{
+  OrganizationEntity, OrganizationMemberEntity,
+};
+
+pub trait OrganizationResponseConverter {
+  fn organization_response_converter(organization: OrganizationEntity) -> OrganizationResponse;
+  fn organization_member_response_converter(
+    member: OrganizationMemberEntity,
+  ) -> OrganizationMemberResponse;
+}
+
+impl OrganizationResponseConverter for OrganizationManagerImpl {
+  fn organization_response_converter(organization: OrganizationEntity) -> OrganizationResponse {
+    OrganizationResponse {
+      id: organization.id,
+      name: organization.name,
+      created_at: organization.created_at,
+    }
+  }
+
+  fn organization_member_response_converter(
+    member: OrganizationMemberEntity,
+  ) -> OrganizationMemberResponse {
+    OrganizationMemberResponse {
+      organization_id: member.organization_id,
+      user_id: member.user_id,
+      role: member.role,
+      invited_by: member.invited_by,
+      accepted_at: member.accepted_at,
+      created_at: member.created_at,
+    }
+  }
+}
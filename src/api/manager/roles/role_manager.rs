@@ -0,0 +1,66 @@
+use crate::api::repository::roles::repository::role_repository::RoleRepository;
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::core::error::error::AppError;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+const PERMISSIONS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+pub trait RoleManager: Send + Sync + 'static {
+  async fn permissions(&self, role: UserRole) -> Result<Vec<String>, AppError>;
+  async fn has_permission(&self, role: UserRole, permission: &str) -> Result<bool, AppError>;
+}
+
+#[derive(Clone)]
+struct CachedPermissions {
+  permissions: Vec<String>,
+  loaded_at: Instant,
+}
+
+/// Permissions are looked up per role and cached for a minute, so changes to
+/// `role_permissions` apply without reissuing tokens.
+#[derive(Clone)]
+pub struct RoleManagerImpl {
+  role_repository: Arc<dyn RoleRepository>,
+  cache: Arc<RwLock<HashMap<UserRole, CachedPermissions>>>,
+}
+
+impl RoleManagerImpl {
+  pub fn new(role_repository: Arc<dyn RoleRepository>) -> Self {
+    Self {
+      role_repository,
+      cache: Arc::new(RwLock::new(HashMap::new())),
+    }
+  }
+}
+
+impl RoleManager for RoleManagerImpl {
+  async fn permissions(&self, role: UserRole) -> Result<Vec<String>, AppError> {
+    let cached: Option<CachedPermissions> = self.cache.read().unwrap().get(&role).cloned();
+    if let Some(cached) = cached.filter(|cached| cached.loaded_at.elapsed() < PERMISSIONS_CACHE_TTL)
+    {
+      return Ok(cached.permissions);
+    }
+
+    let permissions: Vec<String> = self.role_repository.permissions(role).await?;
+    self.cache.write().unwrap().insert(
+      role,
+      CachedPermissions {
+        permissions: permissions.clone(),
+        loaded_at: Instant::now(),
+      },
+    );
+    Ok(permissions)
+  }
+
+  async fn has_permission(&self, role: UserRole, permission: &str) -> Result<bool, AppError> {
+    Ok(
+      self
+        .permissions(role)
+        .await?
+        .iter()
+        .any(|granted| granted == permission),
+    )
+  }
+}
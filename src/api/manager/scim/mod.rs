@@ -0,0 +1,2 @@
+pub(crate) mod scim_manager;
+mod scim_response_converter;
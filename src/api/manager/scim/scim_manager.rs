@@ -0,0 +1,331 @@
+use crate::api::client::scim::request::scim_request::{
+  ScimListRequest, ScimPatchOperation, ScimPatchRequest, ScimUserRequest,
+};
+use crate::api::client::scim::response::scim_response::{
+  ScimAuthenticationScheme, ScimBulkSupported, ScimFilterSupported, ScimListResponse,
+  ScimServiceProviderConfigResponse, ScimSupported, ScimUserResponse,
+};
+use crate::api::manager::scim::scim_response_converter::ScimResponseConverter;
+use crate::api::repository::users::constant::user_constant::UserStatus;
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::users::repository::user_repository::UserRepository;
+use crate::core::error::error::AppError;
+use crate::resources::config::ScimConfig;
+use crate::utils::hash_utils::HashUtils;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const SCIM_SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+  "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+const DEFAULT_COUNT: u64 = 100;
+const MAX_COUNT: u64 = 200;
+
+pub trait ScimManager: Send + Sync + 'static {
+  fn service_provider_config(&self) -> ScimServiceProviderConfigResponse;
+  async fn all(&self, payload: ScimListRequest) -> Result<ScimListResponse, AppError>;
+  async fn get(&self, id: Uuid) -> Result<ScimUserResponse, AppError>;
+  async fn create(&self, payload: ScimUserRequest) -> Result<ScimUserResponse, AppError>;
+  async fn replace(&self, id: Uuid, payload: ScimUserRequest)
+    -> Result<ScimUserResponse, AppError>;
+  async fn patch(&self, id: Uuid, payload: ScimPatchRequest) -> Result<ScimUserResponse, AppError>;
+  async fn delete(&self, id: Uuid) -> Result<(), AppError>;
+}
+
+/// Attributes a SCIM request asked to change; `None` leaves the column untouched.
+#[derive(Default)]
+struct ScimUserChanges {
+  email: Option<String>,
+  name: Option<Option<String>>,
+  active: Option<bool>,
+}
+
+#[derive(Clone)]
+pub struct ScimManagerImpl {
+  user_repository: Arc<dyn UserRepository>,
+  pub(crate) config: ScimConfig,
+}
+
+impl ScimManagerImpl {
+  pub fn new(user_repository: Arc<dyn UserRepository>, config: ScimConfig) -> Self {
+    Self {
+      user_repository,
+      config,
+    }
+  }
+
+  /// Only `userName eq "..."` and `emails.value eq "..."` are supported, which is what
+  /// identity providers send to look a user up before provisioning it.
+  fn parse_filter(filter: &str) -> Result<String, AppError> {
+    let mut parts = filter.trim().splitn(3, ' ');
+    match (parts.next(), parts.next(), parts.next()) {
+      (Some(attribute), Some(operator), Some(value))
+        if operator.eq_ignore_ascii_case("eq")
+          && (attribute.eq_ignore_ascii_case("userName")
+            || attribute.eq_ignore_ascii_case("emails.value")) =>
+      {
+        serde_json::from_str::<String>(value.trim()).map_err(|_| AppError::InvalidScimFilter)
+      }
+      _ => Err(AppError::InvalidScimFilter),
+    }
+  }
+
+  fn normalize_email(user_name: &str) -> Result<String, AppError> {
+    let email: String = user_name.trim().to_lowercase();
+    match email.is_empty() {
+      true => Err(AppError::InvalidScimRequest),
+      false => Ok(email),
+    }
+  }
+
+  fn request_changes(payload: &ScimUserRequest) -> ScimUserChanges {
+    ScimUserChanges {
+      email: Some(payload.user_name.clone()),
+      name: Some(
+        payload
+          .display_name
+          .clone()
+          .or_else(|| payload.name.clone().and_then(|name| name.formatted)),
+      ),
+      active: payload.active,
+    }
+  }
+
+  /// Folds the operations into one set of changes. Paths without a matching column, such as
+  /// `name.givenName`, are ignored so providers can send their full attribute mapping.
+  fn patch_changes(operations: Vec<ScimPatchOperation>) -> Result<ScimUserChanges, AppError> {
+    let mut changes: ScimUserChanges = ScimUserChanges::default();
+    for operation in operations {
+      match (operation.op.to_lowercase().as_str(), operation.path) {
+        ("add" | "replace", Some(path)) => {
+          let value: Value = operation.value.ok_or(AppError::InvalidScimRequest)?;
+          Self::patch_attribute(&mut changes, &path, value)?;
+        }
+        ("add" | "replace", None) => match operation.value {
+          Some(Value::Object(values)) => {
+            for (path, value) in values {
+              Self::patch_attribute(&mut changes, &path, value)?;
+            }
+          }
+          _ => return Err(AppError::InvalidScimRequest),
+        },
+        ("remove", Some(path)) => Self::patch_attribute(&mut changes, &path, Value::Null)?,
+        _ => return Err(AppError::InvalidScimRequest),
+      }
+    }
+    Ok(changes)
+  }
+
+  fn patch_attribute(
+    changes: &mut ScimUserChanges,
+    path: &str,
+    value: Value,
+  ) -> Result<(), AppError> {
+    match path.to_lowercase().as_str() {
+      "username" => {
+        changes.email = Some(
+          value
+            .as_str()
+            .ok_or(AppError::InvalidScimRequest)?
+            .to_string(),
+        );
+      }
+      "displayname" | "name.formatted" => {
+        changes.name = Some(match value {
+          Value::Null => None,
+          Value::String(name) => Some(name),
+          _ => return Err(AppError::InvalidScimRequest),
+        });
+      }
+      "name" => {
+        if let Some(formatted) = value.get("formatted") {
+          Self::patch_attribute(changes, "name.formatted", formatted.clone())?;
+        }
+      }
+      "active" => {
+        // Some providers send booleans as strings.
+        changes.active = Some(match value {
+          Value::Bool(active) => active,
+          Value::String(active) if active.eq_ignore_ascii_case("true") => true,
+          Value::String(active) if active.eq_ignore_ascii_case("false") => false,
+          _ => return Err(AppError::InvalidScimRequest),
+        });
+      }
+      _ => {}
+    }
+    Ok(())
+  }
+
+  /// Deprovisioned users stay visible so a provider can read and reactivate them.
+  async fn user(&self, id: Uuid) -> Result<UserEntity, AppError> {
+    self
+      .user_repository
+      .by_id_with_deleted(id)
+      .await?
+      .ok_or(AppError::UserNotFound)
+  }
+
+  /// Writes the changed columns, soft-deletes the user when it is deactivated and clears
+  /// `deleted_at` when it is activated. Emails of deleted users are still taken.
+  async fn apply(
+    &self,
+    user: UserEntity,
+    changes: ScimUserChanges,
+  ) -> Result<ScimUserResponse, AppError> {
+    if let Some(email) = changes.email {
+      let email: String = Self::normalize_email(&email)?;
+      if email != user.email {
+        if self.user_repository.email_exists(email.clone()).await? {
+          return Err(AppError::UserExistingEmail);
+        }
+        self.user_repository.update_email(user.id, email).await?;
+      }
+    }
+    if let Some(name) = changes.name {
+      if name != user.name {
+        self.user_repository.update_name(user.id, name).await?;
+      }
+    }
+
+    match changes.active {
+      Some(true) => {
+        self.user_repository.reactivate(user.id).await?;
+      }
+      Some(false) => {
+        self.user_repository.delete(user.id).await?;
+      }
+      None => {}
+    }
+
+    let user: UserEntity = self.user(user.id).await?;
+    Ok(self.scim_user_response_converter(user))
+  }
+}
+
+impl ScimManager for ScimManagerImpl {
+  fn service_provider_config(&self) -> ScimServiceProviderConfigResponse {
+    ScimServiceProviderConfigResponse {
+      schemas: vec![SCIM_SERVICE_PROVIDER_CONFIG_SCHEMA.to_string()],
+      patch: ScimSupported { supported: true },
+      bulk: ScimBulkSupported {
+        supported: false,
+        max_operations: 0,
+        max_payload_size: 0,
+      },
+      filter: ScimFilterSupported {
+        supported: true,
+        max_results: MAX_COUNT,
+      },
+      change_password: ScimSupported { supported: false },
+      sort: ScimSupported { supported: false },
+      etag: ScimSupported { supported: false },
+      authentication_schemes: vec![ScimAuthenticationScheme {
+        scheme_type: "oauthbearertoken".to_string(),
+        name: "Bearer Token".to_string(),
+        description: "Shared secret sent in the Authorization header".to_string(),
+        primary: true,
+      }],
+    }
+  }
+
+  async fn all(&self, payload: ScimListRequest) -> Result<ScimListResponse, AppError> {
+    let email: Option<String> = payload
+      .filter
+      .as_deref()
+      .map(Self::parse_filter)
+      .transpose()?;
+    let start_index: u64 = payload.start_index.unwrap_or(1).max(1);
+    let count: u64 = payload.count.unwrap_or(DEFAULT_COUNT).min(MAX_COUNT);
+
+    let total_results: i64 = self.user_repository.count(email.clone()).await?;
+    let users: Vec<UserEntity> = match count {
+      0 => Vec::new(),
+      _ => {
+        self
+          .user_repository
+          .search(email, start_index - 1, count)
+          .await?
+      }
+    };
+    let resources: Vec<ScimUserResponse> = users
+      .into_iter()
+      .map(|user| self.scim_user_response_converter(user))
+      .collect();
+
+    Ok(ScimListResponse {
+      schemas: vec![SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+      total_results,
+      start_index,
+      items_per_page: resources.len(),
+      resources,
+    })
+  }
+
+  async fn get(&self, id: Uuid) -> Result<ScimUserResponse, AppError> {
+    let user: UserEntity = self.user(id).await?;
+    Ok(self.scim_user_response_converter(user))
+  }
+
+  /// Provisioned users are verified by the identity provider. A user deprovisioned earlier is
+  /// restored under its id, but as a plain `user` without its former password, identities,
+  /// API keys or sessions.
+  async fn create(&self, payload: ScimUserRequest) -> Result<ScimUserResponse, AppError> {
+    let email: String = Self::normalize_email(&payload.user_name)?;
+    if self
+      .user_repository
+      .by_email(email.clone())
+      .await?
+      .is_some()
+    {
+      return Err(AppError::UserExistingEmail);
+    }
+
+    let password: Option<String> = payload.password.as_deref().map(HashUtils::hash_password);
+    let user: UserEntity = match self.user_repository.restore(email.clone()).await? {
+      Some(user) => {
+        if let Some(password) = password {
+          self
+            .user_repository
+            .update_password(user.id, password)
+            .await?;
+        }
+        user
+      }
+      None => self.user_repository.create(email, password, None).await?,
+    };
+    self
+      .user_repository
+      .update_status(user.id, UserStatus::Verified)
+      .await?;
+
+    let changes: ScimUserChanges = ScimUserChanges {
+      email: None,
+      ..Self::request_changes(&payload)
+    };
+    self.apply(user, changes).await
+  }
+
+  async fn replace(
+    &self,
+    id: Uuid,
+    payload: ScimUserRequest,
+  ) -> Result<ScimUserResponse, AppError> {
+    let user: UserEntity = self.user(id).await?;
+    self.apply(user, Self::request_changes(&payload)).await
+  }
+
+  async fn patch(&self, id: Uuid, payload: ScimPatchRequest) -> Result<ScimUserResponse, AppError> {
+    let user: UserEntity = self.user(id).await?;
+    let changes: ScimUserChanges = Self::patch_changes(payload.operations)?;
+    self.apply(user, changes).await
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), AppError> {
+    match self.user_repository.delete(id).await? {
+      true => Ok(()),
+      false => Err(AppError::UserNotFound),
+    }
+  }
+}
@@ -0,0 +1,34 @@
+use crate::api::client::scim::response::scim_response::{
+  ScimEmail, ScimMeta, ScimName, ScimUserResponse,
+};
+use crate::api::manager::scim::scim_manager::{ScimManagerImpl, SCIM_USER_SCHEMA};
+use crate::api::repository::users::entity::user_entity::UserEntity;
+
+pub trait ScimResponseConverter {
+  fn scim_user_response_converter(&self, user: UserEntity) -> ScimUserResponse;
+}
+
+impl ScimResponseConverter for ScimManagerImpl {
+  fn scim_user_response_converter(&self, user: UserEntity) -> ScimUserResponse {
+    ScimUserResponse {
+      schemas: vec![SCIM_USER_SCHEMA.to_string()],
+      id: user.id,
+      user_name: user.email.clone(),
+      name: user.name.clone().map(|name| ScimName {
+        formatted: Some(name),
+      }),
+      display_name: user.name,
+      emails: vec![ScimEmail {
+        value: user.email,
+        primary: true,
+      }],
+      active: user.deleted_at.is_none(),
+      meta: ScimMeta {
+        resource_type: "User".to_string(),
+        created: user.created_at,
+        last_modified: user.updated_at.or(user.created_at),
+        location: format!("{}/Users/{}", self.config.url, user.id),
+      },
+    }
+  }
+}
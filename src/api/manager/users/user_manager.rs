@@ -1,34 +1,56 @@
 use crate::api::client::users::request::user_request::{
-  UserUpdateRoleRequest, UserUpdateStatusRequest,
+  UserUpdateRequest, UserUpdateRoleRequest, UserUpdateStatusRequest,
 };
 use crate::api::client::users::response::user_response::UserResponse;
 use crate::api::manager::users::user_response_converter::UserResponseConverter;
-use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::pagination::Page;
+use crate::api::repository::users::constant::user_constant::UserStatus;
+use crate::api::repository::users::entity::user_entity::{UserEntity, UserFilter, Users};
 use crate::api::repository::users::repository::user_repository::UserRepository;
 use crate::core::error::error::AppError;
 use crate::core::request::pagination::Pagination;
 use crate::core::request::sorting::Sorting;
+use crate::core::security::jwt_manager::Claims;
 use crate::service::aws::s3::s3::S3Service;
 use crate::utils::datetime_utils::{TimeUtils, TimeUtilsBuilder};
+use crate::utils::network_utils::NetworkUtils;
 use aws_smithy_types::error::metadata::ProvideErrorMetadata;
 use axum::body::Bytes;
 use axum::extract::Multipart;
+use http::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use reqwest::{Client, Response, Url};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{error, info};
 use uuid::Uuid;
 
+const PICTURE_TIMEOUT_SECONDS: u64 = 10;
+const PICTURE_MAX_BYTES: usize = 5 * 1024 * 1024;
+
 pub trait UserManager: Send + Sync + 'static {
   async fn all(
     &self,
     pagination: Pagination,
-    sorting: Sorting,
-  ) -> Result<Vec<UserResponse>, AppError>;
+    sorting: Sorting<Users>,
+    filter: UserFilter,
+  ) -> Result<Page<UserResponse>, AppError>;
   async fn me(&self, id: Uuid) -> Result<UserEntity, AppError>;
+  async fn get(&self, id: Uuid) -> Result<UserResponse, AppError>;
+  async fn update(
+    &self,
+    actor_id: Uuid,
+    id: Uuid,
+    payload: UserUpdateRequest,
+  ) -> Result<UserResponse, AppError>;
+  /// Service clients have no account to check; any other subject must be an active user.
+  async fn ensure_active(&self, claims: &Claims) -> Result<(), AppError>;
   async fn upload_profile_url(
     &self,
     user_id: Uuid,
     file: Multipart,
   ) -> Result<Option<String>, AppError>;
+  async fn import_profile_url(&self, user_id: Uuid, picture_url: String) -> Result<(), AppError>;
   async fn update_role(&self, payload: UserUpdateRoleRequest) -> Result<(), AppError>;
   async fn update_status(&self, payload: UserUpdateStatusRequest) -> Result<(), AppError>;
 }
@@ -46,33 +68,169 @@ impl UserManagerImpl {
       s3_service,
     }
   }
+
+  /// S3 key of the user's avatar, and the value stored in `profile_url` with a cache-busting suffix.
+  fn profile_key(user_id: Uuid) -> (String, String) {
+    let key: String = format!("users/{}/{}", user_id, "profile");
+    let formatted_key: String = format!(
+      "{}?t={}",
+      key,
+      TimeUtilsBuilder::new(TimeUtils::utc_now()).to_timestamp()
+    );
+    (key, formatted_key)
+  }
+
+  /// Picture URLs come from third-party profiles, so only images on public https hosts are
+  /// fetched, from the addresses that were checked, without following redirects.
+  async fn download_picture(picture_url: &str) -> Result<Vec<u8>, AppError> {
+    let url: Url = Url::parse(picture_url).map_err(|_| AppError::InvalidPictureUrl)?;
+    let (host, addresses) = NetworkUtils::resolve_public_https(&url)
+      .await
+      .ok_or(AppError::InvalidPictureUrl)?;
+    let client: Client = Client::builder()
+      .timeout(Duration::from_secs(PICTURE_TIMEOUT_SECONDS))
+      .redirect(Policy::none())
+      .resolve_to_addrs(&host, &addresses)
+      .build()
+      .map_err(|_| AppError::SomethingWentWrong)?;
+
+    let mut resp: Response = match client.get(url).send().await {
+      Ok(resp) if resp.status().is_success() => resp,
+      Ok(resp) => {
+        error!(
+          "Failed to download picture {}, status: {}",
+          picture_url,
+          resp.status()
+        );
+        return Err(AppError::SomethingWentWrong);
+      }
+      Err(e) => {
+        error!("Error downloading picture {}: {:?}", picture_url, e);
+        return Err(AppError::SomethingWentWrong);
+      }
+    };
+    let is_image: bool = resp
+      .headers()
+      .get(CONTENT_TYPE)
+      .and_then(|content_type| content_type.to_str().ok())
+      .is_some_and(|content_type| content_type.starts_with("image/"));
+    if !is_image || resp.content_length().unwrap_or(0) > PICTURE_MAX_BYTES as u64 {
+      return Err(AppError::InvalidPictureUrl);
+    }
+
+    let mut data: Vec<u8> = Vec::new();
+    while let Some(chunk) = resp.chunk().await.map_err(|e| {
+      error!("Failed to read picture {}: {:?}", picture_url, e);
+      AppError::SomethingWentWrong
+    })? {
+      if data.len() + chunk.len() > PICTURE_MAX_BYTES {
+        return Err(AppError::InvalidPictureUrl);
+      }
+      data.extend_from_slice(&chunk);
+    }
+    Ok(data)
+  }
+
+  /// The reason and end of a restriction only go with a `suspended` or `banned` status, and a
+  /// ban has no end.
+  fn validate_restriction(payload: &UserUpdateRequest) -> Result<(), AppError> {
+    let valid: bool = match (payload.status, payload.status_until) {
+      (Some(UserStatus::Banned), Some(_)) => false,
+      (Some(UserStatus::Suspended), Some(until)) => until > TimeUtils::utc_now(),
+      (Some(UserStatus::Suspended | UserStatus::Banned), None) => true,
+      _ => payload.status_reason.is_none() && payload.status_until.is_none(),
+    };
+    match valid {
+      true => Ok(()),
+      false => Err(AppError::InvalidUserRequest),
+    }
+  }
 }
 
 impl UserManager for UserManagerImpl {
   async fn all(
     &self,
     pagination: Pagination,
-    sorting: Sorting,
-  ) -> Result<Vec<UserResponse>, AppError> {
-    let users: Vec<UserEntity> = self.user_repository.all(pagination, sorting).await?;
-    Ok(self.users_response_converter(users).await)
+    sorting: Sorting<Users>,
+    filter: UserFilter,
+  ) -> Result<Page<UserResponse>, AppError> {
+    let users: Page<UserEntity> = self
+      .user_repository
+      .all(pagination, sorting, filter)
+      .await?;
+    Ok(Page {
+      items: self.users_response_converter(users.items).await,
+      total: users.total,
+      next_cursor: users.next_cursor,
+    })
   }
 
   async fn me(&self, user_id: Uuid) -> Result<UserEntity, AppError> {
     Ok(self.user_repository.me(user_id).await?)
   }
 
+  async fn get(&self, id: Uuid) -> Result<UserResponse, AppError> {
+    let user: UserEntity = self
+      .user_repository
+      .by_id(id)
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    Ok(self.user_response_converter(user).await)
+  }
+
+  /// Admins cannot suspend or ban themselves, so there is always someone left to lift it.
+  async fn update(
+    &self,
+    actor_id: Uuid,
+    id: Uuid,
+    payload: UserUpdateRequest,
+  ) -> Result<UserResponse, AppError> {
+    if actor_id == id
+      && matches!(
+        payload.status,
+        Some(UserStatus::Suspended | UserStatus::Banned)
+      )
+    {
+      return Err(AppError::InvalidUserRequest);
+    }
+    Self::validate_restriction(&payload)?;
+    if self.user_repository.by_id(id).await?.is_none() {
+      return Err(AppError::UserNotFound);
+    }
+
+    if let Some(name) = payload.name {
+      self.user_repository.update_name(id, Some(name)).await?;
+    }
+    if let Some(role) = payload.role {
+      self.user_repository.update_role(id, role).await?;
+    }
+    if let Some(status) = payload.status {
+      self
+        .user_repository
+        .update_restriction(id, status, payload.status_reason, payload.status_until)
+        .await?;
+    }
+    self.get(id).await
+  }
+
+  /// Tokens outlive a suspension or ban, so callers recheck the account on every request.
+  /// Subjects that are not users, such as service clients, pass.
+  async fn ensure_active(&self, claims: &Claims) -> Result<(), AppError> {
+    if claims.client_id.is_some() {
+      return Ok(());
+    }
+    match self.user_repository.by_id(claims.sub).await? {
+      Some(user) => user.ensure_active(),
+      None => Err(AppError::InvalidJwtToken),
+    }
+  }
+
   async fn upload_profile_url(
     &self,
     user_id: Uuid,
     mut file: Multipart,
   ) -> Result<Option<String>, AppError> {
-    let key: String = format!("users/{}/{}", user_id, "profile");
-    let formatted_key: String = format!(
-      "{}?t={}",
-      key,
-      TimeUtilsBuilder::new(TimeUtils::utc_now()).to_timestamp()
-    );
+    let (key, formatted_key) = Self::profile_key(user_id);
 
     while let Some(field) = file.next_field().await.unwrap() {
       let data: Bytes = match field.bytes().await {
@@ -104,6 +262,22 @@ impl UserManager for UserManagerImpl {
     Ok(url)
   }
 
+  async fn import_profile_url(&self, user_id: Uuid, picture_url: String) -> Result<(), AppError> {
+    let data: Vec<u8> = Self::download_picture(&picture_url).await?;
+
+    let (key, formatted_key) = Self::profile_key(user_id);
+    if let Err(e) = self.s3_service.upload_object(&key, data).await {
+      error!("Failed to upload file: {:?}", e.message());
+      return Err(AppError::SomethingWentWrong);
+    }
+    info!("Profile picture of {} imported to {:?}", user_id, key);
+
+    self
+      .user_repository
+      .update_profile_url(user_id, formatted_key)
+      .await
+  }
+
   async fn update_role(&self, payload: UserUpdateRoleRequest) -> Result<(), AppError> {
     Ok(
       self
@@ -27,6 +27,9 @@ impl UserResponseConverter for UserManagerImpl {
       email: user.email,
       name: user.name,
       role: user.role,
+      status: user.status,
+      status_reason: user.status_reason,
+      status_until: user.status_until,
       profile_url: profile,
       logged_in_at: user.logged_in_at,
       created_at: user.created_at,
@@ -4,4 +4,4 @@ pub(crate) mod manager;
 pub(crate) mod repository;
 pub(crate) mod server;
 pub(crate) mod state;
-mod test;
+pub(crate) mod test;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+pub const API_KEY_PREFIX: &str = "pat_";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy)]
+pub enum ApiKeyScope {
+  #[serde(rename = "read")]
+  Read,
+  #[serde(rename = "write")]
+  Write,
+  #[serde(rename = "admin")]
+  Admin,
+}
+
+impl ApiKeyScope {
+  pub fn as_str(&self) -> &str {
+    match self {
+      ApiKeyScope::Read => "read",
+      ApiKeyScope::Write => "write",
+      ApiKeyScope::Admin => "admin",
+    }
+  }
+
+  pub fn to_strings(scopes: &[ApiKeyScope]) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for scope in scopes.iter().map(ApiKeyScope::as_str) {
+      if !values.iter().any(|value| value == scope) {
+        values.push(scope.to_string());
+      }
+    }
+    values
+  }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum ApiKeys {
+  Table,
+  Id,
+  UserId,
+  Name,
+  KeyHash,
+  Scopes,
+  ExpiresAt,
+  LastUsedAt,
+  CreatedAt,
+  RevokedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct ApiKeyEntity {
+  pub id: Uuid,
+  pub user_id: Uuid,
+  pub name: String,
+  pub key_hash: String,
+  pub scopes: Vec<String>,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub revoked_at: Option<DateTime<Utc>>,
+}
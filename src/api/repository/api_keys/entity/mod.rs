@@ -0,0 +1 @@
+pub(crate) mod api_key_entity;
@@ -0,0 +1,127 @@
+use crate::api::repository::api_keys::entity::api_key_entity::{ApiKeyEntity, ApiKeys};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Alias, ColumnRef, Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    key_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Result<ApiKeyEntity, AppError>;
+  async fn by_id(&self, id: Uuid) -> Result<Option<ApiKeyEntity>, AppError>;
+  async fn by_user_id(&self, user_id: Uuid) -> Result<Vec<ApiKeyEntity>, AppError>;
+  async fn update_last_used_at(&self, id: Uuid) -> Result<(), AppError>;
+  async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl ApiKeyRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    key_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Result<ApiKeyEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(ApiKeys::Table)
+      .columns([
+        ApiKeys::Id,
+        ApiKeys::UserId,
+        ApiKeys::Name,
+        ApiKeys::KeyHash,
+        ApiKeys::Scopes,
+        ApiKeys::ExpiresAt,
+      ])
+      .values_panic([
+        id.to_string().into(),
+        user_id.to_string().into(),
+        name.into(),
+        key_hash.into(),
+        Expr::val(scopes).cast_as(Alias::new("TEXT[]")),
+        expires_at
+          .map(|expires_at| Expr::value(expires_at.to_rfc3339()))
+          .unwrap_or(Expr::value(None::<&str>)),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let api_key: Result<ApiKeyEntity, sqlx::Error> = sqlx::query_as::<_, ApiKeyEntity>(&query)
+      .fetch_one(conn)
+      .await;
+    Ok(api_key?)
+  }
+
+  async fn by_id(&self, id: Uuid) -> Result<Option<ApiKeyEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(ApiKeys::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(ApiKeys::Id).eq(id.to_string()))
+      .and_where(Expr::col(ApiKeys::RevokedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let api_key: Result<Option<ApiKeyEntity>, sqlx::Error> =
+      sqlx::query_as::<_, ApiKeyEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(api_key?)
+  }
+
+  async fn by_user_id(&self, user_id: Uuid) -> Result<Vec<ApiKeyEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(ApiKeys::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(ApiKeys::UserId).eq(user_id.to_string()))
+      .and_where(Expr::col(ApiKeys::RevokedAt).is_null())
+      .order_by(ApiKeys::CreatedAt, Order::Desc)
+      .to_string(PostgresQueryBuilder);
+
+    let api_keys: Result<Vec<ApiKeyEntity>, sqlx::Error> =
+      sqlx::query_as::<_, ApiKeyEntity>(&query)
+        .fetch_all(conn)
+        .await;
+    Ok(api_keys?)
+  }
+
+  async fn update_last_used_at(&self, id: Uuid) -> Result<(), AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(ApiKeys::Table)
+      .value(ApiKeys::LastUsedAt, TimeUtils::utc_now().to_rfc3339())
+      .and_where(Expr::col(ApiKeys::Id).eq(id.to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    sqlx::query(&query).execute(conn).await?;
+    Ok(())
+  }
+
+  async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(ApiKeys::Table)
+      .value(ApiKeys::RevokedAt, TimeUtils::utc_now().to_rfc3339())
+      .and_where(Expr::col(ApiKeys::Id).eq(id.to_string()))
+      .and_where(Expr::col(ApiKeys::UserId).eq(user_id.to_string()))
+      .and_where(Expr::col(ApiKeys::RevokedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
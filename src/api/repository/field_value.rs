@@ -10,6 +10,7 @@ pub enum FieldValue {
   Float(f32),              // Maps to PostgresSQL FLOAT or DOUBLE PRECISION
   Enum(String),            // Maps to PostgresSQL ENUM
   DateTime(DateTime<Utc>), // Maps to PostgresSQL TIMESTAMP
+  Null,                    // Maps to PostgresSQL NULL
 }
 
 impl FieldValue {
@@ -23,6 +24,7 @@ impl FieldValue {
       FieldValue::DateTime(val) => {
         SimpleExpr::Value(Value::String(Some(Box::new(val.to_rfc3339()))))
       }
+      FieldValue::Null => SimpleExpr::Value(Value::String(None)),
     }
   }
 }
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum ImpersonationAudits {
+  Table,
+  ActorId,
+  UserId,
+  Jti,
+  ExpiresAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct ImpersonationAuditEntity {
+  pub id: Uuid,
+  pub actor_id: Uuid,
+  pub user_id: Uuid,
+  pub jti: Uuid,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+/// One request made with an impersonation token.
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum ImpersonationActions {
+  Table,
+  Jti,
+  ActorId,
+  UserId,
+  Method,
+  Path,
+}
@@ -0,0 +1,95 @@
+use crate::api::repository::impersonation_audits::entity::impersonation_audit_entity::{
+  ImpersonationActions, ImpersonationAuditEntity, ImpersonationAudits,
+};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{ColumnRef, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ImpersonationAuditRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    actor_id: Uuid,
+    user_id: Uuid,
+    jti: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Result<ImpersonationAuditEntity, AppError>;
+  async fn create_action(
+    &self,
+    jti: Uuid,
+    actor_id: Uuid,
+    user_id: Uuid,
+    method: &str,
+    path: &str,
+  ) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl ImpersonationAuditRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    actor_id: Uuid,
+    user_id: Uuid,
+    jti: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Result<ImpersonationAuditEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(ImpersonationAudits::Table)
+      .columns([
+        ImpersonationAudits::ActorId,
+        ImpersonationAudits::UserId,
+        ImpersonationAudits::Jti,
+        ImpersonationAudits::ExpiresAt,
+      ])
+      .values_panic([
+        actor_id.to_string().into(),
+        user_id.to_string().into(),
+        jti.to_string().into(),
+        expires_at.map(|expires_at| expires_at.to_rfc3339()).into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let audit: Result<ImpersonationAuditEntity, sqlx::Error> =
+      sqlx::query_as::<_, ImpersonationAuditEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(audit?)
+  }
+
+  async fn create_action(
+    &self,
+    jti: Uuid,
+    actor_id: Uuid,
+    user_id: Uuid,
+    method: &str,
+    path: &str,
+  ) -> Result<(), AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(ImpersonationActions::Table)
+      .columns([
+        ImpersonationActions::Jti,
+        ImpersonationActions::ActorId,
+        ImpersonationActions::UserId,
+        ImpersonationActions::Method,
+        ImpersonationActions::Path,
+      ])
+      .values_panic([
+        jti.to_string().into(),
+        actor_id.to_string().into(),
+        user_id.to_string().into(),
+        method.into(),
+        path.into(),
+      ])
+      .to_string(PostgresQueryBuilder);
+    let result: Result<PgQueryResult, sqlx::Error> = sqlx::query(&query).execute(conn).await;
+    result?;
+    Ok(())
+  }
+}
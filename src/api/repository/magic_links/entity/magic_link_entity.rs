@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum MagicLinks {
+  Table,
+  Id,
+  Email,
+  ExpiresAt,
+  UsedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct MagicLinkEntity {
+  pub id: Uuid,
+  pub email: String,
+  pub expires_at: DateTime<Utc>,
+  pub used_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1 @@
+pub(crate) mod magic_link_entity;
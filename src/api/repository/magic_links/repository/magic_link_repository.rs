@@ -0,0 +1,58 @@
+use crate::api::repository::magic_links::entity::magic_link_entity::{MagicLinkEntity, MagicLinks};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{ColumnRef, Expr, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait MagicLinkRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    email: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<MagicLinkEntity, AppError>;
+  async fn consume(&self, id: Uuid) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl MagicLinkRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    email: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<MagicLinkEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(MagicLinks::Table)
+      .columns([MagicLinks::Email, MagicLinks::ExpiresAt])
+      .values_panic([email.into(), expires_at.to_rfc3339().into()])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let magic_link: Result<MagicLinkEntity, sqlx::Error> =
+      sqlx::query_as::<_, MagicLinkEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(magic_link?)
+  }
+
+  /// Marks the link as used; returns false when it was already used or has expired.
+  async fn consume(&self, id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let now: String = TimeUtils::utc_now().to_rfc3339();
+    let query: String = Query::update()
+      .table(MagicLinks::Table)
+      .value(MagicLinks::UsedAt, now.clone())
+      .and_where(Expr::col(MagicLinks::Id).eq(id.to_string()))
+      .and_where(Expr::col(MagicLinks::UsedAt).is_null())
+      .and_where(Expr::col(MagicLinks::ExpiresAt).gt(now))
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
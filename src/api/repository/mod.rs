@@ -1,9 +1,22 @@
 use crate::db::db::PostgresDatabase;
 
+pub(crate) mod api_keys;
 mod field_value;
-mod pagination;
+pub(crate) mod impersonation_audits;
+pub(crate) mod magic_links;
+pub(crate) mod oauth2_states;
+pub(crate) mod oauth_authorization_codes;
+pub(crate) mod oauth_clients;
+pub(crate) mod organizations;
+pub(crate) mod pagination;
 pub(crate) mod refresh_tokens;
-mod sorting;
+pub(crate) mod roles;
+pub(crate) mod saml_assertions;
+pub(crate) mod saml_tenants;
+pub(crate) mod sorting;
+pub(crate) mod tenant;
+pub(crate) mod user_identities;
+pub(crate) mod user_invitations;
 pub(crate) mod users;
 
 #[derive(Clone)]
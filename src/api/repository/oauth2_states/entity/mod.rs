@@ -0,0 +1 @@
+pub(crate) mod oauth2_state_entity;
@@ -0,0 +1,26 @@
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum OAuth2States {
+  #[iden = "oauth2_states"]
+  Table,
+  State,
+  Provider,
+  PkceVerifier,
+  Nonce,
+  ExpiresAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct OAuth2StateEntity {
+  pub state: String,
+  pub provider: OAuth2Provider,
+  pub pkce_verifier: String,
+  pub nonce: Option<String>,
+  pub expires_at: DateTime<Utc>,
+  pub created_at: Option<DateTime<Utc>>,
+}
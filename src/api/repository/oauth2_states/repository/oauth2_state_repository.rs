@@ -0,0 +1,86 @@
+use crate::api::repository::oauth2_states::entity::oauth2_state_entity::{
+  OAuth2StateEntity, OAuth2States,
+};
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{ColumnRef, Expr, PostgresQueryBuilder, Query};
+
+#[async_trait]
+pub trait OAuth2StateRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    state: String,
+    provider: OAuth2Provider,
+    pkce_verifier: String,
+    nonce: Option<String>,
+    expires_at: DateTime<Utc>,
+  ) -> Result<OAuth2StateEntity, AppError>;
+  async fn consume(
+    &self,
+    state: String,
+    provider: OAuth2Provider,
+  ) -> Result<Option<OAuth2StateEntity>, AppError>;
+}
+
+#[async_trait]
+impl OAuth2StateRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    state: String,
+    provider: OAuth2Provider,
+    pkce_verifier: String,
+    nonce: Option<String>,
+    expires_at: DateTime<Utc>,
+  ) -> Result<OAuth2StateEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(OAuth2States::Table)
+      .columns([
+        OAuth2States::State,
+        OAuth2States::Provider,
+        OAuth2States::PkceVerifier,
+        OAuth2States::Nonce,
+        OAuth2States::ExpiresAt,
+      ])
+      .values_panic([
+        state.into(),
+        provider.as_str().into(),
+        pkce_verifier.into(),
+        nonce.into(),
+        expires_at.to_rfc3339().into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let oauth2_state: Result<OAuth2StateEntity, sqlx::Error> =
+      sqlx::query_as::<_, OAuth2StateEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(oauth2_state?)
+  }
+
+  /// Deletes the state so it can only be used once; returns None when unknown or expired.
+  async fn consume(
+    &self,
+    state: String,
+    provider: OAuth2Provider,
+  ) -> Result<Option<OAuth2StateEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::delete()
+      .from_table(OAuth2States::Table)
+      .and_where(Expr::col(OAuth2States::State).eq(state))
+      .and_where(Expr::col(OAuth2States::Provider).eq(provider.as_str()))
+      .and_where(Expr::col(OAuth2States::ExpiresAt).gt(TimeUtils::utc_now().to_rfc3339()))
+      .returning_all()
+      .to_string(PostgresQueryBuilder);
+    let oauth2_state: Result<Option<OAuth2StateEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OAuth2StateEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(oauth2_state?)
+  }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum OAuthAuthorizationCodes {
+  #[iden = "oauth_authorization_codes"]
+  Table,
+  Id,
+  ClientId,
+  UserId,
+  CodeHash,
+  RedirectUri,
+  Scopes,
+  Nonce,
+  CodeChallenge,
+  ExpiresAt,
+  UsedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct OAuthAuthorizationCodeEntity {
+  pub id: Uuid,
+  pub client_id: Uuid,
+  pub user_id: Uuid,
+  pub code_hash: String,
+  pub redirect_uri: String,
+  pub scopes: Vec<String>,
+  pub nonce: Option<String>,
+  pub code_challenge: String,
+  pub expires_at: DateTime<Utc>,
+  pub used_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
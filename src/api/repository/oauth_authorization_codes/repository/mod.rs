@@ -0,0 +1 @@
+pub(crate) mod oauth_authorization_code_repository;
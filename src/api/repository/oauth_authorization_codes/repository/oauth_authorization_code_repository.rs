@@ -0,0 +1,115 @@
+use crate::api::repository::oauth_authorization_codes::entity::oauth_authorization_code_entity::{
+  OAuthAuthorizationCodeEntity, OAuthAuthorizationCodes,
+};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Alias, ColumnRef, Expr, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OAuthAuthorizationCodeRepository: Send + Sync + 'static {
+  #[allow(clippy::too_many_arguments)]
+  async fn create(
+    &self,
+    id: Uuid,
+    client_id: Uuid,
+    user_id: Uuid,
+    code_hash: String,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    nonce: Option<String>,
+    code_challenge: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<OAuthAuthorizationCodeEntity, AppError>;
+  async fn by_id(&self, id: Uuid) -> Result<Option<OAuthAuthorizationCodeEntity>, AppError>;
+  async fn consume(&self, id: Uuid) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl OAuthAuthorizationCodeRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    id: Uuid,
+    client_id: Uuid,
+    user_id: Uuid,
+    code_hash: String,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    nonce: Option<String>,
+    code_challenge: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<OAuthAuthorizationCodeEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(OAuthAuthorizationCodes::Table)
+      .columns([
+        OAuthAuthorizationCodes::Id,
+        OAuthAuthorizationCodes::ClientId,
+        OAuthAuthorizationCodes::UserId,
+        OAuthAuthorizationCodes::CodeHash,
+        OAuthAuthorizationCodes::RedirectUri,
+        OAuthAuthorizationCodes::Scopes,
+        OAuthAuthorizationCodes::Nonce,
+        OAuthAuthorizationCodes::CodeChallenge,
+        OAuthAuthorizationCodes::ExpiresAt,
+      ])
+      .values_panic([
+        id.to_string().into(),
+        client_id.to_string().into(),
+        user_id.to_string().into(),
+        code_hash.into(),
+        redirect_uri.into(),
+        Expr::val(scopes).cast_as(Alias::new("TEXT[]")),
+        nonce
+          .as_deref()
+          .map(Expr::value)
+          .unwrap_or(Expr::value(None::<&str>)),
+        code_challenge.into(),
+        expires_at.to_rfc3339().into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let code: Result<OAuthAuthorizationCodeEntity, sqlx::Error> =
+      sqlx::query_as::<_, OAuthAuthorizationCodeEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(code?)
+  }
+
+  /// Reads from the primary since codes are exchanged right after they are issued.
+  async fn by_id(&self, id: Uuid) -> Result<Option<OAuthAuthorizationCodeEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::select()
+      .from(OAuthAuthorizationCodes::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(OAuthAuthorizationCodes::Id).eq(id.to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    let code: Result<Option<OAuthAuthorizationCodeEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OAuthAuthorizationCodeEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(code?)
+  }
+
+  /// Marks the code as used; returns false when it was already used or has expired.
+  async fn consume(&self, id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let now: String = TimeUtils::utc_now().to_rfc3339();
+    let query: String = Query::update()
+      .table(OAuthAuthorizationCodes::Table)
+      .value(OAuthAuthorizationCodes::UsedAt, now.clone())
+      .and_where(Expr::col(OAuthAuthorizationCodes::Id).eq(id.to_string()))
+      .and_where(Expr::col(OAuthAuthorizationCodes::UsedAt).is_null())
+      .and_where(Expr::col(OAuthAuthorizationCodes::ExpiresAt).gt(now))
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
@@ -0,0 +1 @@
+pub(crate) mod oauth_client_entity;
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum OAuthClients {
+  #[iden = "oauth_clients"]
+  Table,
+  Id,
+  ClientId,
+  ClientSecret,
+  Name,
+  Scopes,
+  RedirectUris,
+  DeletedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct OAuthClientEntity {
+  pub id: Uuid,
+  pub client_id: String,
+  pub client_secret: String,
+  pub name: String,
+  pub scopes: Vec<String>,
+  pub redirect_uris: Vec<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub deleted_at: Option<DateTime<Utc>>,
+}
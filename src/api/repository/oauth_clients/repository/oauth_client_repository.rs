@@ -0,0 +1,92 @@
+use crate::api::repository::oauth_clients::entity::oauth_client_entity::{
+  OAuthClientEntity, OAuthClients,
+};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use sea_query::{Alias, ColumnRef, Expr, PostgresQueryBuilder, Query};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OAuthClientRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    client_id: String,
+    client_secret: String,
+    name: String,
+    scopes: Vec<String>,
+    redirect_uris: Vec<String>,
+  ) -> Result<OAuthClientEntity, AppError>;
+  async fn by_id(&self, id: Uuid) -> Result<Option<OAuthClientEntity>, AppError>;
+  async fn by_client_id(&self, client_id: String) -> Result<Option<OAuthClientEntity>, AppError>;
+}
+
+#[async_trait]
+impl OAuthClientRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    client_id: String,
+    client_secret: String,
+    name: String,
+    scopes: Vec<String>,
+    redirect_uris: Vec<String>,
+  ) -> Result<OAuthClientEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(OAuthClients::Table)
+      .columns([
+        OAuthClients::ClientId,
+        OAuthClients::ClientSecret,
+        OAuthClients::Name,
+        OAuthClients::Scopes,
+        OAuthClients::RedirectUris,
+      ])
+      .values_panic([
+        client_id.into(),
+        client_secret.into(),
+        name.into(),
+        Expr::val(scopes).cast_as(Alias::new("TEXT[]")),
+        Expr::val(redirect_uris).cast_as(Alias::new("TEXT[]")),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let client: Result<OAuthClientEntity, sqlx::Error> =
+      sqlx::query_as::<_, OAuthClientEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(client?)
+  }
+
+  async fn by_id(&self, id: Uuid) -> Result<Option<OAuthClientEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(OAuthClients::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(OAuthClients::Id).eq(id.to_string()))
+      .and_where(Expr::col(OAuthClients::DeletedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let client: Result<Option<OAuthClientEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OAuthClientEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(client?)
+  }
+
+  async fn by_client_id(&self, client_id: String) -> Result<Option<OAuthClientEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(OAuthClients::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(OAuthClients::ClientId).eq(client_id))
+      .and_where(Expr::col(OAuthClients::DeletedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let client: Result<Option<OAuthClientEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OAuthClientEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(client?)
+  }
+}
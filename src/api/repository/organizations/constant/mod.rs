@@ -0,0 +1 @@
+pub(crate) mod organization_constant;
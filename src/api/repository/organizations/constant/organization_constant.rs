@@ -0,0 +1,43 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Eq, Copy)]
+#[sqlx(type_name = "organization_role", rename_all = "snake_case")]
+pub enum OrganizationRole {
+  #[serde(rename = "member")]
+  Member,
+  #[serde(rename = "admin")]
+  Admin,
+  #[serde(rename = "owner")]
+  Owner,
+}
+
+impl OrganizationRole {
+  pub fn as_str(&self) -> &str {
+    match self {
+      OrganizationRole::Member => "member",
+      OrganizationRole::Admin => "admin",
+      OrganizationRole::Owner => "owner",
+    }
+  }
+
+  fn level(&self) -> usize {
+    match self {
+      OrganizationRole::Member => 0,
+      OrganizationRole::Admin => 1,
+      OrganizationRole::Owner => 2,
+    }
+  }
+
+  /// A role has every privilege of the roles below it.
+  pub fn includes(&self, role: OrganizationRole) -> bool {
+    self.level() >= role.level()
+  }
+}
+
+impl From<OrganizationRole> for Value {
+  fn from(role: OrganizationRole) -> Self {
+    Value::from(role.as_str().to_string())
+  }
+}
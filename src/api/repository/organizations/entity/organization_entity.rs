@@ -0,0 +1,42 @@
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum Organizations {
+  Table,
+  Id,
+  Name,
+  CreatedAt,
+}
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum OrganizationMembers {
+  Table,
+  OrganizationId,
+  UserId,
+  Role,
+  InvitedBy,
+  AcceptedAt,
+  CreatedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct OrganizationEntity {
+  pub id: Uuid,
+  pub name: String,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct OrganizationMemberEntity {
+  pub organization_id: Uuid,
+  pub user_id: Uuid,
+  pub role: OrganizationRole,
+  pub invited_by: Option<Uuid>,
+  pub accepted_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,3 @@
+pub mod constant;
+pub mod entity;
+pub mod repository;
@@ -0,0 +1,306 @@
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use crate::api::repository::organizations::entity::organization_entity::{
+  OrganizationEntity, OrganizationMemberEntity, OrganizationMembers, Organizations,
+};
+use crate::api::repository::tenant::TenantTransaction;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use sea_query::{Alias, Asterisk, ColumnRef, Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+/// Methods keyed by an organization run in a [`TenantTransaction`] for it, which a request
+/// opens once and shares between calls. `create` and `by_member` span organizations and open
+/// their own.
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync + 'static {
+  async fn begin(&self, organization_id: Uuid) -> Result<TenantTransaction, AppError>;
+  async fn create(
+    &self,
+    transaction: &mut TenantTransaction,
+    name: String,
+  ) -> Result<OrganizationEntity, AppError>;
+  async fn by_id(
+    &self,
+    transaction: &mut TenantTransaction,
+  ) -> Result<Option<OrganizationEntity>, AppError>;
+  async fn by_member(&self, user_id: Uuid) -> Result<Vec<OrganizationEntity>, AppError>;
+  async fn add_member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+    role: OrganizationRole,
+    invited_by: Option<Uuid>,
+    accepted: bool,
+  ) -> Result<OrganizationMemberEntity, AppError>;
+  async fn member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<Option<OrganizationMemberEntity>, AppError>;
+  async fn members(
+    &self,
+    transaction: &mut TenantTransaction,
+  ) -> Result<Vec<OrganizationMemberEntity>, AppError>;
+  async fn accept(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<bool, AppError>;
+  async fn remove_member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<bool, AppError>;
+  async fn transfer_ownership(
+    &self,
+    transaction: &mut TenantTransaction,
+    owner_id: Uuid,
+    user_id: Uuid,
+  ) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl OrganizationRepository for RepositoryImpl {
+  async fn begin(&self, organization_id: Uuid) -> Result<TenantTransaction, AppError> {
+    Self::tenant_transaction(self.db_pool.primary(), organization_id).await
+  }
+
+  /// Inserts the organization of the transaction, whose id is picked up front.
+  async fn create(
+    &self,
+    transaction: &mut TenantTransaction,
+    name: String,
+  ) -> Result<OrganizationEntity, AppError> {
+    let query: String = Query::insert()
+      .into_table(Organizations::Table)
+      .columns([Organizations::Id, Organizations::Name])
+      .values_panic([
+        transaction.organization_id().to_string().into(),
+        name.into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+
+    let organization: OrganizationEntity = sqlx::query_as::<_, OrganizationEntity>(&query)
+      .fetch_one(transaction.connection())
+      .await?;
+    Ok(organization)
+  }
+
+  async fn by_id(
+    &self,
+    transaction: &mut TenantTransaction,
+  ) -> Result<Option<OrganizationEntity>, AppError> {
+    let query: String = Query::select()
+      .from(Organizations::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(Organizations::Id).eq(transaction.organization_id().to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    let organization: Result<Option<OrganizationEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OrganizationEntity>(&query)
+        .fetch_optional(transaction.connection())
+        .await;
+    Ok(organization?)
+  }
+
+  /// Organizations the user has joined; pending invitations are left out.
+  async fn by_member(&self, user_id: Uuid) -> Result<Vec<OrganizationEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .column((Organizations::Table, Asterisk))
+      .from(Organizations::Table)
+      .inner_join(
+        OrganizationMembers::Table,
+        Expr::col((
+          OrganizationMembers::Table,
+          OrganizationMembers::OrganizationId,
+        ))
+        .equals((Organizations::Table, Organizations::Id)),
+      )
+      .and_where(
+        Expr::col((OrganizationMembers::Table, OrganizationMembers::UserId))
+          .eq(user_id.to_string()),
+      )
+      .and_where(
+        Expr::col((OrganizationMembers::Table, OrganizationMembers::AcceptedAt)).is_not_null(),
+      )
+      .order_by((Organizations::Table, Organizations::CreatedAt), Order::Asc)
+      .to_string(PostgresQueryBuilder);
+
+    let mut transaction: Transaction<'static, Postgres> =
+      Self::member_transaction(conn, user_id).await?;
+    let organizations: Vec<OrganizationEntity> = sqlx::query_as::<_, OrganizationEntity>(&query)
+      .fetch_all(&mut *transaction)
+      .await?;
+    transaction.commit().await?;
+    Ok(organizations)
+  }
+
+  async fn add_member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+    role: OrganizationRole,
+    invited_by: Option<Uuid>,
+    accepted: bool,
+  ) -> Result<OrganizationMemberEntity, AppError> {
+    let query: String = Query::insert()
+      .into_table(OrganizationMembers::Table)
+      .columns([
+        OrganizationMembers::OrganizationId,
+        OrganizationMembers::UserId,
+        OrganizationMembers::Role,
+        OrganizationMembers::InvitedBy,
+        OrganizationMembers::AcceptedAt,
+      ])
+      .values_panic([
+        transaction.organization_id().to_string().into(),
+        user_id.to_string().into(),
+        role.into(),
+        invited_by
+          .map(|invited_by| Expr::value(invited_by.to_string()))
+          .unwrap_or(Expr::value(None::<&str>)),
+        match accepted {
+          true => Expr::value(TimeUtils::utc_now().to_rfc3339()),
+          false => Expr::value(None::<&str>),
+        },
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+
+    let member: Result<OrganizationMemberEntity, sqlx::Error> =
+      sqlx::query_as::<_, OrganizationMemberEntity>(&query)
+        .fetch_one(transaction.connection())
+        .await;
+    Ok(member?)
+  }
+
+  async fn member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<Option<OrganizationMemberEntity>, AppError> {
+    let query: String = Query::select()
+      .from(OrganizationMembers::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(
+        Expr::col(OrganizationMembers::OrganizationId)
+          .eq(transaction.organization_id().to_string()),
+      )
+      .and_where(Expr::col(OrganizationMembers::UserId).eq(user_id.to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    let member: Result<Option<OrganizationMemberEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OrganizationMemberEntity>(&query)
+        .fetch_optional(transaction.connection())
+        .await;
+    Ok(member?)
+  }
+
+  async fn members(
+    &self,
+    transaction: &mut TenantTransaction,
+  ) -> Result<Vec<OrganizationMemberEntity>, AppError> {
+    let query: String = Query::select()
+      .from(OrganizationMembers::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(
+        Expr::col(OrganizationMembers::OrganizationId)
+          .eq(transaction.organization_id().to_string()),
+      )
+      .order_by(OrganizationMembers::CreatedAt, Order::Asc)
+      .to_string(PostgresQueryBuilder);
+
+    let members: Result<Vec<OrganizationMemberEntity>, sqlx::Error> =
+      sqlx::query_as::<_, OrganizationMemberEntity>(&query)
+        .fetch_all(transaction.connection())
+        .await;
+    Ok(members?)
+  }
+
+  async fn accept(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<bool, AppError> {
+    let query: String = Query::update()
+      .table(OrganizationMembers::Table)
+      .value(
+        OrganizationMembers::AcceptedAt,
+        TimeUtils::utc_now().to_rfc3339(),
+      )
+      .and_where(
+        Expr::col(OrganizationMembers::OrganizationId)
+          .eq(transaction.organization_id().to_string()),
+      )
+      .and_where(Expr::col(OrganizationMembers::UserId).eq(user_id.to_string()))
+      .and_where(Expr::col(OrganizationMembers::AcceptedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query)
+      .execute(transaction.connection())
+      .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  async fn remove_member(
+    &self,
+    transaction: &mut TenantTransaction,
+    user_id: Uuid,
+  ) -> Result<bool, AppError> {
+    let query: String = Query::delete()
+      .from_table(OrganizationMembers::Table)
+      .and_where(
+        Expr::col(OrganizationMembers::OrganizationId)
+          .eq(transaction.organization_id().to_string()),
+      )
+      .and_where(Expr::col(OrganizationMembers::UserId).eq(user_id.to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query)
+      .execute(transaction.connection())
+      .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  /// Swaps the roles in one statement; the previous owner stays on as an admin. Callers check
+  /// that `user_id` has joined the organization first.
+  async fn transfer_ownership(
+    &self,
+    transaction: &mut TenantTransaction,
+    owner_id: Uuid,
+    user_id: Uuid,
+  ) -> Result<bool, AppError> {
+    let role_type: Alias = Alias::new("ORGANIZATION_ROLE");
+    let query: String = Query::update()
+      .table(OrganizationMembers::Table)
+      .value(
+        OrganizationMembers::Role,
+        Expr::case(
+          Expr::col(OrganizationMembers::UserId).eq(user_id.to_string()),
+          Expr::val(OrganizationRole::Owner.as_str()).cast_as(role_type.clone()),
+        )
+        .finally(Expr::val(OrganizationRole::Admin.as_str()).cast_as(role_type)),
+      )
+      .and_where(
+        Expr::col(OrganizationMembers::OrganizationId)
+          .eq(transaction.organization_id().to_string()),
+      )
+      .and_where(
+        Expr::col(OrganizationMembers::UserId).is_in([owner_id.to_string(), user_id.to_string()]),
+      )
+      .and_where(Expr::col(OrganizationMembers::AcceptedAt).is_not_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query)
+      .execute(transaction.connection())
+      .await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
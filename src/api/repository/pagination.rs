@@ -1,3 +1,11 @@
+use crate::api::repository::sorting::Sortable;
+use crate::core::error::error::AppError;
+use crate::core::request::pagination::Cursor;
+use crate::core::request::sorting::Sorting;
+use sea_query::{Cond, Expr, Order, SimpleExpr};
+use sqlx::FromRow;
+use uuid::Uuid;
+
 #[derive(Debug)]
 pub struct PaginationHelper {
   pub page: usize,
@@ -8,7 +16,7 @@ impl PaginationHelper {
   pub fn new(page: Option<usize>, per_page: Option<usize>) -> Self {
     PaginationHelper {
       page: page.unwrap_or(1).max(1),
-      per_page: per_page.unwrap_or(10).max(0),
+      per_page: per_page.unwrap_or(10),
     }
   }
 
@@ -20,3 +28,75 @@ impl PaginationHelper {
     ((self.page - 1) * self.per_page) as u64
   }
 }
+
+/// One page of rows. Page-numbered reads know the number of rows across all pages; keyset reads
+/// skip that count, and both hand back the cursor of the following page while there is one.
+#[derive(Debug)]
+pub struct Page<T> {
+  pub items: Vec<T>,
+  pub total: Option<u64>,
+  pub next_cursor: Option<Cursor>,
+}
+
+/// Keyset pagination over the fields of a sorting and then its tie breaker, which keeps rows
+/// sharing sort values from being skipped or repeated across pages.
+pub struct CursorHelper;
+
+impl CursorHelper {
+  /// Cursor after a row, from the value of each sorted column.
+  pub fn cursor<T: Sortable>(
+    sorting: &Sorting<T>,
+    id: Uuid,
+    value: impl Fn(&T) -> Option<String>,
+  ) -> Cursor {
+    let values: Vec<Option<String>> = sorting
+      .fields
+      .iter()
+      .map(|field| value(&field.column))
+      .collect();
+    Cursor::new(sorting.key(), values, id)
+  }
+
+  /// Rows strictly after `cursor`, which must come from the same sorting. Postgres sorts NULLs
+  /// last ascending and first descending, and the condition follows that so nullable sort
+  /// columns page like any other.
+  pub fn condition<T: Sortable>(sorting: &Sorting<T>, cursor: &Cursor) -> Result<Cond, AppError> {
+    if cursor.sort != sorting.key() || cursor.values.len() != sorting.fields.len() {
+      return Err(AppError::InvalidCursor);
+    }
+    let id: Expr = Expr::col(T::TIE_BREAKER);
+    let mut after: Cond = Cond::all().add(match sorting.tie_breaker_order() {
+      Order::Desc => id.lt(cursor.id.to_string()),
+      _ => id.gt(cursor.id.to_string()),
+    });
+    for (field, value) in sorting.fields.iter().zip(&cursor.values).rev() {
+      let column: Expr = Expr::col(field.column);
+      let same: SimpleExpr = match value {
+        Some(value) => column.clone().eq(value),
+        None => column.clone().is_null(),
+      };
+      let beyond: Option<SimpleExpr> = match (&field.order, value) {
+        (Order::Desc, Some(value)) => Some(column.lt(value)),
+        (Order::Desc, None) => Some(column.is_not_null()),
+        (_, Some(value)) => Some(column.clone().gt(value).or(column.is_null())),
+        (_, None) => None,
+      };
+      let mut condition: Cond = Cond::any().add(Cond::all().add(same).add(after));
+      if let Some(beyond) = beyond {
+        condition = condition.add(beyond);
+      }
+      after = condition;
+    }
+    Ok(after)
+  }
+}
+
+/// A row selected with `COUNT(*) OVER ()`, so a page and its total come back in one round-trip.
+#[derive(Debug, FromRow)]
+pub struct CountedRow<T> {
+  #[sqlx(flatten)]
+  pub row: T,
+  pub total_count: i64,
+}
+
+pub const TOTAL_COUNT_COLUMN: &str = "total_count";
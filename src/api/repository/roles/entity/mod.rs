@@ -0,0 +1 @@
+pub(crate) mod role_entity;
@@ -0,0 +1,22 @@
+use sea_query::Iden;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum Roles {
+  Table,
+  Id,
+  Name,
+}
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum Permissions {
+  Table,
+  Id,
+  Name,
+}
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum RolePermissions {
+  Table,
+  RoleId,
+  PermissionId,
+}
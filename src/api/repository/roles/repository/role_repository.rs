@@ -0,0 +1,46 @@
+use crate::api::repository::roles::entity::role_entity::{Permissions, RolePermissions, Roles};
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+
+#[async_trait]
+pub trait RoleRepository: Send + Sync + 'static {
+  async fn permissions(&self, role: UserRole) -> Result<Vec<String>, AppError>;
+}
+
+#[async_trait]
+impl RoleRepository for RepositoryImpl {
+  /// Includes the permissions of every role below `role` in the hierarchy.
+  async fn permissions(&self, role: UserRole) -> Result<Vec<String>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let roles: Vec<String> = role
+      .inherited()
+      .iter()
+      .map(|role| role.as_str().to_string())
+      .collect();
+    let query: String = Query::select()
+      .distinct()
+      .column((Permissions::Table, Permissions::Name))
+      .from(Permissions::Table)
+      .inner_join(
+        RolePermissions::Table,
+        Expr::col((RolePermissions::Table, RolePermissions::PermissionId))
+          .equals((Permissions::Table, Permissions::Id)),
+      )
+      .inner_join(
+        Roles::Table,
+        Expr::col((Roles::Table, Roles::Id))
+          .equals((RolePermissions::Table, RolePermissions::RoleId)),
+      )
+      .and_where(Expr::col((Roles::Table, Roles::Name)).is_in(roles))
+      .to_string(PostgresQueryBuilder);
+
+    let permissions: Result<Vec<String>, sqlx::Error> = sqlx::query_scalar::<_, String>(&query)
+      .fetch_all(conn)
+      .await;
+    Ok(permissions?)
+  }
+}
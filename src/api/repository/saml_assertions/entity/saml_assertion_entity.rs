@@ -0,0 +1,9 @@
+use sea_query::Iden;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum SamlAssertions {
+  Table,
+  TenantId,
+  Id,
+  ExpiresAt,
+}
@@ -0,0 +1 @@
+pub(crate) mod saml_assertion_repository;
@@ -0,0 +1,53 @@
+use crate::api::repository::saml_assertions::entity::saml_assertion_entity::SamlAssertions;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{OnConflict, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SamlAssertionRepository: Send + Sync + 'static {
+  async fn consume(
+    &self,
+    tenant_id: Uuid,
+    id: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl SamlAssertionRepository for RepositoryImpl {
+  /// Records the assertion id; returns false when the tenant's IdP already used it.
+  async fn consume(
+    &self,
+    tenant_id: Uuid,
+    id: String,
+    expires_at: DateTime<Utc>,
+  ) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(SamlAssertions::Table)
+      .columns([
+        SamlAssertions::TenantId,
+        SamlAssertions::Id,
+        SamlAssertions::ExpiresAt,
+      ])
+      .values_panic([
+        tenant_id.to_string().into(),
+        id.into(),
+        expires_at.to_rfc3339().into(),
+      ])
+      .on_conflict(
+        OnConflict::columns([SamlAssertions::TenantId, SamlAssertions::Id])
+          .do_nothing()
+          .to_owned(),
+      )
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
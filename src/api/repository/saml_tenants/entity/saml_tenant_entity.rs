@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum SamlTenants {
+  Table,
+  Slug,
+  IdpEntityId,
+  IdpSsoUrl,
+  IdpCertificate,
+  EmailAttribute,
+  NameAttribute,
+  AllowedDomains,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct SamlTenantEntity {
+  pub id: Uuid,
+  pub slug: String,
+  pub idp_entity_id: String,
+  pub idp_sso_url: String,
+  pub idp_certificate: String,
+  pub email_attribute: Option<String>,
+  pub name_attribute: Option<String>,
+  pub allowed_domains: Vec<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl SamlTenantEntity {
+  /// Tenants only vouch for emails under the domains they were configured with.
+  pub fn allows_email(&self, email: &str) -> bool {
+    match email.rsplit_once('@') {
+      Some((_, domain)) => self
+        .allowed_domains
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(domain)),
+      None => false,
+    }
+  }
+}
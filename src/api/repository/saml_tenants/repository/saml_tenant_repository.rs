@@ -0,0 +1,126 @@
+use crate::api::repository::saml_tenants::entity::saml_tenant_entity::{
+  SamlTenantEntity, SamlTenants,
+};
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use sea_query::{Alias, ColumnRef, Expr, PostgresQueryBuilder, Query};
+
+#[async_trait]
+pub trait SamlTenantRepository: Send + Sync + 'static {
+  #[allow(clippy::too_many_arguments)]
+  async fn create(
+    &self,
+    slug: String,
+    idp_entity_id: String,
+    idp_sso_url: String,
+    idp_certificate: String,
+    email_attribute: Option<String>,
+    name_attribute: Option<String>,
+    allowed_domains: Vec<String>,
+  ) -> Result<SamlTenantEntity, AppError>;
+  #[allow(clippy::too_many_arguments)]
+  async fn configure(
+    &self,
+    slug: String,
+    idp_entity_id: String,
+    idp_sso_url: String,
+    idp_certificate: String,
+    email_attribute: Option<String>,
+    name_attribute: Option<String>,
+    allowed_domains: Vec<String>,
+  ) -> Result<Option<SamlTenantEntity>, AppError>;
+  async fn by_slug(&self, slug: String) -> Result<Option<SamlTenantEntity>, AppError>;
+}
+
+#[async_trait]
+impl SamlTenantRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    slug: String,
+    idp_entity_id: String,
+    idp_sso_url: String,
+    idp_certificate: String,
+    email_attribute: Option<String>,
+    name_attribute: Option<String>,
+    allowed_domains: Vec<String>,
+  ) -> Result<SamlTenantEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(SamlTenants::Table)
+      .columns([
+        SamlTenants::Slug,
+        SamlTenants::IdpEntityId,
+        SamlTenants::IdpSsoUrl,
+        SamlTenants::IdpCertificate,
+        SamlTenants::EmailAttribute,
+        SamlTenants::NameAttribute,
+        SamlTenants::AllowedDomains,
+      ])
+      .values_panic([
+        slug.into(),
+        idp_entity_id.into(),
+        idp_sso_url.into(),
+        idp_certificate.into(),
+        email_attribute.into(),
+        name_attribute.into(),
+        Expr::val(allowed_domains).cast_as(Alias::new("TEXT[]")),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let tenant: Result<SamlTenantEntity, sqlx::Error> =
+      sqlx::query_as::<_, SamlTenantEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(tenant?)
+  }
+
+  async fn configure(
+    &self,
+    slug: String,
+    idp_entity_id: String,
+    idp_sso_url: String,
+    idp_certificate: String,
+    email_attribute: Option<String>,
+    name_attribute: Option<String>,
+    allowed_domains: Vec<String>,
+  ) -> Result<Option<SamlTenantEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(SamlTenants::Table)
+      .values([
+        (SamlTenants::IdpEntityId, idp_entity_id.into()),
+        (SamlTenants::IdpSsoUrl, idp_sso_url.into()),
+        (SamlTenants::IdpCertificate, idp_certificate.into()),
+        (SamlTenants::EmailAttribute, email_attribute.into()),
+        (SamlTenants::NameAttribute, name_attribute.into()),
+        (
+          SamlTenants::AllowedDomains,
+          Expr::val(allowed_domains).cast_as(Alias::new("TEXT[]")),
+        ),
+      ])
+      .and_where(Expr::col(SamlTenants::Slug).eq(slug))
+      .returning_all()
+      .to_string(PostgresQueryBuilder);
+    let tenant: Result<Option<SamlTenantEntity>, sqlx::Error> =
+      sqlx::query_as::<_, SamlTenantEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(tenant?)
+  }
+
+  async fn by_slug(&self, slug: String) -> Result<Option<SamlTenantEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(SamlTenants::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(SamlTenants::Slug).eq(slug))
+      .to_string(PostgresQueryBuilder);
+    let tenant: Result<Option<SamlTenantEntity>, sqlx::Error> =
+      sqlx::query_as::<_, SamlTenantEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(tenant?)
+  }
+}
@@ -1,12 +1,32 @@
-use sea_query::Order;
+use crate::core::request::sorting::Sorting;
+use sea_query::{Iden, Order};
+
+/// Columns of a table that clients may sort by, under the names the API exposes.
+pub trait Sortable: Iden + Copy + Send + Sync + 'static {
+  const SORTABLE_FIELDS: &'static [(&'static str, Self)];
+  /// Field sorted by, ascending, when a request asks for no sorting.
+  const DEFAULT_SORT: &'static str;
+  /// Unique column that breaks ties, so the order of rows is stable across pages.
+  const TIE_BREAKER: Self;
+
+  fn sortable_names() -> Vec<&'static str> {
+    Self::SORTABLE_FIELDS
+      .iter()
+      .map(|(field, _)| *field)
+      .collect()
+  }
+}
 
 pub struct SortingHelper;
 
 impl SortingHelper {
-  pub fn map_order_direction(order_direction: &str) -> Order {
-    match order_direction.to_lowercase().as_str() {
-      "desc" => Order::Desc,
-      _ => Order::Asc,
-    }
+  /// Every field of `sorting`, then the tie breaker in the last field's direction.
+  pub fn columns<T: Sortable>(sorting: &Sorting<T>) -> Vec<(T, Order)> {
+    sorting
+      .fields
+      .iter()
+      .map(|field| (field.column, field.order.clone()))
+      .chain([(T::TIE_BREAKER, sorting.tie_breaker_order())])
+      .collect()
   }
 }
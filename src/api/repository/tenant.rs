@@ -0,0 +1,272 @@
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use sea_query::{Alias, Expr, Func, PostgresQueryBuilder, Query};
+use sqlx::{PgConnection, Postgres, Transaction};
+use uuid::Uuid;
+
+const SET_TENANT_ROLE: &str = "SET LOCAL ROLE app_tenant";
+const CURRENT_ORG_SETTING: &str = "app.current_org";
+const CURRENT_USER_SETTING: &str = "app.current_user";
+
+/// Transaction that runs as `app_tenant` with `app.current_org` set, so the row-level security
+/// policies only expose the rows of one organization, even to a query that forgets to filter on
+/// it. Both settings end with the transaction, and nothing is written until it is committed.
+pub struct TenantTransaction {
+  organization_id: Uuid,
+  transaction: Transaction<'static, Postgres>,
+}
+
+impl TenantTransaction {
+  pub fn organization_id(&self) -> Uuid {
+    self.organization_id
+  }
+
+  pub async fn commit(self) -> Result<(), AppError> {
+    Ok(self.transaction.commit().await?)
+  }
+
+  pub(crate) fn connection(&mut self) -> &mut PgConnection {
+    &mut self.transaction
+  }
+}
+
+impl RepositoryImpl {
+  pub(crate) async fn tenant_transaction(
+    conn: &DbPool,
+    organization_id: Uuid,
+  ) -> Result<TenantTransaction, AppError> {
+    let transaction: Transaction<'static, Postgres> =
+      Self::scoped_transaction(conn, CURRENT_ORG_SETTING, organization_id).await?;
+    Ok(TenantTransaction {
+      organization_id,
+      transaction,
+    })
+  }
+
+  /// Opens a transaction that runs as `app_tenant` with `app.current_user` set instead, which
+  /// only reads the user's own memberships and the organizations they belong to.
+  pub(crate) async fn member_transaction(
+    conn: &DbPool,
+    user_id: Uuid,
+  ) -> Result<Transaction<'static, Postgres>, AppError> {
+    Self::scoped_transaction(conn, CURRENT_USER_SETTING, user_id).await
+  }
+
+  async fn scoped_transaction(
+    conn: &DbPool,
+    setting: &str,
+    id: Uuid,
+  ) -> Result<Transaction<'static, Postgres>, AppError> {
+    let mut transaction: Transaction<'static, Postgres> = conn.begin().await?;
+    sqlx::query(SET_TENANT_ROLE)
+      .execute(&mut *transaction)
+      .await?;
+
+    let query: String = Query::select()
+      .expr(Func::cust(Alias::new("set_config")).args([
+        Expr::val(setting).into(),
+        Expr::val(id.to_string()).into(),
+        Expr::val(true).into(),
+      ]))
+      .to_string(PostgresQueryBuilder);
+    sqlx::query(&query).execute(&mut *transaction).await?;
+    Ok(transaction)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+  use crate::api::repository::organizations::entity::organization_entity::{
+    OrganizationEntity, OrganizationMemberEntity, OrganizationMembers, Organizations,
+  };
+  use crate::api::repository::organizations::repository::organization_repository::OrganizationRepository;
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::repository::users::repository::user_repository::UserRepository;
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use faker_rand::en_us::internet::Email;
+  use faker_rand::fr_fr::names::FullName;
+  use sea_query::ColumnRef;
+
+  async fn init_repository() -> RepositoryImpl {
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(APP_CONFIG.postgres.clone())
+      .await
+      .unwrap();
+    RepositoryImpl::new(db_pool)
+  }
+
+  async fn init_organization(repository: &RepositoryImpl) -> (OrganizationEntity, UserEntity) {
+    let user: UserEntity =
+      UserRepository::create(repository, rand::random::<Email>().to_string(), None, None)
+        .await
+        .unwrap();
+    let mut transaction: TenantTransaction = repository.begin(Uuid::new_v4()).await.unwrap();
+    let organization: OrganizationEntity = OrganizationRepository::create(
+      repository,
+      &mut transaction,
+      rand::random::<FullName>().to_string(),
+    )
+    .await
+    .unwrap();
+    repository
+      .add_member(
+        &mut transaction,
+        user.id,
+        OrganizationRole::Owner,
+        None,
+        true,
+      )
+      .await
+      .unwrap();
+    transaction.commit().await.unwrap();
+    (organization, user)
+  }
+
+  #[tokio::test]
+  async fn test_tenant_transaction_hides_other_organizations() {
+    let repository: RepositoryImpl = init_repository().await;
+    let (organization, _) = init_organization(&repository).await;
+    init_organization(&repository).await;
+
+    let mut transaction: TenantTransaction =
+      RepositoryImpl::tenant_transaction(repository.db_pool.primary(), organization.id)
+        .await
+        .unwrap();
+
+    let query: String = Query::select()
+      .from(OrganizationMembers::Table)
+      .column(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let members: Vec<OrganizationMemberEntity> =
+      sqlx::query_as::<_, OrganizationMemberEntity>(&query)
+        .fetch_all(transaction.connection())
+        .await
+        .unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].organization_id, organization.id);
+
+    let query: String = Query::select()
+      .from(Organizations::Table)
+      .column(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let organizations: Vec<OrganizationEntity> = sqlx::query_as::<_, OrganizationEntity>(&query)
+      .fetch_all(transaction.connection())
+      .await
+      .unwrap();
+    assert_eq!(organizations.len(), 1);
+    assert_eq!(organizations[0].id, organization.id);
+  }
+
+  #[tokio::test]
+  async fn test_tenant_transaction_rejects_other_organizations_rows() {
+    let repository: RepositoryImpl = init_repository().await;
+    let (organization, owner) = init_organization(&repository).await;
+    let (other, other_owner) = init_organization(&repository).await;
+
+    let mut transaction: TenantTransaction =
+      RepositoryImpl::tenant_transaction(repository.db_pool.primary(), organization.id)
+        .await
+        .unwrap();
+
+    let query: String = Query::delete()
+      .from_table(OrganizationMembers::Table)
+      .and_where(Expr::col(OrganizationMembers::UserId).eq(other_owner.id.to_string()))
+      .to_string(PostgresQueryBuilder);
+    let deleted: u64 = sqlx::query(&query)
+      .execute(transaction.connection())
+      .await
+      .unwrap()
+      .rows_affected();
+    assert_eq!(deleted, 0);
+
+    let query: String = Query::insert()
+      .into_table(OrganizationMembers::Table)
+      .columns([
+        OrganizationMembers::OrganizationId,
+        OrganizationMembers::UserId,
+        OrganizationMembers::Role,
+      ])
+      .values_panic([
+        other.id.to_string().into(),
+        owner.id.to_string().into(),
+        OrganizationRole::Member.into(),
+      ])
+      .to_string(PostgresQueryBuilder);
+    assert!(sqlx::query(&query)
+      .execute(transaction.connection())
+      .await
+      .is_err());
+  }
+
+  #[tokio::test]
+  async fn test_tenant_transaction_rolls_back_on_drop() {
+    let repository: RepositoryImpl = init_repository().await;
+    let organization_id: Uuid = Uuid::new_v4();
+
+    let mut transaction: TenantTransaction = repository.begin(organization_id).await.unwrap();
+    OrganizationRepository::create(
+      &repository,
+      &mut transaction,
+      rand::random::<FullName>().to_string(),
+    )
+    .await
+    .unwrap();
+    assert!(repository
+      .add_member(
+        &mut transaction,
+        Uuid::new_v4(),
+        OrganizationRole::Owner,
+        None,
+        true,
+      )
+      .await
+      .is_err());
+    drop(transaction);
+
+    let mut transaction: TenantTransaction = repository.begin(organization_id).await.unwrap();
+    assert!(OrganizationRepository::by_id(&repository, &mut transaction)
+      .await
+      .unwrap()
+      .is_none());
+  }
+
+  #[tokio::test]
+  async fn test_member_transaction_reads_own_organizations() {
+    let repository: RepositoryImpl = init_repository().await;
+    let (organization, owner) = init_organization(&repository).await;
+    let (other, _) = init_organization(&repository).await;
+
+    let mut transaction: Transaction<'static, Postgres> =
+      RepositoryImpl::member_transaction(repository.db_pool.primary(), owner.id)
+        .await
+        .unwrap();
+
+    let query: String = Query::select()
+      .from(Organizations::Table)
+      .column(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let organizations: Vec<OrganizationEntity> = sqlx::query_as::<_, OrganizationEntity>(&query)
+      .fetch_all(&mut *transaction)
+      .await
+      .unwrap();
+    assert_eq!(organizations.len(), 1);
+    assert_eq!(organizations[0].id, organization.id);
+
+    let query: String = Query::update()
+      .table(Organizations::Table)
+      .value(Organizations::Name, rand::random::<FullName>().to_string())
+      .and_where(
+        Expr::col(Organizations::Id).is_in([organization.id.to_string(), other.id.to_string()]),
+      )
+      .to_string(PostgresQueryBuilder);
+    let updated: u64 = sqlx::query(&query)
+      .execute(&mut *transaction)
+      .await
+      .unwrap()
+      .rows_affected();
+    assert_eq!(updated, 0);
+  }
+}
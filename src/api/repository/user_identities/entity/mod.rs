@@ -0,0 +1 @@
+pub(crate) mod user_identity_entity;
@@ -0,0 +1,26 @@
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum UserIdentities {
+  Table,
+  UserId,
+  Provider,
+  Subject,
+  Email,
+  CreatedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct UserIdentityEntity {
+  pub id: Uuid,
+  pub user_id: Uuid,
+  pub provider: OAuth2Provider,
+  pub subject: String,
+  pub email: Option<String>,
+  pub created_at: Option<DateTime<Utc>>,
+}
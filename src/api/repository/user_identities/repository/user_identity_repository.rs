@@ -0,0 +1,109 @@
+use crate::api::repository::user_identities::entity::user_identity_entity::{
+  UserIdentities, UserIdentityEntity,
+};
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use async_trait::async_trait;
+use sea_query::{ColumnRef, Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UserIdentityRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    user_id: Uuid,
+    provider: OAuth2Provider,
+    subject: String,
+    email: Option<String>,
+  ) -> Result<UserIdentityEntity, AppError>;
+  async fn by_subject(
+    &self,
+    provider: OAuth2Provider,
+    subject: String,
+  ) -> Result<Option<UserIdentityEntity>, AppError>;
+  async fn by_user_id(&self, user_id: Uuid) -> Result<Vec<UserIdentityEntity>, AppError>;
+  async fn delete(&self, user_id: Uuid, provider: OAuth2Provider) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+impl UserIdentityRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    user_id: Uuid,
+    provider: OAuth2Provider,
+    subject: String,
+    email: Option<String>,
+  ) -> Result<UserIdentityEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(UserIdentities::Table)
+      .columns([
+        UserIdentities::UserId,
+        UserIdentities::Provider,
+        UserIdentities::Subject,
+        UserIdentities::Email,
+      ])
+      .values_panic([
+        user_id.to_string().into(),
+        provider.as_str().into(),
+        subject.into(),
+        email.into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let identity: Result<UserIdentityEntity, sqlx::Error> =
+      sqlx::query_as::<_, UserIdentityEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(identity?)
+  }
+
+  async fn by_subject(
+    &self,
+    provider: OAuth2Provider,
+    subject: String,
+  ) -> Result<Option<UserIdentityEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(UserIdentities::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(UserIdentities::Provider).eq(provider.as_str()))
+      .and_where(Expr::col(UserIdentities::Subject).eq(subject))
+      .to_string(PostgresQueryBuilder);
+    let identity: Result<Option<UserIdentityEntity>, sqlx::Error> =
+      sqlx::query_as::<_, UserIdentityEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(identity?)
+  }
+
+  async fn by_user_id(&self, user_id: Uuid) -> Result<Vec<UserIdentityEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(UserIdentities::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(UserIdentities::UserId).eq(user_id.to_string()))
+      .order_by(UserIdentities::CreatedAt, Order::Asc)
+      .to_string(PostgresQueryBuilder);
+    let identities: Result<Vec<UserIdentityEntity>, sqlx::Error> =
+      sqlx::query_as::<_, UserIdentityEntity>(&query)
+        .fetch_all(conn)
+        .await;
+    Ok(identities?)
+  }
+
+  async fn delete(&self, user_id: Uuid, provider: OAuth2Provider) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::delete()
+      .from_table(UserIdentities::Table)
+      .and_where(Expr::col(UserIdentities::UserId).eq(user_id.to_string()))
+      .and_where(Expr::col(UserIdentities::Provider).eq(provider.as_str()))
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+}
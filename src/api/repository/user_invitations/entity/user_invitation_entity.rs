@@ -0,0 +1,31 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Eq, Hash, Iden, PartialEq)]
+pub enum UserInvitations {
+  Table,
+  Id,
+  Email,
+  Role,
+  InvitedBy,
+  ExpiresAt,
+  AcceptedAt,
+  RevokedAt,
+  CreatedAt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+pub struct UserInvitationEntity {
+  pub id: Uuid,
+  pub email: String,
+  pub role: UserRole,
+  pub invited_by: Option<Uuid>,
+  pub expires_at: DateTime<Utc>,
+  pub accepted_at: Option<DateTime<Utc>>,
+  pub revoked_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+}
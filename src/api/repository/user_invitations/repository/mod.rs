@@ -0,0 +1 @@
+pub(crate) mod user_invitation_repository;
@@ -0,0 +1,188 @@
+use crate::api::repository::user_invitations::entity::user_invitation_entity::{
+  UserInvitationEntity, UserInvitations,
+};
+use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
+use crate::api::repository::users::entity::user_entity::UserEntity;
+use crate::api::repository::RepositoryImpl;
+use crate::core::error::error::AppError;
+use crate::db::db::DbPool;
+use crate::utils::datetime_utils::TimeUtils;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{ColumnRef, Cond, Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::postgres::PgQueryResult;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UserInvitationRepository: Send + Sync + 'static {
+  async fn create(
+    &self,
+    email: String,
+    role: UserRole,
+    invited_by: Uuid,
+    expires_at: DateTime<Utc>,
+  ) -> Result<UserInvitationEntity, AppError>;
+  async fn by_id(&self, id: Uuid) -> Result<Option<UserInvitationEntity>, AppError>;
+  async fn pending(&self) -> Result<Vec<UserInvitationEntity>, AppError>;
+  async fn pending_by_email(&self, email: String)
+    -> Result<Option<UserInvitationEntity>, AppError>;
+  async fn revoke(&self, id: Uuid) -> Result<bool, AppError>;
+  /// Marks the pending invitation accepted and creates its verified user with the invited
+  /// role, in one transaction; `None` when the invitation is no longer pending.
+  async fn accept(
+    &self,
+    invitation: &UserInvitationEntity,
+    password: String,
+    name: Option<String>,
+  ) -> Result<Option<UserEntity>, AppError>;
+}
+
+#[async_trait]
+impl UserInvitationRepository for RepositoryImpl {
+  async fn create(
+    &self,
+    email: String,
+    role: UserRole,
+    invited_by: Uuid,
+    expires_at: DateTime<Utc>,
+  ) -> Result<UserInvitationEntity, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::insert()
+      .into_table(UserInvitations::Table)
+      .columns([
+        UserInvitations::Email,
+        UserInvitations::Role,
+        UserInvitations::InvitedBy,
+        UserInvitations::ExpiresAt,
+      ])
+      .values_panic([
+        email.into(),
+        role.into(),
+        invited_by.to_string().into(),
+        expires_at.to_rfc3339().into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder);
+    let invitation: Result<UserInvitationEntity, sqlx::Error> =
+      sqlx::query_as::<_, UserInvitationEntity>(&query)
+        .fetch_one(conn)
+        .await;
+    Ok(invitation?)
+  }
+
+  async fn by_id(&self, id: Uuid) -> Result<Option<UserInvitationEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(UserInvitations::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(UserInvitations::Id).eq(id.to_string()))
+      .to_string(PostgresQueryBuilder);
+
+    let invitation: Result<Option<UserInvitationEntity>, sqlx::Error> =
+      sqlx::query_as::<_, UserInvitationEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(invitation?)
+  }
+
+  async fn pending(&self) -> Result<Vec<UserInvitationEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(UserInvitations::Table)
+      .column(ColumnRef::Asterisk)
+      .cond_where(Self::pending_invitation_condition())
+      .order_by(UserInvitations::CreatedAt, Order::Desc)
+      .to_string(PostgresQueryBuilder);
+
+    let invitations: Result<Vec<UserInvitationEntity>, sqlx::Error> =
+      sqlx::query_as::<_, UserInvitationEntity>(&query)
+        .fetch_all(conn)
+        .await;
+    Ok(invitations?)
+  }
+
+  async fn pending_by_email(
+    &self,
+    email: String,
+  ) -> Result<Option<UserInvitationEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(UserInvitations::Table)
+      .column(ColumnRef::Asterisk)
+      .cond_where(Self::pending_invitation_condition())
+      .and_where(Expr::col(UserInvitations::Email).eq(email))
+      .to_string(PostgresQueryBuilder);
+
+    let invitation: Result<Option<UserInvitationEntity>, sqlx::Error> =
+      sqlx::query_as::<_, UserInvitationEntity>(&query)
+        .fetch_optional(conn)
+        .await;
+    Ok(invitation?)
+  }
+
+  /// Revokes a pending invitation; returns false when there is none with this id.
+  async fn revoke(&self, id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(UserInvitations::Table)
+      .value(
+        UserInvitations::RevokedAt,
+        TimeUtils::utc_now().to_rfc3339(),
+      )
+      .and_where(Expr::col(UserInvitations::Id).eq(id.to_string()))
+      .and_where(Expr::col(UserInvitations::AcceptedAt).is_null())
+      .and_where(Expr::col(UserInvitations::RevokedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  /// Marks the invitation as accepted; returns false when it is no longer pending.
+  async fn accept(
+    &self,
+    invitation: &UserInvitationEntity,
+    password: String,
+    name: Option<String>,
+  ) -> Result<Option<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(UserInvitations::Table)
+      .value(
+        UserInvitations::AcceptedAt,
+        TimeUtils::utc_now().to_rfc3339(),
+      )
+      .and_where(Expr::col(UserInvitations::Id).eq(invitation.id.to_string()))
+      .cond_where(Self::pending_invitation_condition())
+      .to_string(PostgresQueryBuilder);
+
+    let mut transaction: Transaction<'static, Postgres> = conn.begin().await?;
+    let result: PgQueryResult = sqlx::query(&query).execute(&mut *transaction).await?;
+    if result.rows_affected() == 0 {
+      return Ok(None);
+    }
+    let query: String = Self::create_user_query(
+      invitation.email.clone(),
+      Some(password),
+      name,
+      invitation.role,
+      UserStatus::Verified,
+    );
+    let user: UserEntity = sqlx::query_as::<_, UserEntity>(&query)
+      .fetch_one(&mut *transaction)
+      .await?;
+    transaction.commit().await?;
+    Ok(Some(user))
+  }
+}
+
+impl RepositoryImpl {
+  /// Invitations that were neither accepted nor revoked and have not expired.
+  fn pending_invitation_condition() -> Cond {
+    Cond::all()
+      .add(Expr::col(UserInvitations::AcceptedAt).is_null())
+      .add(Expr::col(UserInvitations::RevokedAt).is_null())
+      .add(Expr::col(UserInvitations::ExpiresAt).gt(TimeUtils::utc_now().to_rfc3339()))
+  }
+}
@@ -1,42 +1,76 @@
+use crate::core::error::error::AppError;
 use sea_query::Value;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
+use std::str::FromStr;
 
-#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Copy)]
+#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Eq, Hash, Copy)]
 #[sqlx(type_name = "user_role", rename_all = "snake_case")]
 pub enum UserRole {
   #[serde(rename = "user")]
   User,
+  #[serde(rename = "moderator")]
+  Moderator,
   #[serde(rename = "admin")]
   Admin,
 }
 
 impl UserRole {
+  /// Roles from the least to the most privileged.
+  pub const HIERARCHY: [UserRole; 3] = [UserRole::User, UserRole::Moderator, UserRole::Admin];
+
   pub fn as_str(&self) -> &str {
     match self {
       UserRole::User => "user",
+      UserRole::Moderator => "moderator",
       UserRole::Admin => "admin",
     }
   }
+
+  fn level(&self) -> usize {
+    match self {
+      UserRole::User => 0,
+      UserRole::Moderator => 1,
+      UserRole::Admin => 2,
+    }
+  }
+
+  /// A role has every privilege of the roles below it.
+  pub fn includes(&self, role: UserRole) -> bool {
+    self.level() >= role.level()
+  }
+
+  /// This role and every role it inherits from.
+  pub fn inherited(&self) -> Vec<UserRole> {
+    Self::HIERARCHY
+      .into_iter()
+      .filter(|role| self.includes(*role))
+      .collect()
+  }
 }
 
 impl From<UserRole> for Value {
   fn from(role: UserRole) -> Self {
     let value: &str = match role {
       UserRole::User => "user",
+      UserRole::Moderator => "moderator",
       UserRole::Admin => "admin",
     };
     Value::from(value.to_string())
   }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Type)]
+#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Eq, Copy)]
 #[sqlx(type_name = "user_status", rename_all = "snake_case")]
 pub enum UserStatus {
   #[serde(rename = "registered")]
   Registered,
   #[serde(rename = "verified")]
   Verified,
+  #[serde(rename = "suspended")]
+  Suspended,
+  #[serde(rename = "banned")]
+  Banned,
 }
 
 impl UserStatus {
@@ -44,6 +78,8 @@ impl UserStatus {
     match self {
       UserStatus::Registered => "registered",
       UserStatus::Verified => "verified",
+      UserStatus::Suspended => "suspended",
+      UserStatus::Banned => "banned",
     }
   }
 }
@@ -53,31 +89,61 @@ impl From<UserStatus> for Value {
     let value: &str = match status {
       UserStatus::Registered => "registered",
       UserStatus::Verified => "verified",
+      UserStatus::Suspended => "suspended",
+      UserStatus::Banned => "banned",
     };
     Value::from(value.to_string())
   }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Copy)]
+#[derive(Serialize, Deserialize, Clone, Debug, Type, PartialEq, Eq, Hash, Copy)]
 #[sqlx(type_name = "oauth2_provider", rename_all = "snake_case")]
 pub enum OAuth2Provider {
   #[serde(rename = "google")]
   Google,
+  #[serde(rename = "github")]
+  Github,
+  #[serde(rename = "microsoft")]
+  Microsoft,
+  #[serde(rename = "oidc")]
+  Oidc,
+  #[serde(rename = "saml")]
+  Saml,
+  #[serde(rename = "ldap")]
+  Ldap,
 }
 
 impl OAuth2Provider {
   pub fn as_str(&self) -> &str {
     match self {
       OAuth2Provider::Google => "google",
+      OAuth2Provider::Github => "github",
+      OAuth2Provider::Microsoft => "microsoft",
+      OAuth2Provider::Oidc => "oidc",
+      OAuth2Provider::Saml => "saml",
+      OAuth2Provider::Ldap => "ldap",
+    }
+  }
+}
+
+impl FromStr for OAuth2Provider {
+  type Err = AppError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "google" => Ok(OAuth2Provider::Google),
+      "github" => Ok(OAuth2Provider::Github),
+      "microsoft" => Ok(OAuth2Provider::Microsoft),
+      "oidc" => Ok(OAuth2Provider::Oidc),
+      "saml" => Ok(OAuth2Provider::Saml),
+      "ldap" => Ok(OAuth2Provider::Ldap),
+      _ => Err(AppError::InvalidOauth2Provider),
     }
   }
 }
 
 impl From<OAuth2Provider> for Value {
   fn from(provider: OAuth2Provider) -> Self {
-    let value: &str = match provider {
-      OAuth2Provider::Google => "google",
-    };
-    Value::from(value.to_string())
+    Value::from(provider.as_str().to_string())
   }
 }
@@ -1,13 +1,16 @@
+use crate::api::repository::sorting::Sortable;
 use crate::api::repository::users::constant::user_constant::{
   OAuth2Provider, UserRole, UserStatus,
 };
+use crate::core::error::error::AppError;
+use crate::utils::datetime_utils::TimeUtils;
 use chrono::{DateTime, Utc};
 use sea_query::Iden;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-#[derive(Eq, Hash, Iden, PartialEq)]
+#[derive(Clone, Copy, Eq, Hash, Iden, PartialEq)]
 pub enum Users {
   Table,
   Id,
@@ -16,8 +19,8 @@ pub enum Users {
   Name,
   Role,
   Status,
-  AuthId,
-  AuthProvider,
+  StatusReason,
+  StatusUntil,
   ProfileUrl,
   LoggedInAt,
   CreatedAt,
@@ -25,14 +28,29 @@ pub enum Users {
   DeletedAt,
 }
 
-impl Users {
-  pub fn map_order_by(order_by: &str) -> Option<Users> {
-    match order_by.to_lowercase().as_str() {
-      "name" => Option::from(Users::Name),
-      "updated_at" => Option::from(Users::UpdatedAt),
-      _ => Option::from(Users::CreatedAt),
-    }
-  }
+impl Sortable for Users {
+  const SORTABLE_FIELDS: &'static [(&'static str, Self)] = &[
+    ("name", Users::Name),
+    ("created_at", Users::CreatedAt),
+    ("updated_at", Users::UpdatedAt),
+  ];
+  const DEFAULT_SORT: &'static str = "created_at";
+  const TIE_BREAKER: Self = Users::Id;
+}
+
+/// Filters of the admin user list. Each one narrows the result; `q` matches part of the email
+/// or name, and the date ranges are inclusive.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct UserFilter {
+  pub q: Option<String>,
+  pub role: Option<UserRole>,
+  pub status: Option<UserStatus>,
+  pub auth_provider: Option<OAuth2Provider>,
+  pub created_from: Option<DateTime<Utc>>,
+  pub created_to: Option<DateTime<Utc>>,
+  pub logged_in_from: Option<DateTime<Utc>>,
+  pub logged_in_to: Option<DateTime<Utc>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
@@ -44,10 +62,37 @@ pub struct UserEntity {
   pub profile_url: Option<String>,
   pub role: UserRole,
   pub status: UserStatus,
-  pub auth_id: Option<String>,
-  pub auth_provider: Option<OAuth2Provider>,
+  pub status_reason: Option<String>,
+  pub status_until: Option<DateTime<Utc>>,
   pub logged_in_at: Option<DateTime<Utc>>,
   pub created_at: Option<DateTime<Utc>>,
   pub updated_at: Option<DateTime<Utc>>,
   pub deleted_at: Option<DateTime<Utc>>,
 }
+
+impl UserEntity {
+  /// Banned accounts stay locked; a suspension without `status_until` lasts until an admin
+  /// lifts it, otherwise it ends on its own at that time.
+  pub fn ensure_active(&self) -> Result<(), AppError> {
+    match self.status {
+      UserStatus::Banned => Err(AppError::UserBanned),
+      UserStatus::Suspended
+        if self
+          .status_until
+          .is_none_or(|until| until > TimeUtils::utc_now()) =>
+      {
+        Err(AppError::UserSuspended)
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Value of one of the sortable columns, as a cursor carries it.
+  pub fn sort_value(&self, column: &Users) -> Option<String> {
+    match column {
+      Users::Name => self.name.clone(),
+      Users::UpdatedAt => self.updated_at.map(|at| at.to_rfc3339()),
+      _ => self.created_at.map(|at| at.to_rfc3339()),
+    }
+  }
+}
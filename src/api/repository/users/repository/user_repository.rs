@@ -1,18 +1,27 @@
+use crate::api::repository::api_keys::entity::api_key_entity::ApiKeys;
 use crate::api::repository::field_value::FieldValue;
-use crate::api::repository::pagination::PaginationHelper;
-use crate::api::repository::sorting::SortingHelper;
-use crate::api::repository::users::constant::user_constant::{
-  OAuth2Provider, UserRole, UserStatus,
+use crate::api::repository::pagination::{
+  CountedRow, CursorHelper, Page, PaginationHelper, TOTAL_COUNT_COLUMN,
 };
-use crate::api::repository::users::entity::user_entity::{UserEntity, Users};
+use crate::api::repository::refresh_tokens::entity::refresh_tokens_entity::RefreshTokens;
+use crate::api::repository::sorting::SortingHelper;
+use crate::api::repository::user_identities::entity::user_identity_entity::UserIdentities;
+use crate::api::repository::users::constant::user_constant::{UserRole, UserStatus};
+use crate::api::repository::users::entity::user_entity::{UserEntity, UserFilter, Users};
 use crate::api::repository::RepositoryImpl;
 use crate::core::error::error::AppError;
-use crate::core::request::pagination::Pagination;
+use crate::core::request::pagination::{Cursor, Pagination};
 use crate::core::request::sorting::Sorting;
 use crate::db::db::DbPool;
 use crate::utils::datetime_utils::TimeUtils;
 use async_trait::async_trait;
-use sea_query::{ColumnRef, Expr, Order, PostgresQueryBuilder, Query, SimpleExpr};
+use chrono::{DateTime, Utc};
+use sea_query::extension::postgres::PgExpr;
+use sea_query::{
+  Alias, ColumnRef, Cond, Expr, Func, Iden, Order, PostgresQueryBuilder, Query, SimpleExpr,
+};
+use sqlx::postgres::PgQueryResult;
+use sqlx::{Postgres, Transaction};
 use uuid::Uuid;
 
 #[async_trait]
@@ -22,26 +31,50 @@ pub trait UserRepository: Send + Sync + 'static {
     email: String,
     password: Option<String>,
     name: Option<String>,
-    auth_id: Option<String>,
-    auth_provider: Option<OAuth2Provider>,
   ) -> Result<UserEntity, AppError>;
   async fn update(&self, id: Uuid, fields: Vec<(Users, FieldValue)>) -> Result<(), AppError>;
   async fn update_logged_in_at(&self, id: Uuid) -> Result<(), AppError>;
   async fn by_email(&self, email: String) -> Result<Option<UserEntity>, AppError>;
-  async fn by_auth_provider(
-    &self,
-    auth_id: String,
-    auth_provider: OAuth2Provider,
-  ) -> Result<Option<UserEntity>, AppError>;
+  /// One page of the users matching `filter`: by page number with the number of matches across
+  /// all pages, or by keyset after a cursor.
   async fn all(
     &self,
     pagination: Pagination,
-    sorting: Sorting,
-  ) -> Result<Vec<UserEntity>, AppError>;
+    sorting: Sorting<Users>,
+    filter: UserFilter,
+  ) -> Result<Page<UserEntity>, AppError>;
+  async fn by_id(&self, id: Uuid) -> Result<Option<UserEntity>, AppError>;
+  /// Like [`UserRepository::by_id`], but also finds soft-deleted users.
+  async fn by_id_with_deleted(&self, id: Uuid) -> Result<Option<UserEntity>, AppError>;
+  /// Whether any user, soft-deleted or not, has this email.
+  async fn email_exists(&self, email: String) -> Result<bool, AppError>;
   async fn me(&self, id: Uuid) -> Result<UserEntity, AppError>;
   async fn update_profile_url(&self, id: Uuid, profile_url: String) -> Result<(), AppError>;
   async fn update_role(&self, id: Uuid, role: UserRole) -> Result<(), AppError>;
   async fn update_status(&self, id: Uuid, status: UserStatus) -> Result<(), AppError>;
+  /// Sets the status together with its reason and end, clearing whichever are `None`.
+  async fn update_restriction(
+    &self,
+    id: Uuid,
+    status: UserStatus,
+    reason: Option<String>,
+    until: Option<DateTime<Utc>>,
+  ) -> Result<(), AppError>;
+  async fn update_email(&self, id: Uuid, email: String) -> Result<(), AppError>;
+  async fn update_name(&self, id: Uuid, name: Option<String>) -> Result<(), AppError>;
+  async fn update_password(&self, id: Uuid, password: String) -> Result<(), AppError>;
+  async fn search(
+    &self,
+    email: Option<String>,
+    offset: u64,
+    limit: u64,
+  ) -> Result<Vec<UserEntity>, AppError>;
+  async fn count(&self, email: Option<String>) -> Result<i64, AppError>;
+  /// Soft-deletes the user; returns false when it was already deleted.
+  async fn delete(&self, id: Uuid) -> Result<bool, AppError>;
+  /// Clears `deleted_at`, keeping the account as it was; returns false when it wasn't deleted.
+  async fn reactivate(&self, id: Uuid) -> Result<bool, AppError>;
+  async fn restore(&self, email: String) -> Result<Option<UserEntity>, AppError>;
 }
 
 #[async_trait]
@@ -51,44 +84,16 @@ impl UserRepository for RepositoryImpl {
     email: String,
     password: Option<String>,
     name: Option<String>,
-    auth_id: Option<String>,
-    auth_provider: Option<OAuth2Provider>,
   ) -> Result<UserEntity, AppError> {
     let conn: &DbPool = &self.db_pool.primary();
 
-    let query: String = Query::insert()
-      .into_table(Users::Table)
-      .columns([
-        Users::Email,
-        Users::Password,
-        Users::Name,
-        Users::Role,
-        Users::Status,
-        Users::AuthId,
-        Users::AuthProvider,
-      ])
-      .values_panic([
-        email.into(),
-        password
-          .as_deref()
-          .map(Expr::value)
-          .unwrap_or(Expr::value(None::<&str>)),
-        name
-          .as_deref()
-          .map(Expr::value)
-          .unwrap_or(Expr::value(None::<&str>)),
-        UserRole::User.into(),
-        UserStatus::Registered.into(),
-        auth_id
-          .as_deref()
-          .map(Expr::value)
-          .unwrap_or(Expr::value(None::<&str>)),
-        auth_provider
-          .map(|provider| Expr::value(provider.as_str()))
-          .unwrap_or(Expr::value(None::<&str>)),
-      ])
-      .returning_col(ColumnRef::Asterisk)
-      .to_string(PostgresQueryBuilder);
+    let query: String = Self::create_user_query(
+      email,
+      password,
+      name,
+      UserRole::User,
+      UserStatus::Registered,
+    );
     let user: Result<UserEntity, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
       .fetch_one(conn)
       .await;
@@ -132,7 +137,6 @@ impl UserRepository for RepositoryImpl {
       .column(ColumnRef::Asterisk)
       .and_where(Expr::col(Users::Email).eq(email))
       .and_where(Expr::col(Users::DeletedAt).is_null())
-      .and_where(Expr::col(Users::AuthId).is_null())
       .to_string(PostgresQueryBuilder);
 
     let user: Result<Option<UserEntity>, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
@@ -141,17 +145,75 @@ impl UserRepository for RepositoryImpl {
     Ok(user?)
   }
 
-  async fn by_auth_provider(
+  async fn all(
     &self,
-    auth_id: String,
-    auth_provider: OAuth2Provider,
-  ) -> Result<Option<UserEntity>, AppError> {
+    pagination: Pagination,
+    sorting: Sorting<Users>,
+    filter: UserFilter,
+  ) -> Result<Page<UserEntity>, AppError> {
     let conn: &DbPool = &self.db_pool.replica();
+
+    let pagination_helper: PaginationHelper =
+      PaginationHelper::new(pagination.page, pagination.per_page);
+
+    if let Some(cursor) = pagination.cursor {
+      return self
+        .all_after(cursor, pagination_helper.per_page, sorting, filter)
+        .await;
+    }
+
     let query: String = Query::select()
       .from(Users::Table)
       .column(ColumnRef::Asterisk)
-      .and_where(Expr::col(Users::AuthId).eq(auth_id))
-      .and_where(Expr::col(Users::AuthProvider).eq(auth_provider.as_str()))
+      .expr_as(
+        Expr::cust("COUNT(*) OVER ()"),
+        Alias::new(TOTAL_COUNT_COLUMN),
+      )
+      .cond_where(Self::filter_condition(filter.clone()))
+      .limit(pagination_helper.limit())
+      .offset(pagination_helper.offset())
+      .order_by_columns(SortingHelper::columns(&sorting))
+      .to_string(PostgresQueryBuilder);
+
+    let rows: Result<Vec<CountedRow<UserEntity>>, sqlx::Error> =
+      sqlx::query_as::<_, CountedRow<UserEntity>>(&query)
+        .fetch_all(conn)
+        .await;
+    let rows: Vec<CountedRow<UserEntity>> = rows?;
+
+    // A page past the end has no row to carry the window count, so count separately.
+    let total: u64 = match rows.first() {
+      Some(row) => row.total_count as u64,
+      None if pagination_helper.offset() == 0 => 0,
+      None => {
+        let query: String = Query::select()
+          .from(Users::Table)
+          .expr(Func::count(Expr::col(Users::Id)))
+          .cond_where(Self::filter_condition(filter))
+          .to_string(PostgresQueryBuilder);
+        let count: Result<i64, sqlx::Error> =
+          sqlx::query_scalar::<_, i64>(&query).fetch_one(conn).await;
+        count? as u64
+      }
+    };
+    let items: Vec<UserEntity> = rows.into_iter().map(|row| row.row).collect();
+    let next_cursor: Option<Cursor> = match pagination_helper.offset() + (items.len() as u64) {
+      read if read < total => items.last().map(|user| Self::user_cursor(user, &sorting)),
+      _ => None,
+    };
+    Ok(Page {
+      items,
+      total: Some(total),
+      next_cursor,
+    })
+  }
+
+  async fn by_id(&self, id: Uuid) -> Result<Option<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(Users::Table)
+      .column(ColumnRef::Asterisk)
+      .and_where(Expr::col(Users::Id).eq(id.to_string()))
       .and_where(Expr::col(Users::DeletedAt).is_null())
       .to_string(PostgresQueryBuilder);
 
@@ -161,33 +223,35 @@ impl UserRepository for RepositoryImpl {
     Ok(user?)
   }
 
-  async fn all(
-    &self,
-    pagination: Pagination,
-    sorting: Sorting,
-  ) -> Result<Vec<UserEntity>, AppError> {
-    let conn: &DbPool = &self.db_pool.replica();
-
-    let pagination_helper: PaginationHelper =
-      PaginationHelper::new(pagination.page, pagination.per_page);
-
-    let order_by: Users = Users::map_order_by(&sorting.order_by.unwrap()).unwrap();
-    let order_direction: Order =
-      SortingHelper::map_order_direction(&sorting.order_direction.unwrap());
-
+  async fn by_id_with_deleted(&self, id: Uuid) -> Result<Option<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
     let query: String = Query::select()
       .from(Users::Table)
       .column(ColumnRef::Asterisk)
-      .and_where(Expr::col(Users::DeletedAt).is_null())
-      .limit(pagination_helper.limit())
-      .offset(pagination_helper.offset())
-      .order_by(order_by, order_direction)
+      .and_where(Expr::col(Users::Id).eq(id.to_string()))
       .to_string(PostgresQueryBuilder);
 
-    let users: Result<Vec<UserEntity>, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
-      .fetch_all(conn)
+    let user: Result<Option<UserEntity>, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
+      .fetch_optional(conn)
       .await;
-    Ok(users?)
+    Ok(user?)
+  }
+
+  async fn email_exists(&self, email: String) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::select()
+      .expr(Expr::exists(
+        Query::select()
+          .expr(Expr::val(1))
+          .from(Users::Table)
+          .and_where(Expr::col(Users::Email).eq(email))
+          .to_owned(),
+      ))
+      .to_string(PostgresQueryBuilder);
+
+    let exists: Result<bool, sqlx::Error> =
+      sqlx::query_scalar::<_, bool>(&query).fetch_one(conn).await;
+    Ok(exists?)
   }
 
   async fn me(&self, id: Uuid) -> Result<UserEntity, AppError> {
@@ -228,4 +292,298 @@ impl UserRepository for RepositoryImpl {
       )
       .await
   }
+
+  async fn update_restriction(
+    &self,
+    id: Uuid,
+    status: UserStatus,
+    reason: Option<String>,
+    until: Option<DateTime<Utc>>,
+  ) -> Result<(), AppError> {
+    self
+      .update(
+        id,
+        vec![
+          (Users::Status, FieldValue::Enum(status.as_str().to_string())),
+          (
+            Users::StatusReason,
+            reason.map_or(FieldValue::Null, FieldValue::Text),
+          ),
+          (
+            Users::StatusUntil,
+            until.map_or(FieldValue::Null, FieldValue::DateTime),
+          ),
+        ],
+      )
+      .await
+  }
+
+  async fn update_email(&self, id: Uuid, email: String) -> Result<(), AppError> {
+    self
+      .update(id, vec![(Users::Email, FieldValue::Text(email))])
+      .await
+  }
+
+  async fn update_name(&self, id: Uuid, name: Option<String>) -> Result<(), AppError> {
+    self
+      .update(
+        id,
+        vec![(Users::Name, name.map_or(FieldValue::Null, FieldValue::Text))],
+      )
+      .await
+  }
+
+  async fn update_password(&self, id: Uuid, password: String) -> Result<(), AppError> {
+    self
+      .update(id, vec![(Users::Password, FieldValue::Text(password))])
+      .await
+  }
+
+  async fn search(
+    &self,
+    email: Option<String>,
+    offset: u64,
+    limit: u64,
+  ) -> Result<Vec<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(Users::Table)
+      .column(ColumnRef::Asterisk)
+      .cond_where(Self::search_condition(email))
+      .order_by(Users::CreatedAt, Order::Asc)
+      .order_by(Users::Id, Order::Asc)
+      .offset(offset)
+      .limit(limit)
+      .to_string(PostgresQueryBuilder);
+
+    let users: Result<Vec<UserEntity>, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
+      .fetch_all(conn)
+      .await;
+    Ok(users?)
+  }
+
+  async fn count(&self, email: Option<String>) -> Result<i64, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+    let query: String = Query::select()
+      .from(Users::Table)
+      .expr(Func::count(Expr::col(Users::Id)))
+      .cond_where(Self::search_condition(email))
+      .to_string(PostgresQueryBuilder);
+
+    let count: Result<i64, sqlx::Error> =
+      sqlx::query_scalar::<_, i64>(&query).fetch_one(conn).await;
+    Ok(count?)
+  }
+
+  /// Soft-deletes the user; returns false when it was already deleted.
+  async fn delete(&self, id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(Users::Table)
+      .value(Users::DeletedAt, TimeUtils::utc_now().to_rfc3339())
+      .and_where(Expr::col(Users::Id).eq(id.to_string()))
+      .and_where(Expr::col(Users::DeletedAt).is_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  async fn reactivate(&self, id: Uuid) -> Result<bool, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(Users::Table)
+      .value(Users::DeletedAt, Expr::value(None::<String>))
+      .and_where(Expr::col(Users::Id).eq(id.to_string()))
+      .and_where(Expr::col(Users::DeletedAt).is_not_null())
+      .to_string(PostgresQueryBuilder);
+
+    let result: PgQueryResult = sqlx::query(&query).execute(conn).await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  /// Brings back a deleted user with this email, as emails stay unique across deletes, as a
+  /// plain `user` without a password, linked identities, API keys or sessions, so nothing the
+  /// previous holder of the email had carries over.
+  async fn restore(&self, email: String) -> Result<Option<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.primary();
+    let query: String = Query::update()
+      .table(Users::Table)
+      .values([
+        (Users::DeletedAt, Expr::value(None::<String>)),
+        (Users::Password, Expr::value(None::<String>)),
+        (Users::Role, UserRole::User.into()),
+      ])
+      .and_where(Expr::col(Users::Email).eq(email))
+      .and_where(Expr::col(Users::DeletedAt).is_not_null())
+      .returning_all()
+      .to_string(PostgresQueryBuilder);
+
+    let mut transaction: Transaction<'static, Postgres> = conn.begin().await?;
+    let user: Option<UserEntity> = sqlx::query_as::<_, UserEntity>(&query)
+      .fetch_optional(&mut *transaction)
+      .await?;
+    let Some(user) = user else {
+      return Ok(None);
+    };
+    for query in [
+      Self::delete_by_user_query(UserIdentities::Table, UserIdentities::UserId, user.id),
+      Self::delete_by_user_query(ApiKeys::Table, ApiKeys::UserId, user.id),
+      Self::delete_by_user_query(RefreshTokens::Table, RefreshTokens::UserId, user.id),
+    ] {
+      sqlx::query(&query).execute(&mut *transaction).await?;
+    }
+    transaction.commit().await?;
+    Ok(Some(user))
+  }
+}
+
+impl RepositoryImpl {
+  fn delete_by_user_query<T, C>(table: T, column: C, user_id: Uuid) -> String
+  where
+    T: Iden + 'static,
+    C: Iden + 'static,
+  {
+    Query::delete()
+      .from_table(table)
+      .and_where(Expr::col(column).eq(user_id.to_string()))
+      .to_string(PostgresQueryBuilder)
+  }
+
+  /// Inserts a user and returns the created row.
+  pub(crate) fn create_user_query(
+    email: String,
+    password: Option<String>,
+    name: Option<String>,
+    role: UserRole,
+    status: UserStatus,
+  ) -> String {
+    Query::insert()
+      .into_table(Users::Table)
+      .columns([
+        Users::Email,
+        Users::Password,
+        Users::Name,
+        Users::Role,
+        Users::Status,
+      ])
+      .values_panic([
+        email.into(),
+        password
+          .as_deref()
+          .map(Expr::value)
+          .unwrap_or(Expr::value(None::<&str>)),
+        name
+          .as_deref()
+          .map(Expr::value)
+          .unwrap_or(Expr::value(None::<&str>)),
+        role.into(),
+        status.into(),
+      ])
+      .returning_col(ColumnRef::Asterisk)
+      .to_string(PostgresQueryBuilder)
+  }
+
+  /// Active users, optionally narrowed to a case-insensitive email match.
+  fn search_condition(email: Option<String>) -> Cond {
+    let mut condition: Cond = Cond::all().add(Expr::col(Users::DeletedAt).is_null());
+    if let Some(email) = email {
+      condition =
+        condition.add(Expr::expr(Func::lower(Expr::col(Users::Email))).eq(email.to_lowercase()));
+    }
+    condition
+  }
+
+  /// Keyset read of the page after `cursor`. One extra row tells whether another page follows,
+  /// and no total is counted since that would scan every match again.
+  async fn all_after(
+    &self,
+    cursor: Cursor,
+    per_page: usize,
+    sorting: Sorting<Users>,
+    filter: UserFilter,
+  ) -> Result<Page<UserEntity>, AppError> {
+    let conn: &DbPool = self.db_pool.replica();
+
+    let query: String = Query::select()
+      .from(Users::Table)
+      .column(ColumnRef::Asterisk)
+      .cond_where(Self::filter_condition(filter).add(CursorHelper::condition(&sorting, &cursor)?))
+      .limit(per_page as u64 + 1)
+      .order_by_columns(SortingHelper::columns(&sorting))
+      .to_string(PostgresQueryBuilder);
+
+    let users: Result<Vec<UserEntity>, sqlx::Error> = sqlx::query_as::<_, UserEntity>(&query)
+      .fetch_all(conn)
+      .await;
+    let mut users: Vec<UserEntity> = users?;
+
+    let next_cursor: Option<Cursor> = match users.len() > per_page {
+      true => {
+        users.truncate(per_page);
+        users.last().map(|user| Self::user_cursor(user, &sorting))
+      }
+      false => None,
+    };
+    Ok(Page {
+      items: users,
+      total: None,
+      next_cursor,
+    })
+  }
+
+  fn user_cursor(user: &UserEntity, sorting: &Sorting<Users>) -> Cursor {
+    CursorHelper::cursor(sorting, user.id, |column| user.sort_value(column))
+  }
+
+  /// `q` is matched literally, so `%`, `_` and the default LIKE escape `\` in it are escaped;
+  /// the trigram indexes on email and name serve the leading wildcard.
+  fn filter_condition(filter: UserFilter) -> Cond {
+    let mut condition: Cond = Cond::all().add(Expr::col(Users::DeletedAt).is_null());
+    if let Some(q) = filter.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+      let pattern: String = format!(
+        "%{}%",
+        q.replace('\\', "\\\\")
+          .replace('%', "\\%")
+          .replace('_', "\\_")
+      );
+      condition = condition.add(
+        Cond::any()
+          .add(Expr::col(Users::Email).ilike(pattern.clone()))
+          .add(Expr::col(Users::Name).ilike(pattern)),
+      );
+    }
+    if let Some(role) = filter.role {
+      condition = condition.add(Expr::col(Users::Role).eq(role));
+    }
+    if let Some(status) = filter.status {
+      condition = condition.add(Expr::col(Users::Status).eq(status));
+    }
+    if let Some(provider) = filter.auth_provider {
+      condition = condition.add(Expr::exists(
+        Query::select()
+          .expr(Expr::val(1))
+          .from(UserIdentities::Table)
+          .and_where(
+            Expr::col((UserIdentities::Table, UserIdentities::UserId))
+              .equals((Users::Table, Users::Id)),
+          )
+          .and_where(Expr::col((UserIdentities::Table, UserIdentities::Provider)).eq(provider))
+          .to_owned(),
+      ));
+    }
+    if let Some(from) = filter.created_from {
+      condition = condition.add(Expr::col(Users::CreatedAt).gte(from.to_rfc3339()));
+    }
+    if let Some(to) = filter.created_to {
+      condition = condition.add(Expr::col(Users::CreatedAt).lte(to.to_rfc3339()));
+    }
+    if let Some(from) = filter.logged_in_from {
+      condition = condition.add(Expr::col(Users::LoggedInAt).gte(from.to_rfc3339()));
+    }
+    if let Some(to) = filter.logged_in_to {
+      condition = condition.add(Expr::col(Users::LoggedInAt).lte(to.to_rfc3339()));
+    }
+    condition
+  }
 }
@@ -1,7 +1,22 @@
+use crate::api::controller::api_keys::api_key_controller::ApiKeyController;
 use crate::api::controller::auth::auth_controller::AuthController;
 use crate::api::controller::health::health_controller::HealthController;
+use crate::api::controller::identities::identity_controller::IdentityController;
+use crate::api::controller::impersonation::impersonation_controller::ImpersonationController;
+use crate::api::controller::invitations::invitation_controller::InvitationController;
+use crate::api::controller::oauth::oauth_controller::OAuthController;
+use crate::api::controller::organizations::organization_controller::OrganizationController;
+use crate::api::controller::saml::saml_controller::SamlController;
+use crate::api::controller::scim::scim_controller::ScimController;
 use crate::api::controller::users::user_controller::UserController;
+use crate::api::state::api_keys::api_key_state::ApiKeyState;
 use crate::api::state::auth::auth_state::AuthState;
+use crate::api::state::impersonation::impersonation_state::ImpersonationState;
+use crate::api::state::invitations::invitation_state::InvitationState;
+use crate::api::state::oauth::oauth_state::OAuthState;
+use crate::api::state::organizations::organization_state::OrganizationState;
+use crate::api::state::roles::role_state::RoleState;
+use crate::api::state::scim::scim_state::ScimState;
 use crate::api::state::users::user_state::UserState;
 use crate::core::cors::cors::Cors;
 use crate::core::error::error::AppError;
@@ -11,14 +26,14 @@ use crate::db::db::PostgresDatabase;
 use crate::resources::config::AppConfig;
 use axum::body::Body;
 use axum::http::Request;
-use axum::Router;
+use axum::{Extension, Router};
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tower_http::decompression::RequestDecompressionLayer;
 use tower_http::trace::{HttpMakeClassifier, TraceLayer};
-use tracing::{info, Span};
+use tracing::{info, warn, Span};
 
 type LogsLayerType = TraceLayer<HttpMakeClassifier, fn(&Request<Body>) -> Span>;
 
@@ -54,19 +69,60 @@ impl Api {
         self.jwt_manager.clone(),
         &self.config.aws,
         &self.config.oauth2,
+        &self.config.magic_link,
+        &self.config.saml,
+        &self.config.ldap,
       )
       .await,
     );
     let user_state: Arc<UserState> =
       Arc::new(UserState::new(&self.db_pool, &self.config.aws).await);
+    let oauth_state: Arc<OAuthState> = Arc::new(
+      OAuthState::new(
+        &self.db_pool,
+        self.jwt_manager.clone(),
+        &self.config.oidc_provider,
+      )
+      .await,
+    );
+    let api_key_state: Arc<ApiKeyState> = Arc::new(ApiKeyState::new(&self.db_pool).await);
+    let role_state: Arc<RoleState> = Arc::new(RoleState::new(&self.db_pool).await);
+    let impersonation_state: Arc<ImpersonationState> =
+      Arc::new(ImpersonationState::new(&self.db_pool, self.jwt_manager.clone()).await);
+    let organization_state: Arc<OrganizationState> =
+      Arc::new(OrganizationState::new(&self.db_pool).await);
+    let invitation_state: Arc<InvitationState> = Arc::new(
+      InvitationState::new(
+        &self.db_pool,
+        self.jwt_manager.clone(),
+        &self.config.aws,
+        &self.config.invitation,
+      )
+      .await,
+    );
+    let scim_state: Arc<ScimState> =
+      Arc::new(ScimState::new(&self.db_pool, &self.config.scim).await);
 
     let app: Router = Router::new()
       .nest(
         &self.config.server.path_prefix,
         Router::new()
           .merge(self.configure_health())
-          .merge(self.configure_auth(auth_state))
-          .merge(self.configure_user(user_state)),
+          .merge(self.configure_auth(auth_state.clone()))
+          .merge(self.configure_identity(auth_state.clone()))
+          .merge(self.configure_saml(auth_state))
+          .merge(self.configure_user(user_state.clone()))
+          .merge(self.configure_oauth(oauth_state))
+          .merge(self.configure_api_key(api_key_state.clone()))
+          .merge(self.configure_impersonation(impersonation_state.clone()))
+          .merge(self.configure_scim(scim_state))
+          .merge(self.configure_organization(organization_state.clone()))
+          .merge(self.configure_invitation(invitation_state))
+          .layer(Extension(api_key_state))
+          .layer(Extension(role_state))
+          .layer(Extension(organization_state))
+          .layer(Extension(user_state))
+          .layer(Extension(impersonation_state)),
       )
       .layer(self.cors_layer.clone())
       .layer(self.logs_layer.clone())
@@ -84,10 +140,45 @@ impl Api {
     AuthController::configure(auth_state)
   }
 
+  fn configure_identity(&self, auth_state: Arc<AuthState>) -> Router {
+    IdentityController::configure(auth_state)
+  }
+
+  fn configure_saml(&self, auth_state: Arc<AuthState>) -> Router {
+    SamlController::configure(auth_state)
+  }
+
   fn configure_user(&self, user_state: Arc<UserState>) -> Router {
     UserController::configure(user_state)
   }
 
+  fn configure_oauth(&self, oauth_state: Arc<OAuthState>) -> Router {
+    OAuthController::configure(oauth_state)
+  }
+
+  fn configure_api_key(&self, api_key_state: Arc<ApiKeyState>) -> Router {
+    ApiKeyController::configure(api_key_state)
+  }
+
+  fn configure_impersonation(&self, impersonation_state: Arc<ImpersonationState>) -> Router {
+    ImpersonationController::configure(impersonation_state)
+  }
+
+  fn configure_scim(&self, scim_state: Arc<ScimState>) -> Router {
+    if scim_state.config.secret().is_none() {
+      warn!("SCIM is disabled: `scim.secret` is not set");
+    }
+    ScimController::configure(scim_state)
+  }
+
+  fn configure_organization(&self, organization_state: Arc<OrganizationState>) -> Router {
+    OrganizationController::configure(organization_state)
+  }
+
+  fn configure_invitation(&self, invitation_state: Arc<InvitationState>) -> Router {
+    InvitationController::configure(invitation_state)
+  }
+
   pub async fn start(self) -> Result<(), AppError> {
     let app: Router = self.configure_routes().await?;
     let addr: String = format!(
@@ -0,0 +1,19 @@
+use crate::api::manager::api_keys::api_key_manager::ApiKeyManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::db::db::PostgresDatabase;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ApiKeyState {
+  pub manager: ApiKeyManagerImpl,
+}
+
+impl ApiKeyState {
+  pub async fn new(db_pool: &PostgresDatabase) -> Self {
+    let manager: ApiKeyManagerImpl = ApiKeyManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+    );
+    Self { manager }
+  }
+}
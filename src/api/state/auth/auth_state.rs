@@ -1,11 +1,15 @@
 use crate::api::manager::auth::auth_manager::AuthManagerImpl;
-use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+use crate::api::manager::auth::password_authenticator::{
+  LdapPasswordAuthenticator, LocalPasswordAuthenticator, PasswordAuthenticator,
+};
 use crate::api::repository::RepositoryImpl;
 use crate::core::security::jwt_manager::JwtManager;
 use crate::db::db::PostgresDatabase;
-use crate::resources::config::{AWSConfig, OAuth2Config};
+use crate::resources::config::{AWSConfig, LdapConfig, MagicLinkConfig, OAuth2Config, SamlConfig};
 use crate::service::aws::sqs::producer::producer::SQSProducerImpl;
+use crate::service::ldap::ldap::LdapServiceImpl;
 use crate::service::oauth2::oauth2::OAuth2ServiceImpl;
+use crate::service::saml::saml::SamlServiceImpl;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -20,20 +24,37 @@ impl AuthState {
     jwt_manager: JwtManager,
     aws_config: &AWSConfig,
     oauth2_config: &HashMap<String, OAuth2Config>,
+    magic_link_config: &MagicLinkConfig,
+    saml_config: &SamlConfig,
+    ldap_config: &LdapConfig,
   ) -> Self {
     let aws_config: AWSConfig = aws_config.clone();
-
-    let google_oauth2_config: OAuth2Config = oauth2_config
-      .get(OAuth2Provider::Google.as_str())
-      .unwrap()
-      .clone();
+    let password_authenticator: Arc<dyn PasswordAuthenticator> = match ldap_config.enabled {
+      true => Arc::new(LdapPasswordAuthenticator::new(
+        Arc::new(RepositoryImpl::new(db_pool.clone())),
+        Arc::new(RepositoryImpl::new(db_pool.clone())),
+        Arc::new(LdapServiceImpl::new(ldap_config.clone())),
+      )),
+      false => Arc::new(LocalPasswordAuthenticator::new(Arc::new(
+        RepositoryImpl::new(db_pool.clone()),
+      ))),
+    };
 
     let manager: AuthManagerImpl = AuthManagerImpl::new(
       Arc::new(RepositoryImpl::new(db_pool.clone())),
       Arc::new(RepositoryImpl::new(db_pool.clone())),
       jwt_manager,
       Arc::new(SQSProducerImpl::new(aws_config.region, aws_config.sqs).await),
-      Arc::new(OAuth2ServiceImpl::new(google_oauth2_config).await),
+      OAuth2ServiceImpl::registry(oauth2_config).await,
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      magic_link_config.clone(),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(SamlServiceImpl::new(saml_config.clone())),
+      password_authenticator,
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
     );
     Self { manager }
   }
@@ -0,0 +1,21 @@
+use crate::api::manager::impersonation::impersonation_manager::ImpersonationManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::core::security::jwt_manager::JwtManager;
+use crate::db::db::PostgresDatabase;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ImpersonationState {
+  pub manager: ImpersonationManagerImpl,
+}
+
+impl ImpersonationState {
+  pub async fn new(db_pool: &PostgresDatabase, jwt_manager: JwtManager) -> Self {
+    let manager: ImpersonationManagerImpl = ImpersonationManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      jwt_manager,
+    );
+    Self { manager }
+  }
+}
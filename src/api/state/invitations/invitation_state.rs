@@ -0,0 +1,31 @@
+use crate::api::manager::invitations::invitation_manager::InvitationManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::core::security::jwt_manager::JwtManager;
+use crate::db::db::PostgresDatabase;
+use crate::resources::config::{AWSConfig, InvitationConfig};
+use crate::service::aws::sqs::producer::producer::SQSProducerImpl;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct InvitationState {
+  pub manager: InvitationManagerImpl,
+}
+
+impl InvitationState {
+  pub async fn new(
+    db_pool: &PostgresDatabase,
+    jwt_manager: JwtManager,
+    aws_config: &AWSConfig,
+    invitation_config: &InvitationConfig,
+  ) -> Self {
+    let aws_config: AWSConfig = aws_config.clone();
+    let manager: InvitationManagerImpl = InvitationManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      jwt_manager,
+      Arc::new(SQSProducerImpl::new(aws_config.region, aws_config.sqs).await),
+      invitation_config.clone(),
+    );
+    Self { manager }
+  }
+}
@@ -1,2 +1,9 @@
+pub(crate) mod api_keys;
 pub(crate) mod auth;
+pub(crate) mod impersonation;
+pub(crate) mod invitations;
+pub(crate) mod oauth;
+pub(crate) mod organizations;
+pub(crate) mod roles;
+pub(crate) mod scim;
 pub(crate) mod users;
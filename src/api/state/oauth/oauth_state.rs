@@ -0,0 +1,29 @@
+use crate::api::manager::oauth::oauth_manager::OAuthManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::core::security::jwt_manager::JwtManager;
+use crate::db::db::PostgresDatabase;
+use crate::resources::config::OidcProviderConfig;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct OAuthState {
+  pub manager: OAuthManagerImpl,
+}
+
+impl OAuthState {
+  pub async fn new(
+    db_pool: &PostgresDatabase,
+    jwt_manager: JwtManager,
+    oidc_provider_config: &OidcProviderConfig,
+  ) -> Self {
+    let manager: OAuthManagerImpl = OAuthManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      jwt_manager,
+      oidc_provider_config.clone(),
+    );
+    Self { manager }
+  }
+}
@@ -0,0 +1,19 @@
+use crate::api::manager::organizations::organization_manager::OrganizationManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::db::db::PostgresDatabase;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct OrganizationState {
+  pub manager: OrganizationManagerImpl,
+}
+
+impl OrganizationState {
+  pub async fn new(db_pool: &PostgresDatabase) -> Self {
+    let manager: OrganizationManagerImpl = OrganizationManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+    );
+    Self { manager }
+  }
+}
@@ -0,0 +1,17 @@
+use crate::api::manager::roles::role_manager::RoleManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::db::db::PostgresDatabase;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct RoleState {
+  pub manager: RoleManagerImpl,
+}
+
+impl RoleState {
+  pub async fn new(db_pool: &PostgresDatabase) -> Self {
+    let manager: RoleManagerImpl =
+      RoleManagerImpl::new(Arc::new(RepositoryImpl::new(db_pool.clone())));
+    Self { manager }
+  }
+}
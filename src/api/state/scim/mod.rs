@@ -0,0 +1 @@
+pub(crate) mod scim_state;
@@ -0,0 +1,24 @@
+use crate::api::manager::scim::scim_manager::ScimManagerImpl;
+use crate::api::repository::RepositoryImpl;
+use crate::db::db::PostgresDatabase;
+use crate::resources::config::ScimConfig;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ScimState {
+  pub manager: ScimManagerImpl,
+  pub config: ScimConfig,
+}
+
+impl ScimState {
+  pub async fn new(db_pool: &PostgresDatabase, scim_config: &ScimConfig) -> Self {
+    let manager: ScimManagerImpl = ScimManagerImpl::new(
+      Arc::new(RepositoryImpl::new(db_pool.clone())),
+      scim_config.clone(),
+    );
+    Self {
+      manager,
+      config: scim_config.clone(),
+    }
+  }
+}
@@ -0,0 +1,272 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::resources::config::{LdapConfig, LdapGroupRoleConfig};
+use bytes::BytesMut;
+use lber::common::TagClass;
+use lber::parse::parse_tag;
+use lber::structure::{StructureTag, PL};
+use lber::write::encode_into;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+pub const LDAP_STUB_BASE_DN: &str = "dc=example,dc=com";
+pub const LDAP_STUB_ADMIN_GROUP: &str = "cn=admins,ou=groups,dc=example,dc=com";
+const BIND_DN: &str = "cn=service,dc=example,dc=com";
+const BIND_PASSWORD: &str = "service-secret";
+const SUCCESS: u8 = 0;
+const NO_SUCH_OBJECT: u8 = 32;
+const INVALID_CREDENTIALS: u8 = 49;
+
+pub struct LdapStubEntry {
+  pub dn: String,
+  pub password: String,
+  pub attributes: Vec<(String, Vec<String>)>,
+}
+
+impl LdapStubEntry {
+  pub fn person(uid: &str, email: &str, name: &str, password: &str, groups: &[&str]) -> Self {
+    let mut attributes: Vec<(String, Vec<String>)> = vec![
+      ("objectClass".to_string(), vec!["person".to_string()]),
+      ("mail".to_string(), vec![email.to_string()]),
+      ("displayName".to_string(), vec![name.to_string()]),
+    ];
+    if !groups.is_empty() {
+      attributes.push((
+        "memberOf".to_string(),
+        groups.iter().map(|group| group.to_string()).collect(),
+      ));
+    }
+    Self {
+      dn: format!("uid={},ou=people,{}", uid, LDAP_STUB_BASE_DN),
+      password: password.to_string(),
+      attributes,
+    }
+  }
+
+  fn values(&self, name: &[u8]) -> Option<&Vec<String>> {
+    self
+      .attributes
+      .iter()
+      .find(|(attribute, _)| attribute.as_bytes().eq_ignore_ascii_case(name))
+      .map(|(_, values)| values)
+  }
+}
+
+/// In-process directory speaking just enough LDAPv3 for simple binds and searches with
+/// `&`, `|`, `!`, equality and presence filters.
+pub struct LdapStub {
+  pub url: String,
+  entries: Vec<LdapStubEntry>,
+}
+
+impl LdapStub {
+  pub async fn start(entries: Vec<LdapStubEntry>) -> Arc<LdapStub> {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let stub: Arc<LdapStub> = Arc::new(LdapStub {
+      url: format!("ldap://{}", listener.local_addr().unwrap()),
+      entries,
+    });
+
+    let server: Arc<LdapStub> = stub.clone();
+    tokio::spawn(async move {
+      while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(server.clone().serve(stream));
+      }
+    });
+    stub
+  }
+
+  pub fn config(&self) -> LdapConfig {
+    LdapConfig {
+      enabled: true,
+      url: self.url.clone(),
+      bind_dn: BIND_DN.to_string(),
+      bind_password: BIND_PASSWORD.to_string(),
+      base_dn: LDAP_STUB_BASE_DN.to_string(),
+      user_filter: "(&(objectClass=person)(mail={email}))".to_string(),
+      email_attribute: "mail".to_string(),
+      name_attribute: "displayName".to_string(),
+      group_attribute: "memberOf".to_string(),
+      group_roles: vec![LdapGroupRoleConfig {
+        group: LDAP_STUB_ADMIN_GROUP.to_string(),
+        role: UserRole::Admin,
+      }],
+    }
+  }
+
+  async fn serve(self: Arc<Self>, mut stream: TcpStream) {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk: [u8; 4096] = [0; 4096];
+    loop {
+      let message: StructureTag = match parse_tag(&buffer) {
+        Ok((rest, message)) => {
+          buffer = rest.to_vec();
+          message
+        }
+        Err(lber::Err::Incomplete(_)) => match stream.read(&mut chunk).await {
+          Ok(0) | Err(_) => return,
+          Ok(read) => {
+            buffer.extend_from_slice(&chunk[..read]);
+            continue;
+          }
+        },
+        Err(_) => return,
+      };
+
+      let Some(mut parts) = message.expect_constructed() else {
+        return;
+      };
+      if parts.len() < 2 {
+        return;
+      }
+      let operation: StructureTag = parts.remove(1);
+      let message_id: Vec<u8> = parts.remove(0).expect_primitive().unwrap_or_default();
+
+      let responses: Vec<StructureTag> = match operation.id {
+        0 => vec![self.bind(operation)],
+        3 => self.search(operation),
+        _ => return,
+      };
+      let mut output: BytesMut = BytesMut::new();
+      for response in responses {
+        let envelope: StructureTag = Self::constructed(
+          TagClass::Universal,
+          16,
+          vec![
+            Self::primitive(TagClass::Universal, 2, message_id.clone()),
+            response,
+          ],
+        );
+        encode_into(&mut output, envelope).unwrap();
+      }
+      if stream.write_all(&output).await.is_err() {
+        return;
+      }
+    }
+  }
+
+  fn bind(&self, operation: StructureTag) -> StructureTag {
+    let parts: Vec<StructureTag> = operation.expect_constructed().unwrap_or_default();
+    let dn: Vec<u8> = parts
+      .get(1)
+      .cloned()
+      .and_then(StructureTag::expect_primitive)
+      .unwrap_or_default();
+    let password: Vec<u8> = parts
+      .get(2)
+      .cloned()
+      .and_then(StructureTag::expect_primitive)
+      .unwrap_or_default();
+
+    let authenticated: bool = (dn == BIND_DN.as_bytes() && password == BIND_PASSWORD.as_bytes())
+      || self.entries.iter().any(|entry| {
+        entry.dn.as_bytes() == dn.as_slice() && entry.password.as_bytes() == password.as_slice()
+      });
+    let code: u8 = if authenticated {
+      SUCCESS
+    } else {
+      INVALID_CREDENTIALS
+    };
+    Self::result(1, code)
+  }
+
+  fn search(&self, operation: StructureTag) -> Vec<StructureTag> {
+    let mut parts: Vec<StructureTag> = operation.expect_constructed().unwrap_or_default();
+    if parts.len() < 7 {
+      return vec![Self::result(5, NO_SUCH_OBJECT)];
+    }
+    let filter: StructureTag = parts.remove(6);
+    let base: String = String::from_utf8(parts.remove(0).expect_primitive().unwrap_or_default())
+      .unwrap_or_default()
+      .to_lowercase();
+
+    let mut responses: Vec<StructureTag> = self
+      .entries
+      .iter()
+      .filter(|entry| entry.dn.to_lowercase().ends_with(&base))
+      .filter(|entry| Self::matches(entry, &filter))
+      .map(|entry| {
+        let attributes: Vec<StructureTag> = entry
+          .attributes
+          .iter()
+          .map(|(name, values)| {
+            Self::constructed(
+              TagClass::Universal,
+              16,
+              vec![
+                Self::primitive(TagClass::Universal, 4, name.as_bytes().to_vec()),
+                Self::constructed(
+                  TagClass::Universal,
+                  17,
+                  values
+                    .iter()
+                    .map(|value| Self::primitive(TagClass::Universal, 4, value.as_bytes().to_vec()))
+                    .collect(),
+                ),
+              ],
+            )
+          })
+          .collect();
+        Self::constructed(
+          TagClass::Application,
+          4,
+          vec![
+            Self::primitive(TagClass::Universal, 4, entry.dn.as_bytes().to_vec()),
+            Self::constructed(TagClass::Universal, 16, attributes),
+          ],
+        )
+      })
+      .collect();
+    responses.push(Self::result(5, SUCCESS));
+    responses
+  }
+
+  fn matches(entry: &LdapStubEntry, filter: &StructureTag) -> bool {
+    match (&filter.payload, filter.id) {
+      (PL::C(filters), 0) => filters.iter().all(|filter| Self::matches(entry, filter)),
+      (PL::C(filters), 1) => filters.iter().any(|filter| Self::matches(entry, filter)),
+      (PL::C(filters), 2) => !filters.iter().any(|filter| Self::matches(entry, filter)),
+      (PL::C(assertion), 3) => match assertion.as_slice() {
+        [name, value] => match (&name.payload, &value.payload) {
+          (PL::P(name), PL::P(value)) => entry.values(name).is_some_and(|values| {
+            values
+              .iter()
+              .any(|candidate| candidate.as_bytes().eq_ignore_ascii_case(value))
+          }),
+          _ => false,
+        },
+        _ => false,
+      },
+      (PL::P(name), 7) => entry.values(name).is_some(),
+      _ => false,
+    }
+  }
+
+  fn result(operation: u64, code: u8) -> StructureTag {
+    Self::constructed(
+      TagClass::Application,
+      operation,
+      vec![
+        Self::primitive(TagClass::Universal, 10, vec![code]),
+        Self::primitive(TagClass::Universal, 4, Vec::new()),
+        Self::primitive(TagClass::Universal, 4, Vec::new()),
+      ],
+    )
+  }
+
+  fn primitive(class: TagClass, id: u64, value: Vec<u8>) -> StructureTag {
+    StructureTag {
+      class,
+      id,
+      payload: PL::P(value),
+    }
+  }
+
+  fn constructed(class: TagClass, id: u64, values: Vec<StructureTag>) -> StructureTag {
+    StructureTag {
+      class,
+      id,
+      payload: PL::C(values),
+    }
+  }
+}
@@ -1 +1,4 @@
+pub mod ldap_stub;
+pub mod oidc_stub;
+pub mod saml_stub;
 pub mod test_context;
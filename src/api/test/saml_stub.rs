@@ -0,0 +1,175 @@
+use crate::service::saml::canonicalizer::{Canonicalizer, EXC_C14N};
+use crate::service::saml::saml::{ASSERTION_NS, DSIG_NS, PROTOCOL_NS};
+use crate::utils::datetime_utils::TimeUtils;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use openssl::asn1::Asn1Time;
+use openssl::bn::BigNum;
+use openssl::hash::{hash, MessageDigest};
+use openssl::pkey::{PKey, Private};
+use openssl::rsa::Rsa;
+use openssl::sign::Signer;
+use openssl::x509::{X509NameBuilder, X509};
+use roxmltree::Document;
+use uuid::Uuid;
+
+/// Identity provider side of a SAML exchange: a throwaway key pair with a self-signed
+/// certificate, used to sign assertions the way a real IdP would.
+pub struct SamlIdp {
+  pub entity_id: String,
+  key: PKey<Private>,
+  certificate: X509,
+}
+
+impl SamlIdp {
+  pub fn generate() -> Self {
+    let key: PKey<Private> = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+    let mut name = X509NameBuilder::new().unwrap();
+    name.append_entry_by_text("CN", "stub-idp").unwrap();
+    let name = name.build();
+
+    let mut builder = X509::builder().unwrap();
+    builder.set_version(2).unwrap();
+    builder
+      .set_serial_number(&BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap())
+      .unwrap();
+    builder.set_subject_name(&name).unwrap();
+    builder.set_issuer_name(&name).unwrap();
+    builder.set_pubkey(&key).unwrap();
+    builder
+      .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+      .unwrap();
+    builder
+      .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+      .unwrap();
+    builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+    Self {
+      entity_id: format!("https://idp.example.com/{}", Uuid::new_v4()),
+      key,
+      certificate: builder.build(),
+    }
+  }
+
+  pub fn certificate_pem(&self) -> String {
+    String::from_utf8(self.certificate.to_pem().unwrap()).unwrap()
+  }
+
+  /// Unsigned assertion for `email`, addressed to the SP `audience` and its ACS `recipient`.
+  pub fn assertion(&self, audience: &str, recipient: &str, email: &str, name: &str) -> String {
+    let now: DateTime<Utc> = TimeUtils::utc_now();
+    let issue_instant: String = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let not_on_or_after: String =
+      (now + Duration::minutes(5)).to_rfc3339_opts(SecondsFormat::Secs, true);
+    format!(
+      concat!(
+        r#"<saml:Assertion xmlns:saml="{ns}" ID="_{id}" Version="2.0" IssueInstant="{instant}">"#,
+        r#"<saml:Issuer>{issuer}</saml:Issuer>"#,
+        r#"<saml:Subject>"#,
+        r#"<saml:NameID Format="urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress">{email}</saml:NameID>"#,
+        r#"<saml:SubjectConfirmation Method="urn:oasis:names:tc:SAML:2.0:cm:bearer">"#,
+        r#"<saml:SubjectConfirmationData NotOnOrAfter="{expires}" Recipient="{recipient}"/>"#,
+        r#"</saml:SubjectConfirmation>"#,
+        r#"</saml:Subject>"#,
+        r#"<saml:Conditions NotBefore="{instant}" NotOnOrAfter="{expires}">"#,
+        r#"<saml:AudienceRestriction><saml:Audience>{audience}</saml:Audience></saml:AudienceRestriction>"#,
+        r#"</saml:Conditions>"#,
+        r#"<saml:AttributeStatement>"#,
+        r#"<saml:Attribute Name="email"><saml:AttributeValue>{email}</saml:AttributeValue></saml:Attribute>"#,
+        r#"<saml:Attribute Name="displayName"><saml:AttributeValue>{name}</saml:AttributeValue></saml:Attribute>"#,
+        r#"</saml:AttributeStatement>"#,
+        r#"</saml:Assertion>"#,
+      ),
+      ns = ASSERTION_NS,
+      id = Uuid::new_v4(),
+      instant = issue_instant,
+      issuer = self.entity_id,
+      email = email,
+      expires = not_on_or_after,
+      recipient = recipient,
+      audience = audience,
+      name = name,
+    )
+  }
+
+  /// Adds an enveloped RSA-SHA256 signature right after the assertion's `Issuer`.
+  pub fn sign(&self, assertion: &str) -> String {
+    let document: Document = Document::parse(assertion).unwrap();
+    let root = document.root_element();
+    let id: &str = root.attribute("ID").unwrap();
+    let content: String = Canonicalizer::new(EXC_C14N, &[])
+      .unwrap()
+      .canonicalize(root);
+    let digest: String =
+      BASE64_STANDARD.encode(hash(MessageDigest::sha256(), content.as_bytes()).unwrap());
+
+    let signed_info: String = format!(
+      concat!(
+        r#"<ds:SignedInfo xmlns:ds="{ns}">"#,
+        r#"<ds:CanonicalizationMethod Algorithm="{c14n}"/>"#,
+        r#"<ds:SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"/>"#,
+        r##"<ds:Reference URI="#{id}">"##,
+        r#"<ds:Transforms>"#,
+        r#"<ds:Transform Algorithm="http://www.w3.org/2000/09/xmldsig#enveloped-signature"/>"#,
+        r#"<ds:Transform Algorithm="{c14n}"/>"#,
+        r#"</ds:Transforms>"#,
+        r#"<ds:DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256"/>"#,
+        r#"<ds:DigestValue>{digest}</ds:DigestValue>"#,
+        r#"</ds:Reference>"#,
+        r#"</ds:SignedInfo>"#,
+      ),
+      ns = DSIG_NS,
+      c14n = EXC_C14N,
+      id = id,
+      digest = digest,
+    );
+    let signed_info_document: Document = Document::parse(&signed_info).unwrap();
+    let canonical_signed_info: String = Canonicalizer::new(EXC_C14N, &[])
+      .unwrap()
+      .canonicalize(signed_info_document.root_element());
+    let mut signer: Signer = Signer::new(MessageDigest::sha256(), &self.key).unwrap();
+    let signature_value: String = BASE64_STANDARD.encode(
+      signer
+        .sign_oneshot_to_vec(canonical_signed_info.as_bytes())
+        .unwrap(),
+    );
+    let certificate: String = BASE64_STANDARD.encode(self.certificate.to_der().unwrap());
+
+    let signature: String = format!(
+      concat!(
+        r#"<ds:Signature xmlns:ds="{ns}">{signed_info}"#,
+        r#"<ds:SignatureValue>{signature_value}</ds:SignatureValue>"#,
+        r#"<ds:KeyInfo><ds:X509Data><ds:X509Certificate>{certificate}</ds:X509Certificate></ds:X509Data></ds:KeyInfo>"#,
+        r#"</ds:Signature>"#,
+      ),
+      ns = DSIG_NS,
+      signed_info = signed_info,
+      signature_value = signature_value,
+      certificate = certificate,
+    );
+    assertion.replacen("</saml:Issuer>", &format!("</saml:Issuer>{}", signature), 1)
+  }
+
+  /// Wraps an assertion in a successful `Response`, base64 encoded as in the HTTP-POST binding.
+  pub fn response(&self, destination: &str, assertion: &str) -> String {
+    let response: String = format!(
+      concat!(
+        r#"<samlp:Response xmlns:samlp="{protocol}" xmlns:saml="{ns}" ID="_{id}" Version="2.0" "#,
+        r#"IssueInstant="{instant}" Destination="{destination}">"#,
+        r#"<saml:Issuer>{issuer}</saml:Issuer>"#,
+        r#"<samlp:Status><samlp:StatusCode Value="urn:oasis:names:tc:SAML:2.0:status:Success"/></samlp:Status>"#,
+        r#"{assertion}"#,
+        r#"</samlp:Response>"#,
+      ),
+      protocol = PROTOCOL_NS,
+      ns = ASSERTION_NS,
+      id = Uuid::new_v4(),
+      instant = TimeUtils::utc_now().to_rfc3339_opts(SecondsFormat::Secs, true),
+      destination = destination,
+      issuer = self.entity_id,
+      assertion = assertion,
+    );
+    BASE64_STANDARD.encode(response)
+  }
+}
@@ -107,13 +107,54 @@ impl AuthSetup {
     let config: AppConfig = APP_CONFIG.clone();
     let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres).await.unwrap();
     let jwt_manager: JwtManager = JwtManager::new(config.jwt);
-    let auth_state: Arc<AuthState> =
-      Arc::new(AuthState::new(&db_pool, jwt_manager, &config.aws, &config.oauth2).await);
+    let auth_state: Arc<AuthState> = Arc::new(
+      AuthState::new(
+        &db_pool,
+        jwt_manager,
+        &config.aws,
+        &config.oauth2,
+        &config.magic_link,
+        &config.saml,
+        &config.ldap,
+      )
+      .await,
+    );
     let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
     let app: Router = AuthController::configure(auth_state.clone());
     (app, auth_state, user_state)
   }
 
+  /// Signs up a user with random credentials and the given role, and returns it with its
+  /// access token, refresh token and credentials.
+  pub async fn init_user(role: UserRole) -> (UserEntity, String, String, AuthSignInRequest) {
+    let (_, auth_state, user_state) = Self::init().await;
+    let credentials = AuthSignInRequest {
+      email: rand::random::<Email>().to_string().to_lowercase(),
+      password: rand::random::<FullName>().to_string(),
+    };
+    let user: UserEntity = auth_state
+      .manager
+      .sign_up(AuthSignUpRequest {
+        email: credentials.email.clone(),
+        password: credentials.password.clone(),
+        name: Some(rand::random::<FullName>().to_string()),
+      })
+      .await
+      .unwrap();
+    if role != UserRole::User {
+      user_state
+        .manager
+        .update_role(UserUpdateRoleRequest {
+          user_id: user.id,
+          role,
+        })
+        .await
+        .unwrap();
+    }
+    let (access_token, refresh_token) = auth_state.manager.generate_tokens(user.id, role).await;
+    (user, access_token, refresh_token, credentials)
+  }
+
   pub async fn initialize_user_tokens() {
     let mut access_token_guard: MutexGuard<Option<String>> = USER_ACCESS_TOKEN.lock().unwrap();
     let mut refresh_token_guard: MutexGuard<Option<String>> = USER_REFRESH_TOKEN.lock().unwrap();
@@ -11,17 +11,25 @@ use tracing::error;
 struct ErrorResponse {
   error: String,
   code: u16,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  details: Option<String>,
 }
 
 trait ApiError {
   fn status_code(&self) -> StatusCode;
   fn error_message(&self) -> String;
 
+  /// What exactly was wrong with a request, for errors that can be fixed by the caller.
+  fn error_details(&self) -> Option<String> {
+    None
+  }
+
   fn to_response(&self) -> Response {
     let status: StatusCode = self.status_code();
     let error_response = ErrorResponse {
       error: self.error_message(),
       code: status.as_u16(),
+      details: self.error_details(),
     };
 
     (status, Json(error_response)).into_response()
@@ -31,11 +39,51 @@ trait ApiError {
 #[derive(Debug)]
 pub enum AppError {
   InvalidJwtToken,
+  InvalidClient,
+  InvalidApiKey,
+  InvalidScope,
+  InvalidMagicLink,
+  UnsupportedGrantType,
+  InvalidGrant,
+  InvalidRedirectUri,
+  InvalidAuthorizationRequest,
   PermissionDenied,
+  ImpersonationNotAllowed,
   InvalidOauth2Provider,
+  InvalidOauth2State,
+  InvalidIdToken,
+  IdentityAlreadyLinked,
+  IdentityNotFound,
+  IdentityUnlinkNotAllowed,
+  InvalidSamlResponse,
+  SamlTenantNotFound,
+  SamlTenantExisting,
+  InvalidSamlTenant,
+  InvalidScimToken,
+  InvalidScimFilter,
+  InvalidScimRequest,
   UserNotFound,
   UserExistingEmail,
   UserPasswordIncorrect,
+  UserSuspended,
+  UserBanned,
+  InvalidUserRequest,
+  InvalidPictureUrl,
+  ApiKeyNotFound,
+  ApiKeyInvalidRequest,
+  InvalidOrganizationId,
+  InvalidOrganizationRequest,
+  OrganizationNotFound,
+  OrganizationMemberNotFound,
+  OrganizationMemberExists,
+  OrganizationInvitationNotFound,
+  InvalidInvitation,
+  InvitationNotFound,
+  InvitationExists,
+  InvalidCursor,
+  InvalidPagination(String),
+  InvalidSorting(String),
+  InvalidFilter(String),
   SomethingWentWrong,
 }
 
@@ -43,11 +91,51 @@ impl ApiError for AppError {
   fn status_code(&self) -> StatusCode {
     match self {
       AppError::InvalidJwtToken => StatusCode::UNAUTHORIZED,
+      AppError::InvalidClient => StatusCode::UNAUTHORIZED,
+      AppError::InvalidApiKey => StatusCode::UNAUTHORIZED,
+      AppError::InvalidScope => StatusCode::BAD_REQUEST,
+      AppError::InvalidMagicLink => StatusCode::UNAUTHORIZED,
+      AppError::UnsupportedGrantType => StatusCode::BAD_REQUEST,
+      AppError::InvalidGrant => StatusCode::BAD_REQUEST,
+      AppError::InvalidRedirectUri => StatusCode::BAD_REQUEST,
+      AppError::InvalidAuthorizationRequest => StatusCode::BAD_REQUEST,
       AppError::PermissionDenied => StatusCode::FORBIDDEN,
+      AppError::ImpersonationNotAllowed => StatusCode::FORBIDDEN,
       AppError::InvalidOauth2Provider => StatusCode::BAD_REQUEST,
+      AppError::InvalidOauth2State => StatusCode::BAD_REQUEST,
+      AppError::InvalidIdToken => StatusCode::UNAUTHORIZED,
+      AppError::IdentityAlreadyLinked => StatusCode::CONFLICT,
+      AppError::IdentityNotFound => StatusCode::NOT_FOUND,
+      AppError::IdentityUnlinkNotAllowed => StatusCode::BAD_REQUEST,
+      AppError::InvalidSamlResponse => StatusCode::UNAUTHORIZED,
+      AppError::SamlTenantNotFound => StatusCode::NOT_FOUND,
+      AppError::SamlTenantExisting => StatusCode::CONFLICT,
+      AppError::InvalidSamlTenant => StatusCode::BAD_REQUEST,
+      AppError::InvalidScimToken => StatusCode::UNAUTHORIZED,
+      AppError::InvalidScimFilter => StatusCode::BAD_REQUEST,
+      AppError::InvalidScimRequest => StatusCode::BAD_REQUEST,
       AppError::UserNotFound => StatusCode::NOT_FOUND,
       AppError::UserExistingEmail => StatusCode::CONFLICT,
       AppError::UserPasswordIncorrect => StatusCode::UNAUTHORIZED,
+      AppError::UserSuspended => StatusCode::FORBIDDEN,
+      AppError::UserBanned => StatusCode::FORBIDDEN,
+      AppError::InvalidUserRequest => StatusCode::BAD_REQUEST,
+      AppError::InvalidPictureUrl => StatusCode::BAD_REQUEST,
+      AppError::ApiKeyNotFound => StatusCode::NOT_FOUND,
+      AppError::ApiKeyInvalidRequest => StatusCode::BAD_REQUEST,
+      AppError::InvalidOrganizationId => StatusCode::BAD_REQUEST,
+      AppError::InvalidOrganizationRequest => StatusCode::BAD_REQUEST,
+      AppError::OrganizationNotFound => StatusCode::NOT_FOUND,
+      AppError::OrganizationMemberNotFound => StatusCode::NOT_FOUND,
+      AppError::OrganizationMemberExists => StatusCode::CONFLICT,
+      AppError::OrganizationInvitationNotFound => StatusCode::NOT_FOUND,
+      AppError::InvalidInvitation => StatusCode::BAD_REQUEST,
+      AppError::InvitationNotFound => StatusCode::NOT_FOUND,
+      AppError::InvitationExists => StatusCode::CONFLICT,
+      AppError::InvalidCursor => StatusCode::BAD_REQUEST,
+      AppError::InvalidPagination(_) => StatusCode::BAD_REQUEST,
+      AppError::InvalidSorting(_) => StatusCode::BAD_REQUEST,
+      AppError::InvalidFilter(_) => StatusCode::BAD_REQUEST,
       AppError::SomethingWentWrong => StatusCode::INTERNAL_SERVER_ERROR,
     }
   }
@@ -55,15 +143,64 @@ impl ApiError for AppError {
   fn error_message(&self) -> String {
     match self {
       AppError::InvalidJwtToken => "invalid jwt token",
+      AppError::InvalidClient => "invalid client",
+      AppError::InvalidApiKey => "invalid api key",
+      AppError::InvalidScope => "invalid scope",
+      AppError::InvalidMagicLink => "invalid magic link",
+      AppError::UnsupportedGrantType => "unsupported grant type",
+      AppError::InvalidGrant => "invalid grant",
+      AppError::InvalidRedirectUri => "invalid redirect uri",
+      AppError::InvalidAuthorizationRequest => "invalid authorization request",
       AppError::PermissionDenied => "permission denied",
+      AppError::ImpersonationNotAllowed => "impersonation not allowed",
       AppError::InvalidOauth2Provider => "invalid oauth2 provider",
+      AppError::InvalidOauth2State => "invalid oauth2 state",
+      AppError::InvalidIdToken => "invalid id token",
+      AppError::IdentityAlreadyLinked => "identity already linked",
+      AppError::IdentityNotFound => "identity not found",
+      AppError::IdentityUnlinkNotAllowed => "cannot unlink the last sign-in method",
+      AppError::InvalidSamlResponse => "invalid saml response",
+      AppError::SamlTenantNotFound => "saml tenant not found",
+      AppError::SamlTenantExisting => "existing saml tenant",
+      AppError::InvalidSamlTenant => "invalid saml tenant",
+      AppError::InvalidScimToken => "invalid scim token",
+      AppError::InvalidScimFilter => "invalid scim filter",
+      AppError::InvalidScimRequest => "invalid scim request",
       AppError::UserNotFound => "user not found",
       AppError::UserExistingEmail => "existing email",
       AppError::UserPasswordIncorrect => "incorrect password",
+      AppError::UserSuspended => "user suspended",
+      AppError::UserBanned => "user banned",
+      AppError::InvalidUserRequest => "invalid user request",
+      AppError::InvalidPictureUrl => "invalid picture url",
+      AppError::ApiKeyNotFound => "api key not found",
+      AppError::ApiKeyInvalidRequest => "invalid api key request",
+      AppError::InvalidOrganizationId => "invalid organization id",
+      AppError::InvalidOrganizationRequest => "invalid organization request",
+      AppError::OrganizationNotFound => "organization not found",
+      AppError::OrganizationMemberNotFound => "organization member not found",
+      AppError::OrganizationMemberExists => "existing organization member",
+      AppError::OrganizationInvitationNotFound => "organization invitation not found",
+      AppError::InvalidInvitation => "invalid invitation",
+      AppError::InvitationNotFound => "invitation not found",
+      AppError::InvitationExists => "existing invitation",
+      AppError::InvalidCursor => "invalid cursor",
+      AppError::InvalidPagination(_) => "invalid pagination parameters",
+      AppError::InvalidSorting(_) => "invalid sorting parameters",
+      AppError::InvalidFilter(_) => "invalid filter parameters",
       AppError::SomethingWentWrong => "something went wrong",
     }
     .to_string()
   }
+
+  fn error_details(&self) -> Option<String> {
+    match self {
+      AppError::InvalidPagination(details)
+      | AppError::InvalidSorting(details)
+      | AppError::InvalidFilter(details) => Some(details.clone()),
+      _ => None,
+    }
+  }
 }
 
 impl IntoResponse for AppError {
@@ -111,6 +248,14 @@ mod tests {
       AppError::InvalidJwtToken.status_code(),
       StatusCode::UNAUTHORIZED
     );
+    assert_eq!(
+      AppError::InvalidClient.status_code(),
+      StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+      AppError::InvalidApiKey.status_code(),
+      StatusCode::UNAUTHORIZED
+    );
     assert_eq!(AppError::UserNotFound.status_code(), StatusCode::NOT_FOUND);
     assert_eq!(
       AppError::UserExistingEmail.status_code(),
@@ -132,6 +277,8 @@ mod tests {
       AppError::InvalidJwtToken.error_message(),
       "invalid jwt token"
     );
+    assert_eq!(AppError::InvalidClient.error_message(), "invalid client");
+    assert_eq!(AppError::InvalidApiKey.error_message(), "invalid api key");
     assert_eq!(AppError::UserNotFound.error_message(), "user not found");
     assert_eq!(
       AppError::UserExistingEmail.error_message(),
@@ -0,0 +1,52 @@
+use crate::core::error::error::AppError;
+use axum::extract::rejection::QueryRejection;
+use axum::{
+  async_trait,
+  extract::{FromRequestParts, Query},
+  http::request::Parts,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Query parameters owned by `Pagination` and `Sorting`, which filters leave to them.
+const RESERVED_PARAMS: [&str; 6] = [
+  "page",
+  "per_page",
+  "cursor",
+  "sort",
+  "order_by",
+  "order_direction",
+];
+
+/// Typed filters read from the query string. `T` denies unknown fields, so a misspelled filter
+/// is rejected instead of silently matching every row.
+pub struct Filter<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for Filter<T>
+where
+  S: Send + Sync,
+  T: DeserializeOwned,
+{
+  type Rejection = AppError;
+
+  async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    let query: Result<Query<HashMap<String, String>>, QueryRejection> =
+      Query::<HashMap<String, String>>::from_request_parts(parts, _state).await;
+
+    match query {
+      Ok(Query(params)) => {
+        let filters: Map<String, Value> = params
+          .into_iter()
+          .filter(|(key, _)| !RESERVED_PARAMS.contains(&key.as_str()))
+          .map(|(key, value)| (key, Value::String(value)))
+          .collect();
+        serde_json::from_value(Value::Object(filters))
+          .map(Filter)
+          .map_err(|e| AppError::InvalidFilter(e.to_string()))
+      }
+      Err(rejection) => Err(AppError::InvalidFilter(rejection.body_text())),
+    }
+  }
+}
@@ -1,2 +1,3 @@
+pub(crate) mod filter;
 pub(crate) mod pagination;
 pub(crate) mod sorting;
@@ -1,46 +1,111 @@
+use crate::core::error::error::AppError;
+use crate::core::security::jwt_manager::JwtManager;
+use crate::launcher::APP_CONFIG;
+use crate::resources::config::PaginationConfig;
 use axum::extract::rejection::QueryRejection;
 use axum::{
   async_trait,
   extract::{FromRequestParts, Query},
   http::request::Parts,
-  http::StatusCode,
 };
-use serde::Deserialize;
+use jsonwebtoken::get_current_timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-#[derive(Deserialize)]
+/// Either page-numbered (`page`) or keyset (`cursor`) pagination; a request can't use both.
+/// `per_page` defaults to and is capped by the pagination settings of [`AppConfig`].
+///
+/// [`AppConfig`]: crate::resources::config::AppConfig
+#[derive(Clone)]
 pub struct Pagination {
   pub page: Option<usize>,
   pub per_page: Option<usize>,
+  pub cursor: Option<Cursor>,
+}
+
+#[derive(Deserialize)]
+struct PaginationQuery {
+  page: Option<usize>,
+  per_page: Option<usize>,
+  cursor: Option<String>,
+}
+
+/// Position after the last row of a page: its sort values and id, under the sorting that
+/// produced it. Clients get it as an opaque token signed like the other one-off tokens, so a
+/// forged or edited cursor is rejected instead of reading from an arbitrary position.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cursor {
+  pub sort: String,
+  pub values: Vec<Option<String>>,
+  pub id: Uuid,
+  exp: usize,
 }
 
 const DEFAULT_PAGE: usize = 1;
-const DEFAULT_PER_PAGE: usize = 10;
+
+impl Cursor {
+  const EXPIRATION_SECONDS: u64 = 24 * 60 * 60;
+
+  pub fn new(sort: String, values: Vec<Option<String>>, id: Uuid) -> Self {
+    Cursor {
+      sort,
+      values,
+      id,
+      exp: (get_current_timestamp() + Self::EXPIRATION_SECONDS) as usize,
+    }
+  }
+
+  pub fn encode(&self) -> String {
+    JwtManager::new(APP_CONFIG.jwt.clone()).sign(self)
+  }
+
+  pub fn decode(token: &str) -> Option<Self> {
+    JwtManager::new(APP_CONFIG.jwt.clone()).verify::<Cursor>(token)
+  }
+}
 
 #[async_trait]
 impl<S> FromRequestParts<S> for Pagination
 where
   S: Send + Sync,
 {
-  type Rejection = (StatusCode, String);
+  type Rejection = AppError;
 
   async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-    let query: Result<Query<Pagination>, QueryRejection> =
-      Query::<Pagination>::from_request_parts(parts, _state).await;
+    let query: Result<Query<PaginationQuery>, QueryRejection> =
+      Query::<PaginationQuery>::from_request_parts(parts, _state).await;
 
     match query {
       Ok(Query(pagination)) => {
-        let page: usize = pagination.page.unwrap_or(DEFAULT_PAGE).max(1);
-        let per_page: usize = pagination.per_page.unwrap_or(DEFAULT_PER_PAGE).max(0);
+        let config: PaginationConfig = APP_CONFIG.pagination.clone();
+        let per_page: usize = pagination.per_page.unwrap_or(config.default_per_page);
+        if !(1..=config.max_per_page).contains(&per_page) {
+          return Err(AppError::InvalidPagination(format!(
+            "`per_page` must be between 1 and {}",
+            config.max_per_page
+          )));
+        }
 
-        Ok(Pagination {
-          page: Some(page),
-          per_page: Some(per_page),
-        })
+        match (pagination.page, pagination.cursor) {
+          (Some(_), Some(_)) => Err(AppError::InvalidPagination(
+            "`page` can't be combined with `cursor`".to_string(),
+          )),
+          (Some(0), None) => Err(AppError::InvalidPagination(
+            "`page` must be at least 1".to_string(),
+          )),
+          (_, Some(cursor)) => Ok(Pagination {
+            page: None,
+            per_page: Some(per_page),
+            cursor: Some(Cursor::decode(&cursor).ok_or(AppError::InvalidCursor)?),
+          }),
+          (page, None) => Ok(Pagination {
+            page: Some(page.unwrap_or(DEFAULT_PAGE)),
+            per_page: Some(per_page),
+            cursor: None,
+          }),
+        }
       }
-      Err(_) => Err((
-        StatusCode::BAD_REQUEST,
-        "Invalid pagination parameters".to_string(),
-      )),
+      Err(rejection) => Err(AppError::InvalidPagination(rejection.body_text())),
     }
   }
 }
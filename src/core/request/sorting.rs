@@ -1,59 +1,150 @@
+use crate::api::repository::sorting::Sortable;
+use crate::core::error::error::AppError;
 use axum::extract::rejection::QueryRejection;
 use axum::{
   async_trait,
   extract::{FromRequestParts, Query},
   http::request::Parts,
-  http::StatusCode,
 };
+use sea_query::Order;
 use serde::Deserialize;
 
+/// Sort order of a list over the sortable fields of `T`. `sort=-created_at,name` sorts by
+/// `created_at` descending, then by `name`; the older `order_by` and `order_direction` pair
+/// still picks a single field. Unknown fields are rejected with the list of allowed ones.
+#[derive(Clone)]
+pub struct Sorting<T: Sortable> {
+  pub fields: Vec<SortField<T>>,
+}
+
+#[derive(Clone)]
+pub struct SortField<T> {
+  pub name: &'static str,
+  pub column: T,
+  pub order: Order,
+}
+
 #[derive(Deserialize)]
-pub struct Sorting {
-  pub order_by: Option<String>,
-  pub order_direction: Option<String>,
+struct SortingQuery {
+  sort: Option<String>,
+  order_by: Option<String>,
+  order_direction: Option<String>,
 }
 
-const DEFAULT_ORDER_BY: &str = "created_at";
-const DEFAULT_ORDER_DIRECTION: &str = "asc";
+impl<T: Sortable> Sorting<T> {
+  /// The sorting written back as a `sort` parameter, which cursors carry to stay tied to it.
+  pub fn key(&self) -> String {
+    self
+      .fields
+      .iter()
+      .map(|field| match field.order {
+        Order::Desc => format!("-{}", field.name),
+        _ => field.name.to_string(),
+      })
+      .collect::<Vec<String>>()
+      .join(",")
+  }
+
+  pub fn tie_breaker_order(&self) -> Order {
+    self
+      .fields
+      .last()
+      .map_or(Order::Asc, |field| field.order.clone())
+  }
+
+  fn field(name: &str, order: Order) -> Result<SortField<T>, AppError> {
+    let name: String = name.to_lowercase();
+    T::SORTABLE_FIELDS
+      .iter()
+      .find(|(field, _)| *field == name)
+      .map(|(field, column)| SortField {
+        name: field,
+        column: *column,
+        order,
+      })
+      .ok_or_else(|| {
+        AppError::InvalidSorting(format!(
+          "unknown sort field `{}`, allowed fields: {}",
+          name,
+          T::sortable_names().join(", ")
+        ))
+      })
+  }
+
+  fn parse(query: SortingQuery) -> Result<Self, AppError> {
+    let fields: Vec<SortField<T>> = match query {
+      SortingQuery {
+        sort: Some(_),
+        order_by: Some(_),
+        ..
+      }
+      | SortingQuery {
+        sort: Some(_),
+        order_direction: Some(_),
+        ..
+      } => {
+        return Err(AppError::InvalidSorting(
+          "`sort` can't be combined with `order_by` or `order_direction`".to_string(),
+        ))
+      }
+      SortingQuery {
+        sort: Some(sort), ..
+      } => {
+        let mut fields: Vec<SortField<T>> = Vec::new();
+        for field in sort.split(',').map(str::trim) {
+          let field: SortField<T> = match field.strip_prefix('-') {
+            Some(name) => Self::field(name, Order::Desc)?,
+            None => Self::field(field, Order::Asc)?,
+          };
+          if fields.iter().any(|sorted| sorted.name == field.name) {
+            return Err(AppError::InvalidSorting(format!(
+              "sort field `{}` is repeated",
+              field.name
+            )));
+          }
+          fields.push(field);
+        }
+        fields
+      }
+      SortingQuery {
+        order_by,
+        order_direction,
+        ..
+      } => {
+        let order: Order = match order_direction.as_deref().map(str::to_lowercase).as_deref() {
+          None | Some("asc") => Order::Asc,
+          Some("desc") => Order::Desc,
+          Some(_) => {
+            return Err(AppError::InvalidSorting(
+              "`order_direction` must be `asc` or `desc`".to_string(),
+            ))
+          }
+        };
+        vec![Self::field(
+          order_by.as_deref().unwrap_or(T::DEFAULT_SORT),
+          order,
+        )?]
+      }
+    };
+    Ok(Sorting { fields })
+  }
+}
 
 #[async_trait]
-impl<S> FromRequestParts<S> for Sorting
+impl<S, T> FromRequestParts<S> for Sorting<T>
 where
   S: Send + Sync,
+  T: Sortable,
 {
-  type Rejection = (StatusCode, String);
+  type Rejection = AppError;
 
   async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-    let query: Result<Query<Sorting>, QueryRejection> =
-      Query::<Sorting>::from_request_parts(parts, _state).await;
+    let query: Result<Query<SortingQuery>, QueryRejection> =
+      Query::<SortingQuery>::from_request_parts(parts, _state).await;
 
     match query {
-      Ok(query) => {
-        let order_by: String = query
-          .order_by
-          .clone()
-          .unwrap_or_else(|| String::from(DEFAULT_ORDER_BY))
-          .to_lowercase();
-        let order_direction: String = query
-          .order_direction
-          .clone()
-          .unwrap_or_else(|| String::from(DEFAULT_ORDER_DIRECTION))
-          .to_lowercase();
-
-        let order_direction: String = match order_direction.as_str() {
-          "asc" | "desc" => order_direction,
-          _ => String::from(DEFAULT_ORDER_DIRECTION),
-        };
-
-        Ok(Sorting {
-          order_by: Some(order_by),
-          order_direction: Some(order_direction),
-        })
-      }
-      Err(_) => Err((
-        StatusCode::BAD_REQUEST,
-        "Invalid pagination parameters".to_string(),
-      )),
+      Ok(Query(query)) => Self::parse(query),
+      Err(rejection) => Err(AppError::InvalidSorting(rejection.body_text())),
     }
   }
 }
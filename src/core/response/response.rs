@@ -1,5 +1,8 @@
-use axum::http::StatusCode;
+use crate::api::repository::pagination::{Page, PaginationHelper};
+use crate::core::request::pagination::Pagination;
+use axum::http::{StatusCode, Uri};
 use axum::{response::IntoResponse, Json};
+use http::header::LINK;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -24,10 +27,104 @@ impl<T: Serialize> IntoResponse for CreatedResponse<T> {
   }
 }
 
+/// One page of a list with its position, plus RFC 8288 `Link` headers to the first, previous,
+/// next and last pages. The links keep the other query parameters of the request, like filters.
+/// Keyset pages have no page number or total; they link to the first page and, through
+/// `next_cursor`, to the next one.
+#[derive(Serialize)]
+pub(crate) struct PaginatedResponse<T: Serialize> {
+  pub(crate) data: Vec<T>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) page: Option<usize>,
+  pub(crate) per_page: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) total: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) total_pages: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) next_cursor: Option<String>,
+  #[serde(skip)]
+  uri: Uri,
+}
+
+impl<T: Serialize> PaginatedResponse<T> {
+  pub(crate) fn new(page: Page<T>, pagination: Pagination, uri: Uri) -> Self {
+    let helper: PaginationHelper = PaginationHelper::new(pagination.page, pagination.per_page);
+    let total_pages: Option<u64> = page.total.map(|total| match helper.per_page {
+      0 => 0,
+      per_page => total.div_ceil(per_page as u64),
+    });
+    Self {
+      data: page.items,
+      page: pagination.cursor.is_none().then_some(helper.page),
+      per_page: helper.per_page,
+      total: page.total,
+      total_pages,
+      next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+      uri,
+    }
+  }
+
+  fn link(&self, (key, value): (&str, String), rel: &str) -> String {
+    let mut params: Vec<(String, String)> =
+      serde_urlencoded::from_str(self.uri.query().unwrap_or_default()).unwrap_or_default();
+    params.retain(|(key, _)| !matches!(key.as_str(), "page" | "per_page" | "cursor"));
+    params.push((key.to_string(), value));
+    params.push(("per_page".to_string(), self.per_page.to_string()));
+    format!(
+      "<{}?{}>; rel=\"{}\"",
+      self.uri.path(),
+      serde_urlencoded::to_string(params).unwrap_or_default(),
+      rel
+    )
+  }
+
+  fn page_link(&self, page: u64, rel: &str) -> String {
+    self.link(("page", page.to_string()), rel)
+  }
+
+  fn links(&self) -> Vec<String> {
+    let (Some(page), Some(total_pages)) = (self.page, self.total_pages) else {
+      let mut links: Vec<String> = vec![self.page_link(1, "first")];
+      if let Some(cursor) = &self.next_cursor {
+        links.push(self.link(("cursor", cursor.clone()), "next"));
+      }
+      return links;
+    };
+    let page: u64 = page as u64;
+    let mut links: Vec<String> = Vec::new();
+    if total_pages > 0 {
+      links.push(self.page_link(1, "first"));
+    }
+    if page > 1 && page <= total_pages + 1 {
+      links.push(self.page_link(page - 1, "prev"));
+    }
+    if page < total_pages {
+      links.push(self.page_link(page + 1, "next"));
+    }
+    if total_pages > 0 {
+      links.push(self.page_link(total_pages, "last"));
+    }
+    links
+  }
+}
+
+impl<T: Serialize> IntoResponse for PaginatedResponse<T> {
+  fn into_response(self) -> axum::response::Response {
+    let links: String = self.links().join(", ");
+    match links.is_empty() {
+      true => (StatusCode::OK, Json(self)).into_response(),
+      false => (StatusCode::OK, [(LINK, links)], Json(self)).into_response(),
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::core::request::pagination::Cursor;
   use axum::response::Response;
+  use uuid::Uuid;
 
   #[tokio::test]
   async fn test_success_response() {
@@ -50,4 +147,86 @@ mod tests {
 
     assert_eq!(response.status(), StatusCode::CREATED);
   }
+
+  #[tokio::test]
+  async fn test_paginated_response() {
+    let response_data = PaginatedResponse::new(
+      Page {
+        items: vec!["test data".to_string()],
+        total: Some(25),
+        next_cursor: None,
+      },
+      Pagination {
+        page: Some(2),
+        per_page: Some(10),
+        cursor: None,
+      },
+      Uri::from_static("/users?role=admin&page=2&per_page=10"),
+    );
+    assert_eq!(response_data.total_pages, Some(3));
+
+    let response: Response = response_data.into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(LINK).unwrap(),
+      "</users?role=admin&page=1&per_page=10>; rel=\"first\", \
+       </users?role=admin&page=1&per_page=10>; rel=\"prev\", \
+       </users?role=admin&page=3&per_page=10>; rel=\"next\", \
+       </users?role=admin&page=3&per_page=10>; rel=\"last\""
+    );
+  }
+
+  #[tokio::test]
+  async fn test_paginated_response_empty() {
+    let response_data: PaginatedResponse<String> = PaginatedResponse::new(
+      Page {
+        items: vec![],
+        total: Some(0),
+        next_cursor: None,
+      },
+      Pagination {
+        page: Some(1),
+        per_page: Some(10),
+        cursor: None,
+      },
+      Uri::from_static("/users"),
+    );
+    assert_eq!(response_data.total_pages, Some(0));
+
+    let response: Response = response_data.into_response();
+    assert!(response.headers().get(LINK).is_none());
+  }
+
+  #[tokio::test]
+  async fn test_paginated_response_cursor() {
+    let cursor: Cursor = Cursor::new("-created_at".to_string(), vec![None], Uuid::new_v4());
+    let response_data = PaginatedResponse::new(
+      Page {
+        items: vec!["test data".to_string()],
+        total: None,
+        next_cursor: Some(cursor.clone()),
+      },
+      Pagination {
+        page: None,
+        per_page: Some(1),
+        cursor: Some(cursor),
+      },
+      Uri::from_static("/users?role=admin&cursor=abc&per_page=1"),
+    );
+    assert_eq!(response_data.page, None);
+    assert_eq!(response_data.total_pages, None);
+    let next_cursor: String = response_data.next_cursor.clone().unwrap();
+    assert_eq!(Cursor::decode(&next_cursor).unwrap().sort, "-created_at");
+
+    let response: Response = response_data.into_response();
+    assert_eq!(
+      response.headers().get(LINK).unwrap(),
+      format!(
+        "</users?role=admin&page=1&per_page=1>; rel=\"first\", \
+         </users?role=admin&cursor={}&per_page=1>; rel=\"next\"",
+        next_cursor
+      )
+      .as_str()
+    );
+  }
 }
@@ -1,20 +1,52 @@
+use crate::api::manager::api_keys::api_key_manager::ApiKeyManager;
+use crate::api::manager::impersonation::impersonation_manager::ImpersonationManager;
+use crate::api::manager::users::user_manager::UserManager;
+use crate::api::repository::api_keys::constant::api_key_constant::{ApiKeyScope, API_KEY_PREFIX};
+use crate::api::state::api_keys::api_key_state::ApiKeyState;
+use crate::api::state::impersonation::impersonation_state::ImpersonationState;
+use crate::api::state::scim::scim_state::ScimState;
+use crate::api::state::users::user_state::UserState;
 use crate::core::error::error::AppError;
 use crate::core::security::jwt_manager::{Claims, JwtManager, TokenType};
 use crate::launcher::APP_CONFIG;
 use crate::resources::config::AppConfig;
 use async_trait::async_trait;
-use axum::extract::FromRequestParts;
+use axum::extract::{FromRequestParts, OriginalUri};
 use axum::RequestPartsExt;
-use axum_extra::headers::authorization::Bearer;
+use axum_extra::headers::authorization::{Basic, Bearer};
 use axum_extra::headers::Authorization;
 use axum_extra::TypedHeader;
 use http::request::Parts;
+use openssl::memcmp;
+use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct AccessTokenAuth(pub Claims);
+
+impl AccessTokenAuth {
+  /// The admin behind an impersonation token, if any.
+  pub fn actor(&self) -> Option<Uuid> {
+    self.0.act.map(|act| act.sub)
+  }
+}
+
+/// Access token auth that also takes the userinfo-only tokens of the authorization code grant.
+pub struct UserInfoAuth(pub Claims);
 pub struct RefreshTokenAuth(pub Claims);
+/// Access token auth for the routes open to scripts and CI, which also takes personal API keys
+/// (`pat_...`). [`AccessTokenAuth`] only takes JWTs, so keys never reach the other routes.
+pub struct ApiKeyAuth(pub Claims);
+pub struct ClientBasicAuth {
+  pub client_id: String,
+  pub client_secret: String,
+}
+pub struct ScimBearerAuth;
 
 pub struct TokenValidator;
 
+#[derive(Clone)]
+struct ImpersonationAudited;
+
 impl TokenValidator {
   async fn extract_and_validate<S>(
     parts: &mut Parts,
@@ -23,18 +55,104 @@ impl TokenValidator {
   where
     S: Send + Sync,
   {
+    let claims: Claims = Self::extract_claims::<S>(parts, token_type).await?;
+    Self::authorize_method(parts, claims)
+  }
+
+  async fn extract_claims<S>(parts: &mut Parts, token_type: TokenType) -> Result<Claims, AppError>
+  where
+    S: Send + Sync,
+  {
+    let TypedHeader(Authorization(bearer)) = parts
+      .extract::<TypedHeader<Authorization<Bearer>>>()
+      .await
+      .map_err(|_| AppError::InvalidJwtToken)?;
+
+    Self::validate_jwt(bearer.token(), token_type)
+  }
+
+  async fn extract_and_validate_api_key(parts: &mut Parts) -> Result<Claims, AppError> {
     let TypedHeader(Authorization(bearer)) = parts
       .extract::<TypedHeader<Authorization<Bearer>>>()
       .await
       .map_err(|_| AppError::InvalidJwtToken)?;
 
+    let claims: Claims = match bearer.token().starts_with(API_KEY_PREFIX) {
+      true => Self::validate_api_key(parts, bearer.token()).await?,
+      false => Self::validate_jwt(bearer.token(), TokenType::AccessToken)?,
+    };
+    Self::authorize_method(parts, claims)
+  }
+
+  fn validate_jwt(token: &str, token_type: TokenType) -> Result<Claims, AppError> {
     let config: AppConfig = APP_CONFIG.clone();
     let jwt_manager: JwtManager = JwtManager::new(config.jwt);
 
     jwt_manager
-      .validate_jwt(bearer.token(), token_type)
+      .validate_jwt(token, token_type)
       .ok_or(AppError::InvalidJwtToken)
   }
+
+  async fn validate_api_key(parts: &Parts, token: &str) -> Result<Claims, AppError> {
+    let state: Arc<ApiKeyState> = parts
+      .extensions
+      .get::<Arc<ApiKeyState>>()
+      .cloned()
+      .ok_or(AppError::InvalidApiKey)?;
+    state.manager.authenticate(token).await
+  }
+
+  /// Rejects tokens of suspended, banned or deleted users. Without `UserState` the account
+  /// can't be checked and the request is refused.
+  async fn ensure_active(parts: &Parts, claims: &Claims) -> Result<(), AppError> {
+    let state: Arc<UserState> = parts
+      .extensions
+      .get::<Arc<UserState>>()
+      .cloned()
+      .ok_or(AppError::SomethingWentWrong)?;
+    state.manager.ensure_active(claims).await
+  }
+
+  /// Writes each request made with an impersonation token to the audit trail, once however many
+  /// extractors read the token. Without `ImpersonationState` the request can't be audited and
+  /// is refused.
+  async fn audit_impersonation(
+    parts: &mut Parts,
+    actor_id: Uuid,
+    claims: &Claims,
+  ) -> Result<(), AppError> {
+    if parts.extensions.get::<ImpersonationAudited>().is_some() {
+      return Ok(());
+    }
+    let state: Arc<ImpersonationState> = parts
+      .extensions
+      .get::<Arc<ImpersonationState>>()
+      .cloned()
+      .ok_or(AppError::SomethingWentWrong)?;
+    let path: String = match parts.extensions.get::<OriginalUri>() {
+      Some(OriginalUri(uri)) => uri.path().to_string(),
+      None => parts.uri.path().to_string(),
+    };
+    state
+      .manager
+      .audit(actor_id, claims, parts.method.as_str(), &path)
+      .await?;
+    parts.extensions.insert(ImpersonationAudited);
+    Ok(())
+  }
+
+  /// Scoped claims need `read` for safe methods and `write` for everything else.
+  fn authorize_method(parts: &Parts, claims: Claims) -> Result<Claims, AppError> {
+    let required_scope: ApiKeyScope = if parts.method.is_safe() {
+      ApiKeyScope::Read
+    } else {
+      ApiKeyScope::Write
+    };
+    if !claims.has_scope(required_scope) {
+      return Err(AppError::PermissionDenied);
+    }
+    Ok(claims)
+  }
 }
 
 #[async_trait]
@@ -47,7 +165,33 @@ where
   async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
     let claims: Claims =
       TokenValidator::extract_and_validate::<S>(parts, TokenType::AccessToken).await?;
-    Ok(AccessTokenAuth(claims))
+    TokenValidator::ensure_active(parts, &claims).await?;
+    let auth: AccessTokenAuth = AccessTokenAuth(claims);
+    if let Some(actor_id) = auth.actor() {
+      TokenValidator::audit_impersonation(parts, actor_id, &auth.0).await?;
+    }
+    Ok(auth)
+  }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for UserInfoAuth
+where
+  S: Send + Sync,
+{
+  type Rejection = AppError;
+
+  async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    let claims: Claims = TokenValidator::extract_claims::<S>(parts, TokenType::AccessToken).await?;
+    let claims: Claims = match claims.is_userinfo() {
+      true => claims,
+      false => TokenValidator::authorize_method(parts, claims)?,
+    };
+    TokenValidator::ensure_active(parts, &claims).await?;
+    if let Some(act) = claims.act {
+      TokenValidator::audit_impersonation(parts, act.sub, &claims).await?;
+    }
+    Ok(UserInfoAuth(claims))
   }
 }
 
@@ -65,6 +209,70 @@ where
   }
 }
 
+#[async_trait]
+impl<S> FromRequestParts<S> for ApiKeyAuth
+where
+  S: Send + Sync,
+{
+  type Rejection = AppError;
+
+  async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    let claims: Claims = TokenValidator::extract_and_validate_api_key(parts).await?;
+    TokenValidator::ensure_active(parts, &claims).await?;
+    if let Some(act) = claims.act {
+      TokenValidator::audit_impersonation(parts, act.sub, &claims).await?;
+    }
+    Ok(ApiKeyAuth(claims))
+  }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientBasicAuth
+where
+  S: Send + Sync,
+{
+  type Rejection = AppError;
+
+  async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    let TypedHeader(Authorization(basic)) = parts
+      .extract::<TypedHeader<Authorization<Basic>>>()
+      .await
+      .map_err(|_| AppError::InvalidClient)?;
+
+    Ok(ClientBasicAuth {
+      client_id: basic.username().to_string(),
+      client_secret: basic.password().to_string(),
+    })
+  }
+}
+
+#[async_trait]
+impl FromRequestParts<Arc<ScimState>> for ScimBearerAuth {
+  type Rejection = AppError;
+
+  /// Provisioning clients share the single `scim.secret`; an unset secret disables the API.
+  async fn from_request_parts(
+    parts: &mut Parts,
+    state: &Arc<ScimState>,
+  ) -> Result<Self, Self::Rejection> {
+    let TypedHeader(Authorization(bearer)) = parts
+      .extract::<TypedHeader<Authorization<Bearer>>>()
+      .await
+      .map_err(|_| AppError::InvalidScimToken)?;
+
+    let secret: &[u8] = state
+      .config
+      .secret()
+      .ok_or(AppError::InvalidScimToken)?
+      .as_bytes();
+    let token: &[u8] = bearer.token().as_bytes();
+    if secret.len() != token.len() || !memcmp::eq(secret, token) {
+      return Err(AppError::InvalidScimToken);
+    }
+    Ok(ScimBearerAuth)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
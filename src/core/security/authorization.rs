@@ -1,44 +1,442 @@
+use crate::api::manager::roles::role_manager::RoleManager;
+use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
 use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::api::state::roles::role_state::RoleState;
 use crate::core::error::error::AppError;
-use crate::core::security::authentication::AccessTokenAuth;
+use crate::core::security::authentication::ApiKeyAuth;
+use crate::core::security::jwt_manager::Claims;
 use axum::body::Body;
-use axum::extract::Request;
-use axum::{middleware::Next, response::IntoResponse};
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::middleware::{from_fn_with_state, FromFnLayer};
+use axum::response::Response;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+type AuthorizationMiddleware<T> = fn(
+  State<T>,
+  Result<ApiKeyAuth, AppError>,
+  Request<Body>,
+  Next,
+) -> BoxFuture<'static, Result<Response, AppError>>;
+
+/// Route layer checking the access token or API key against a requirement of type `T`. Whether
+/// API keys reach the handler is still up to its own extractor.
+pub type AuthorizationLayer<T> = FromFnLayer<
+  AuthorizationMiddleware<T>,
+  T,
+  (State<T>, Result<ApiKeyAuth, AppError>, Request<Body>),
+>;
 
 pub struct Authorization;
 
 impl Authorization {
-  pub async fn admin(
-    access_token_auth: Result<AccessTokenAuth, AppError>,
-    req: Request<Body>,
-    next: Next,
-  ) -> impl IntoResponse {
-    Self::handle_role_based_access(access_token_auth, req, next, &[UserRole::Admin]).await
+  /// Lets the request through when the caller's role is `role` or above it in
+  /// [`UserRole::HIERARCHY`], e.g. `.layer(Authorization::require_min_role(UserRole::Moderator))`.
+  /// Scoped credentials need the `admin` scope for anything above `user`.
+  pub fn require_min_role(role: UserRole) -> AuthorizationLayer<UserRole> {
+    let middleware: AuthorizationMiddleware<UserRole> =
+      |State(role), access_token_auth, req, next| {
+        Box::pin(Self::handle_role_based_access(
+          access_token_auth,
+          req,
+          next,
+          role,
+        ))
+      };
+    from_fn_with_state(role, middleware)
   }
 
-  pub async fn user(
-    access_token_auth: Result<AccessTokenAuth, AppError>,
+  /// Lets the request through when the caller's role, or a role it inherits from, is granted
+  /// `permission` in `role_permissions`, e.g.
+  /// `.layer(Authorization::require_permission("users:write"))`.
+  /// Scoped credentials also need the `admin` scope.
+  pub fn require_permission(permission: &'static str) -> AuthorizationLayer<&'static str> {
+    let middleware: AuthorizationMiddleware<&'static str> =
+      |State(permission), access_token_auth, req, next| {
+        Box::pin(Self::handle_permission_based_access(
+          access_token_auth,
+          req,
+          next,
+          permission,
+        ))
+      };
+    from_fn_with_state(permission, middleware)
+  }
+
+  fn claims(access_token_auth: Result<ApiKeyAuth, AppError>) -> Result<Claims, AppError> {
+    match access_token_auth {
+      Ok(ApiKeyAuth(claims)) => Ok(claims),
+      Err(
+        err @ (AppError::InvalidApiKey
+        | AppError::PermissionDenied
+        | AppError::UserSuspended
+        | AppError::UserBanned),
+      ) => Err(err),
+      Err(_) => Err(AppError::InvalidJwtToken),
+    }
+  }
+
+  async fn handle_role_based_access(
+    access_token_auth: Result<ApiKeyAuth, AppError>,
     req: Request<Body>,
     next: Next,
-  ) -> impl IntoResponse {
-    Self::handle_role_based_access(access_token_auth, req, next, &[UserRole::User]).await
+    required_role: UserRole,
+  ) -> Result<Response, AppError> {
+    let claims: Claims = Self::claims(access_token_auth)?;
+    let required_scope: Option<ApiKeyScope> =
+      (required_role != UserRole::User).then_some(ApiKeyScope::Admin);
+    if claims.role.includes(required_role)
+      && required_scope.is_none_or(|scope| claims.has_scope(scope))
+    {
+      Ok(next.run(req).await)
+    } else {
+      Err(AppError::PermissionDenied)
+    }
   }
 
-  async fn handle_role_based_access(
-    access_token_auth: Result<AccessTokenAuth, AppError>,
+  async fn handle_permission_based_access(
+    access_token_auth: Result<ApiKeyAuth, AppError>,
     req: Request<Body>,
     next: Next,
-    required_roles: &[UserRole],
-  ) -> impl IntoResponse {
-    match access_token_auth {
-      Ok(auth) => {
-        if required_roles.contains(&auth.0.role) {
-          Ok(next.run(req).await)
-        } else {
-          Err(AppError::PermissionDenied)
+    permission: &'static str,
+  ) -> Result<Response, AppError> {
+    let claims: Claims = Self::claims(access_token_auth)?;
+    if !claims.has_scope(ApiKeyScope::Admin) {
+      return Err(AppError::PermissionDenied);
+    }
+
+    let state: Arc<RoleState> = req
+      .extensions()
+      .get::<Arc<RoleState>>()
+      .cloned()
+      .ok_or(AppError::SomethingWentWrong)?;
+    match state
+      .manager
+      .has_permission(claims.role, permission)
+      .await?
+    {
+      true => Ok(next.run(req).await),
+      false => Err(AppError::PermissionDenied),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::auth::request::auth_request::SamlTenantCreateRequest;
+  use crate::api::client::impersonation::impersonation_client::ImpersonationClient;
+  use crate::api::client::invitations::invitation_client::InvitationClient;
+  use crate::api::client::invitations::request::invitation_request::InvitationCreateRequest;
+  use crate::api::client::oauth::oauth_client::OAuthClient;
+  use crate::api::client::oauth::request::oauth_request::OAuthClientCreateRequest;
+  use crate::api::client::saml::saml_client::SamlClient;
+  use crate::api::client::users::request::user_request::{
+    UserUpdateRequest, UserUpdateRoleRequest,
+  };
+  use crate::api::client::users::user_client::UserClient;
+  use crate::api::controller::impersonation::impersonation_controller::ImpersonationController;
+  use crate::api::controller::invitations::invitation_controller::InvitationController;
+  use crate::api::controller::oauth::oauth_controller::OAuthController;
+  use crate::api::controller::saml::saml_controller::SamlController;
+  use crate::api::controller::users::user_controller::UserController;
+  use crate::api::state::auth::auth_state::AuthState;
+  use crate::api::state::impersonation::impersonation_state::ImpersonationState;
+  use crate::api::state::invitations::invitation_state::InvitationState;
+  use crate::api::state::oauth::oauth_state::OAuthState;
+  use crate::api::state::users::user_state::UserState;
+  use crate::api::test::saml_stub::SamlIdp;
+  use crate::api::test::test_context::{AuthSetup, TestClient};
+  use crate::core::security::jwt_manager::JwtManager;
+  use crate::db::db::PostgresDatabase;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+  use axum::http::StatusCode;
+  use axum::routing::get;
+  use axum::{Extension, Router};
+  use faker_rand::en_us::internet::Email;
+  use faker_rand::fr_fr::names::FullName;
+  use http::header::AUTHORIZATION;
+  use serde_json::{json, Value};
+  use uuid::Uuid;
+
+  /// A guarded route, the least privileged role allowed through it and the status it answers
+  /// once through.
+  struct ProtectedRoute {
+    min_role: UserRole,
+    request: fn(Uuid, String) -> Request<Body>,
+    granted: StatusCode,
+  }
+
+  const PROTECTED_ROUTES: [ProtectedRoute; 12] = [
+    ProtectedRoute {
+      min_role: UserRole::User,
+      request: |_, token| UserClient::me(token),
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::User,
+      request: |_, token| {
+        let boundary: String = "boundary".to_string();
+        let body: String = format!(
+          "--{0}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"profile.png\"\r\n\
+           Content-Type: image/png\r\n\r\nexample content\r\n--{0}--",
+          boundary
+        );
+        UserClient::update_profile_url(body, boundary, token)
+      },
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Moderator,
+      request: |_, token| UserClient::all(token),
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Moderator,
+      request: |target, token| UserClient::get(target, token),
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |target, token| {
+        let payload = UserUpdateRequest {
+          name: Some(rand::random::<FullName>().to_string()),
+          ..Default::default()
+        };
+        UserClient::update(target, &payload, token)
+      },
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |target, token| {
+        let payload = UserUpdateRoleRequest {
+          user_id: target,
+          role: UserRole::User,
+        };
+        UserClient::update_role(&payload, token)
+      },
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |target, token| ImpersonationClient::impersonate(target, token),
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |_, token| {
+        let payload = OAuthClientCreateRequest {
+          name: rand::random::<FullName>().to_string(),
+          scopes: vec![],
+          redirect_uris: vec![],
+        };
+        OAuthClient::create_client(&payload, token)
+      },
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |_, token| InvitationClient::all(token),
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |_, token| {
+        let payload = InvitationCreateRequest {
+          email: rand::random::<Email>().to_string(),
+          role: UserRole::User,
+        };
+        InvitationClient::create(&payload, token)
+      },
+      granted: StatusCode::OK,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |target, token| InvitationClient::revoke(target, token),
+      granted: StatusCode::NOT_FOUND,
+    },
+    ProtectedRoute {
+      min_role: UserRole::Admin,
+      request: |_, token| {
+        let idp: SamlIdp = SamlIdp::generate();
+        let payload = SamlTenantCreateRequest {
+          slug: format!("tenant-{}", Uuid::new_v4()),
+          idp_entity_id: idp.entity_id.clone(),
+          idp_sso_url: format!("{}/sso", idp.entity_id),
+          idp_certificate: idp.certificate_pem(),
+          email_attribute: None,
+          name_attribute: None,
+          allowed_domains: vec!["example.com".to_string()],
+        };
+        SamlClient::create_tenant(&payload, token)
+      },
+      granted: StatusCode::OK,
+    },
+  ];
+
+  async fn init_app() -> TestClient {
+    let config: AppConfig = APP_CONFIG.clone();
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(config.postgres.clone())
+      .await
+      .unwrap();
+    let jwt_manager: JwtManager = JwtManager::new(config.jwt.clone());
+    let user_state: Arc<UserState> = Arc::new(UserState::new(&db_pool, &config.aws).await);
+    let oauth_state: Arc<OAuthState> =
+      Arc::new(OAuthState::new(&db_pool, jwt_manager.clone(), &config.oidc_provider).await);
+    let impersonation_state: Arc<ImpersonationState> =
+      Arc::new(ImpersonationState::new(&db_pool, jwt_manager.clone()).await);
+    let invitation_state: Arc<InvitationState> = Arc::new(
+      InvitationState::new(
+        &db_pool,
+        jwt_manager.clone(),
+        &config.aws,
+        &config.invitation,
+      )
+      .await,
+    );
+    let auth_state: Arc<AuthState> = Arc::new(
+      AuthState::new(
+        &db_pool,
+        jwt_manager,
+        &config.aws,
+        &config.oauth2,
+        &config.magic_link,
+        &config.saml,
+        &config.ldap,
+      )
+      .await,
+    );
+    let app: Router = Router::new()
+      .merge(UserController::configure(user_state.clone()))
+      .merge(OAuthController::configure(oauth_state))
+      .merge(ImpersonationController::configure(impersonation_state))
+      .merge(InvitationController::configure(invitation_state))
+      .merge(SamlController::configure(auth_state))
+      .layer(Extension(user_state))
+      .layer(Extension(Arc::new(RoleState::new(&db_pool).await)));
+    TestClient::new(app)
+  }
+
+  async fn init_setup(permission: &'static str) -> TestClient {
+    let db_pool: PostgresDatabase = PostgresDatabase::connect(APP_CONFIG.postgres.clone())
+      .await
+      .unwrap();
+    let role_state: Arc<RoleState> = Arc::new(RoleState::new(&db_pool).await);
+    let app: Router = Router::new()
+      .route(
+        "/guarded",
+        get(|| async { StatusCode::OK }).layer(Authorization::require_permission(permission)),
+      )
+      .layer(Extension(Arc::new(
+        UserState::new(&db_pool, &APP_CONFIG.aws).await,
+      )))
+      .layer(Extension(role_state));
+    TestClient::new(app)
+  }
+
+  fn guarded_request(access_token: String) -> Request<Body> {
+    Request::builder()
+      .method("GET")
+      .uri("/guarded")
+      .header(AUTHORIZATION, format!("Bearer {}", access_token))
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_require_permission_granted_to_role() {
+    let test_client: TestClient = init_setup("users:write").await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let response: Response = test_client
+      .get_response(guarded_request(AuthSetup::get_admin_access_token()))
+      .await;
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
+  #[tokio::test]
+  async fn test_require_permission_not_granted_to_role() {
+    let test_client: TestClient = init_setup("users:write").await;
+    AuthSetup::initialize_user_tokens().await;
+
+    let response: Response = test_client
+      .get_response(guarded_request(AuthSetup::get_user_access_token()))
+      .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let body: Value = test_client.get_body(response).await;
+    assert_eq!(
+      body,
+      json!({
+          "error": "permission denied",
+          "code": 403
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_require_permission_unknown_permission() {
+    let test_client: TestClient = init_setup("reports:export").await;
+    AuthSetup::initialize_admin_tokens().await;
+
+    let response: Response = test_client
+      .get_response(guarded_request(AuthSetup::get_admin_access_token()))
+      .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+  }
+
+  #[tokio::test]
+  async fn test_require_permission_unauthorized() {
+    let test_client: TestClient = init_setup("users:write").await;
+
+    let response: Response = test_client
+      .get_response(guarded_request("fake_token".to_string()))
+      .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+  }
+
+  #[tokio::test]
+  async fn test_every_role_on_every_protected_route() {
+    let test_client: TestClient = init_app().await;
+
+    for role in UserRole::HIERARCHY {
+      let (_, access_token, _, _) = AuthSetup::init_user(role).await;
+      for (index, route) in PROTECTED_ROUTES.iter().enumerate() {
+        let (target, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+        let response: Response = test_client
+          .get_response((route.request)(target.id, access_token.clone()))
+          .await;
+
+        match role.includes(route.min_role) {
+          true => assert_eq!(
+            response.status(),
+            route.granted,
+            "{:?} denied on route #{}",
+            role,
+            index
+          ),
+          false => assert_eq!(
+            response.status(),
+            StatusCode::FORBIDDEN,
+            "{:?} allowed on route #{}",
+            role,
+            index
+          ),
         }
       }
-      Err(_) => Err(AppError::InvalidJwtToken),
     }
   }
+
+  #[tokio::test]
+  async fn test_require_min_role_unauthorized() {
+    let test_client: TestClient = init_app().await;
+
+    let response: Response = test_client
+      .get_response(UserClient::me("fake_token".to_string()))
+      .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+  }
 }
@@ -1,11 +1,20 @@
+use crate::api::repository::api_keys::constant::api_key_constant::ApiKeyScope;
 use crate::api::repository::users::constant::user_constant::UserRole;
 use crate::resources::config::JwtConfig;
-use base64::prelude::BASE64_STANDARD;
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
 use base64::Engine;
+use jsonwebtoken::jwk::{
+  AlgorithmParameters, CommonParameters, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse, RSAKeyParameters,
+  RSAKeyType,
+};
 use jsonwebtoken::{
   decode, encode, get_current_timestamp, Algorithm, DecodingKey, EncodingKey, Header, TokenData,
   Validation,
 };
+use openssl::pkey::Public;
+use openssl::rsa::Rsa;
+use openssl::sha::sha256;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::cmp::PartialEq;
 use uuid::Uuid;
@@ -25,6 +34,80 @@ pub struct Claims {
   pub sub: Uuid,
   pub exp: usize,
   pub role: UserRole,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub scopes: Option<Vec<String>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_id: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub act: Option<Actor>,
+  /// The OAuth client a userinfo token was issued to, as in the OpenID Connect `azp` claim.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub azp: Option<String>,
+}
+
+/// The party acting on behalf of `sub`, as in the RFC 8693 `act` claim.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct Actor {
+  pub sub: Uuid,
+}
+
+impl Claims {
+  /// Scoped claims come from an API key or a service client, not a user session.
+  pub fn is_scoped(&self) -> bool {
+    self.scopes.is_some()
+  }
+
+  /// Access tokens of the authorization code grant only open the userinfo endpoint.
+  pub fn is_userinfo(&self) -> bool {
+    self.client_id.is_none() && self.azp.is_some()
+  }
+
+  pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+    match &self.scopes {
+      Some(scopes) => scopes.iter().any(|s| s == scope.as_str()),
+      None => true,
+    }
+  }
+}
+
+/// Flow a one-off signed token was issued for, so it can't be replayed in another one.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenPurpose {
+  MagicLink,
+  Invitation,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MagicLinkClaims {
+  pub jti: Uuid,
+  pub typ: TokenPurpose,
+  pub email: String,
+  pub exp: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InvitationClaims {
+  pub jti: Uuid,
+  pub typ: TokenPurpose,
+  pub email: String,
+  pub exp: usize,
+}
+
+/// Claims of an OpenID Connect id_token issued to a first-party client.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+  pub iss: String,
+  pub sub: Uuid,
+  pub aud: String,
+  pub exp: usize,
+  pub iat: usize,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub nonce: Option<String>,
+  pub email: String,
+  pub email_verified: bool,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
 }
 
 #[derive(Clone)]
@@ -33,6 +116,7 @@ pub struct JwtManager {
   public_key_base64: String,
   access_expiration_seconds: i64,
   refresh_expiration_seconds: i64,
+  impersonation_expiration_seconds: i64,
   algorithm: Algorithm,
 }
 
@@ -45,6 +129,7 @@ impl JwtManager {
       public_key_base64: config.public_key_base64,
       access_expiration_seconds: config.access_expiration_seconds,
       refresh_expiration_seconds: config.refresh_expiration_seconds,
+      impersonation_expiration_seconds: config.impersonation_expiration_seconds,
       algorithm: config.algorithm,
     }
   }
@@ -61,6 +146,91 @@ impl JwtManager {
     DecodingKey::from_rsa_pem(&Self::decode_key_base64(self.public_key_base64.clone())).unwrap()
   }
 
+  /// The public key as a JWK whose `kid` is its RFC 7638 thumbprint.
+  pub fn jwk(&self) -> Jwk {
+    let key: Rsa<Public> =
+      Rsa::public_key_from_pem(&Self::decode_key_base64(self.public_key_base64.clone())).unwrap();
+    let n: String = BASE64_URL_SAFE_NO_PAD.encode(key.n().to_vec());
+    let e: String = BASE64_URL_SAFE_NO_PAD.encode(key.e().to_vec());
+    let thumbprint: String = format!(r#"{{"e":"{}","kty":"RSA","n":"{}"}}"#, e, n);
+
+    Jwk {
+      common: CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: format!("{:?}", self.algorithm).parse::<KeyAlgorithm>().ok(),
+        key_id: Some(BASE64_URL_SAFE_NO_PAD.encode(sha256(thumbprint.as_bytes()))),
+        ..Default::default()
+      },
+      algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+        key_type: RSAKeyType::RSA,
+        n,
+        e,
+      }),
+    }
+  }
+
+  pub fn jwk_set(&self) -> JwkSet {
+    JwkSet {
+      keys: vec![self.jwk()],
+    }
+  }
+
+  pub fn algorithm(&self) -> Algorithm {
+    self.algorithm
+  }
+
+  pub fn access_expiration_seconds(&self) -> i64 {
+    self.access_expiration_seconds
+  }
+
+  fn expiration(&self, token_type: TokenType) -> usize {
+    let expiration_seconds: i64 = match token_type {
+      TokenType::AccessToken => self.access_expiration_seconds,
+      TokenType::RefreshToken => self.refresh_expiration_seconds,
+    };
+    Self::expiration_from_seconds(expiration_seconds)
+  }
+
+  fn expiration_from_seconds(expiration_seconds: i64) -> usize {
+    if expiration_seconds == -1 {
+      Self::DEFAULT_EXPIRATION
+    } else {
+      (get_current_timestamp() + expiration_seconds as u64) as usize
+    }
+  }
+
+  /// Signs arbitrary claims with the access token key, e.g. for one-off links.
+  pub fn sign<T: Serialize>(&self, claims: &T) -> String {
+    encode(
+      &Header::new(self.algorithm),
+      claims,
+      &self.load_private_key(),
+    )
+    .unwrap()
+  }
+
+  /// Verifies a token produced by [`JwtManager::sign`]; `exp` is mandatory.
+  pub fn verify<T: DeserializeOwned>(&self, token: &str) -> Option<T> {
+    match decode::<T>(
+      token,
+      &self.load_public_key(),
+      &Validation::new(self.algorithm),
+    ) {
+      Ok(data) => Some(data.claims),
+      Err(e) => {
+        tracing::warn!("Failed to verify signed token: {:?}", e);
+        None
+      }
+    }
+  }
+
+  /// id_tokens carry the `kid` of [`JwtManager::jwk`] so relying parties can pick the key.
+  pub fn generate_id_token(&self, claims: &IdTokenClaims) -> String {
+    let mut header: Header = Header::new(self.algorithm);
+    header.kid = self.jwk().common.key_id;
+    encode(&header, claims, &self.load_private_key()).unwrap()
+  }
+
   pub fn generate_jwt(
     &self,
     jti: Uuid,
@@ -68,31 +238,90 @@ impl JwtManager {
     token_type: TokenType,
     user_role: UserRole,
   ) -> String {
-    let expiration_seconds: i64 = match token_type {
-      TokenType::AccessToken => self.access_expiration_seconds,
-      TokenType::RefreshToken => self.refresh_expiration_seconds,
+    let claims = Claims {
+      jti,
+      token_type,
+      sub: user_id,
+      exp: self.expiration(token_type),
+      role: user_role,
+      scopes: None,
+      client_id: None,
+      act: None,
+      azp: None,
     };
 
-    let expiration: usize = if expiration_seconds == -1 {
-      Self::DEFAULT_EXPIRATION
-    } else {
-      (get_current_timestamp() + expiration_seconds as u64) as usize
+    self.sign(&claims)
+  }
+
+  /// Service tokens carry the OAuth client as subject and its granted scopes.
+  pub fn generate_service_jwt(
+    &self,
+    jti: Uuid,
+    client: Uuid,
+    client_id: String,
+    role: UserRole,
+    scopes: Vec<String>,
+  ) -> String {
+    let claims = Claims {
+      jti,
+      token_type: TokenType::AccessToken,
+      sub: client,
+      exp: self.expiration(TokenType::AccessToken),
+      role,
+      scopes: Some(scopes),
+      client_id: Some(client_id),
+      act: None,
+      azp: None,
     };
 
+    self.sign(&claims)
+  }
+
+  /// Userinfo tokens carry the OpenID scopes granted to a client on behalf of the user.
+  pub fn generate_userinfo_jwt(
+    &self,
+    jti: Uuid,
+    user_id: Uuid,
+    user_role: UserRole,
+    client_id: String,
+    scopes: Vec<String>,
+  ) -> String {
     let claims = Claims {
       jti,
-      token_type,
+      token_type: TokenType::AccessToken,
       sub: user_id,
-      exp: expiration,
+      exp: self.expiration(TokenType::AccessToken),
       role: user_role,
+      scopes: Some(scopes),
+      client_id: None,
+      act: None,
+      azp: Some(client_id),
     };
 
-    encode(
-      &Header::new(self.algorithm),
-      &claims,
-      &self.load_private_key(),
-    )
-    .unwrap()
+    self.sign(&claims)
+  }
+
+  /// Impersonation tokens are short-lived access tokens naming the admin in `act`.
+  pub fn generate_impersonation_jwt(
+    &self,
+    jti: Uuid,
+    user_id: Uuid,
+    user_role: UserRole,
+    actor_id: Uuid,
+  ) -> (String, usize) {
+    let claims = Claims {
+      jti,
+      token_type: TokenType::AccessToken,
+      sub: user_id,
+      exp: Self::expiration_from_seconds(self.impersonation_expiration_seconds),
+      role: user_role,
+      scopes: None,
+      client_id: None,
+      act: Some(Actor { sub: actor_id }),
+      azp: None,
+    };
+
+    (self.sign(&claims), claims.exp)
   }
 
   pub fn validate_jwt(&self, token: &str, token_type: TokenType) -> Option<Claims> {
@@ -1,3 +1,4 @@
 pub(crate) mod authentication;
 pub(crate) mod authorization;
 pub(crate) mod jwt_manager;
+pub(crate) mod organization;
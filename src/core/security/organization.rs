@@ -0,0 +1,75 @@
+use crate::api::manager::organizations::organization_manager::OrganizationManager;
+use crate::api::repository::organizations::constant::organization_constant::OrganizationRole;
+use crate::api::repository::tenant::TenantTransaction;
+use crate::api::state::organizations::organization_state::OrganizationState;
+use crate::core::error::error::AppError;
+use crate::core::security::authentication::AccessTokenAuth;
+use async_trait::async_trait;
+use axum::extract::{FromRequestParts, Path};
+use axum::RequestPartsExt;
+use http::request::Parts;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const ORGANIZATION_ID_HEADER: &str = "X-Org-Id";
+const ORGANIZATION_ID_PARAM: &str = "org_id";
+
+/// The organization a request acts on, resolved from the `org_id` path parameter or the
+/// `X-Org-Id` header. Extraction fails unless the caller has joined that organization.
+/// The membership check opens the request's tenant transaction, which the manager then runs
+/// every query in and commits.
+pub struct OrgContext {
+  pub user_id: Uuid,
+  pub role: OrganizationRole,
+  pub transaction: TenantTransaction,
+}
+
+impl OrgContext {
+  pub fn require_role(&self, role: OrganizationRole) -> Result<(), AppError> {
+    match self.role.includes(role) {
+      true => Ok(()),
+      false => Err(AppError::PermissionDenied),
+    }
+  }
+
+  async fn organization_id(parts: &mut Parts) -> Result<Uuid, AppError> {
+    let path_param: Option<String> = parts
+      .extract::<Path<HashMap<String, String>>>()
+      .await
+      .ok()
+      .and_then(|Path(mut params)| params.remove(ORGANIZATION_ID_PARAM));
+    let value: String = match path_param {
+      Some(value) => value,
+      None => parts
+        .headers
+        .get(ORGANIZATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(AppError::InvalidOrganizationId)?
+        .to_string(),
+    };
+    Uuid::parse_str(value.trim()).map_err(|_| AppError::InvalidOrganizationId)
+  }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for OrgContext
+where
+  S: Send + Sync,
+{
+  type Rejection = AppError;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    let AccessTokenAuth(claims) = AccessTokenAuth::from_request_parts(parts, state).await?;
+    let organization_id: Uuid = Self::organization_id(parts).await?;
+    let organization_state: Arc<OrganizationState> = parts
+      .extensions
+      .get::<Arc<OrganizationState>>()
+      .cloned()
+      .ok_or(AppError::SomethingWentWrong)?;
+    organization_state
+      .manager
+      .context(claims.sub, organization_id)
+      .await
+  }
+}
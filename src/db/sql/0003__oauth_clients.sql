@@ -0,0 +1,16 @@
+CREATE TABLE oauth_clients
+(
+    id            UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    client_id     TEXT UNIQUE              NOT NULL,
+    client_secret TEXT                     NOT NULL,
+    name          TEXT                     NOT NULL,
+    created_at    TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMP WITH TIME ZONE,
+    deleted_at    TIMESTAMP WITH TIME ZONE
+);
+
+CREATE OR REPLACE TRIGGER trigger_oauth_clients_updated_at
+    BEFORE UPDATE
+    ON oauth_clients
+    FOR EACH ROW
+EXECUTE PROCEDURE update_updated_at();
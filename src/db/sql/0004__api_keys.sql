@@ -0,0 +1,15 @@
+CREATE TABLE api_keys
+(
+    id           UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id      UUID                     NOT NULL,
+    name         TEXT                     NOT NULL,
+    key_hash     TEXT                     NOT NULL,
+    scopes       TEXT[]                   NOT NULL DEFAULT '{}',
+    expires_at   TIMESTAMP WITH TIME ZONE,
+    last_used_at TIMESTAMP WITH TIME ZONE,
+    created_at   TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    revoked_at   TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS index_api_keys_by_user_id
+    ON api_keys (user_id);
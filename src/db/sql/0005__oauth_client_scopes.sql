@@ -0,0 +1,2 @@
+ALTER TABLE oauth_clients
+    ADD COLUMN scopes TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,29 @@
+CREATE TABLE impersonation_audits
+(
+    id         UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    actor_id   UUID                     NOT NULL,
+    user_id    UUID                     NOT NULL,
+    jti        UUID                     NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS index_impersonation_audits_by_actor_id
+    ON impersonation_audits (actor_id);
+
+CREATE INDEX IF NOT EXISTS index_impersonation_audits_by_user_id
+    ON impersonation_audits (user_id);
+
+CREATE TABLE impersonation_actions
+(
+    id         UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    jti        UUID                     NOT NULL,
+    actor_id   UUID                     NOT NULL,
+    user_id    UUID                     NOT NULL,
+    method     TEXT                     NOT NULL,
+    path       TEXT                     NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS index_impersonation_actions_by_jti
+    ON impersonation_actions (jti);
@@ -0,0 +1,11 @@
+CREATE TABLE magic_links
+(
+    id         UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    email      TEXT                     NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_at    TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS index_magic_links_by_email
+    ON magic_links (email);
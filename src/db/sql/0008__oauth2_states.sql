@@ -0,0 +1,8 @@
+CREATE TABLE oauth2_states
+(
+    state         TEXT                     NOT NULL PRIMARY KEY,
+    provider      OAUTH2_PROVIDER          NOT NULL,
+    pkce_verifier TEXT                     NOT NULL,
+    expires_at    TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at    TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+ALTER TYPE OAUTH2_PROVIDER ADD VALUE IF NOT EXISTS 'github';
+ALTER TYPE OAUTH2_PROVIDER ADD VALUE IF NOT EXISTS 'microsoft';
@@ -0,0 +1,4 @@
+ALTER TYPE OAUTH2_PROVIDER ADD VALUE IF NOT EXISTS 'oidc';
+
+ALTER TABLE oauth2_states
+    ADD COLUMN IF NOT EXISTS nonce TEXT;
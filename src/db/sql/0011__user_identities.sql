@@ -0,0 +1,21 @@
+CREATE TABLE user_identities
+(
+    id         UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id    UUID                     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    provider   OAUTH2_PROVIDER          NOT NULL,
+    subject    TEXT                     NOT NULL,
+    email      TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (provider, subject),
+    UNIQUE (user_id, provider)
+);
+
+INSERT INTO user_identities (user_id, provider, subject, email)
+SELECT id, auth_provider, auth_id, email
+FROM users
+WHERE auth_id IS NOT NULL
+  AND auth_provider IS NOT NULL;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS auth_id,
+    DROP COLUMN IF EXISTS auth_provider;
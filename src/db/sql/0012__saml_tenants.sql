@@ -0,0 +1,30 @@
+ALTER TYPE OAUTH2_PROVIDER ADD VALUE IF NOT EXISTS 'saml';
+
+CREATE TABLE saml_tenants
+(
+    id              UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    slug            TEXT                     NOT NULL UNIQUE,
+    idp_entity_id   TEXT                     NOT NULL,
+    idp_sso_url     TEXT                     NOT NULL,
+    idp_certificate TEXT                     NOT NULL,
+    email_attribute TEXT,
+    name_attribute  TEXT,
+    allowed_domains TEXT[]                   NOT NULL,
+    created_at      TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE OR REPLACE TRIGGER trigger_saml_tenants_updated_at
+    BEFORE UPDATE
+    ON saml_tenants
+    FOR EACH ROW
+EXECUTE PROCEDURE update_updated_at();
+
+CREATE TABLE saml_assertions
+(
+    tenant_id  UUID                     NOT NULL REFERENCES saml_tenants (id) ON DELETE CASCADE,
+    id         TEXT                     NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, id)
+);
@@ -0,0 +1,17 @@
+ALTER TABLE oauth_clients
+    ADD COLUMN redirect_uris TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE TABLE oauth_authorization_codes
+(
+    id             UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    client_id      UUID                     NOT NULL REFERENCES oauth_clients (id) ON DELETE CASCADE,
+    user_id        UUID                     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    code_hash      TEXT                     NOT NULL,
+    redirect_uri   TEXT                     NOT NULL,
+    scopes         TEXT[]                   NOT NULL DEFAULT '{}',
+    nonce          TEXT,
+    code_challenge TEXT                     NOT NULL,
+    expires_at     TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_at        TIMESTAMP WITH TIME ZONE,
+    created_at     TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,40 @@
+CREATE TABLE roles
+(
+    id          UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name        TEXT UNIQUE              NOT NULL,
+    description TEXT,
+    created_at  TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE permissions
+(
+    id          UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name        TEXT UNIQUE              NOT NULL,
+    description TEXT,
+    created_at  TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE role_permissions
+(
+    role_id       UUID NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    permission_id UUID NOT NULL REFERENCES permissions (id) ON DELETE CASCADE,
+    PRIMARY KEY (role_id, permission_id)
+);
+
+-- Seeded roles mirror the USER_ROLE enum stored on users.
+INSERT INTO roles (name, description)
+VALUES ('user', 'Signed-in user'),
+       ('admin', 'Administrator');
+
+INSERT INTO permissions (name, description)
+VALUES ('users:read', 'List users'),
+       ('users:write', 'Change user roles'),
+       ('users:impersonate', 'Impersonate non-admin users'),
+       ('oauth_clients:write', 'Register OAuth clients'),
+       ('saml_tenants:write', 'Configure SAML tenants');
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT roles.id, permissions.id
+FROM roles,
+     permissions
+WHERE roles.name = 'admin';
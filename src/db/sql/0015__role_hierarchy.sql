@@ -0,0 +1,12 @@
+ALTER TYPE USER_ROLE ADD VALUE IF NOT EXISTS 'moderator' BEFORE 'admin';
+
+INSERT INTO roles (name, description)
+VALUES ('moderator', 'Moderator, inherits the user role');
+
+-- Roles inherit the permissions of the roles below them: user < moderator < admin.
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT roles.id, permissions.id
+FROM roles,
+     permissions
+WHERE roles.name = 'moderator'
+  AND permissions.name = 'users:read';
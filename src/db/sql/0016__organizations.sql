@@ -0,0 +1,27 @@
+CREATE TYPE ORGANIZATION_ROLE AS ENUM ('member', 'admin', 'owner');
+
+CREATE TABLE organizations
+(
+    id         UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name       TEXT                     NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- A row without accepted_at is a pending invitation.
+CREATE TABLE organization_members
+(
+    organization_id UUID                     NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    user_id         UUID                     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role            ORGANIZATION_ROLE        NOT NULL,
+    invited_by      UUID                     REFERENCES users (id) ON DELETE SET NULL,
+    accepted_at     TIMESTAMP WITH TIME ZONE,
+    created_at      TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id),
+    -- Deferred so ownership can be swapped between two members in one statement.
+    CONSTRAINT organization_members_single_owner
+        EXCLUDE USING btree (organization_id WITH =) WHERE (role = 'owner')
+        DEFERRABLE INITIALLY DEFERRED
+);
+
+CREATE INDEX IF NOT EXISTS index_organization_members_by_user_id
+    ON organization_members (user_id);
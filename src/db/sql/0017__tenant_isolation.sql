@@ -0,0 +1,56 @@
+-- Tenant-scoped transactions switch to this role, which is neither a superuser nor a table
+-- owner, so the policies below always apply to it.
+DO
+$$
+    BEGIN
+        IF NOT EXISTS (SELECT FROM pg_roles WHERE rolname = 'app_tenant') THEN
+            CREATE ROLE app_tenant NOLOGIN;
+        END IF;
+    END
+$$;
+
+-- The API connects as `postgres.username`, which is not necessarily the role running the
+-- migrations, so that role is passed in as `app.api_role` (`options=-c app.api_role=...`).
+DO
+$$
+    DECLARE
+        api_role TEXT := NULLIF(current_setting('app.api_role', TRUE), '');
+    BEGIN
+        IF api_role IS NULL THEN
+            RAISE EXCEPTION 'app.api_role must name the role the API connects as';
+        END IF;
+        EXECUTE format('GRANT app_tenant TO %I', api_role);
+    END
+$$;
+
+GRANT SELECT, INSERT, UPDATE, DELETE ON organizations, organization_members TO app_tenant;
+
+-- `app.current_org` is set per transaction; when it is missing no row matches. Forcing row-level
+-- security applies the policies to the table owner as well.
+ALTER TABLE organizations
+    ENABLE ROW LEVEL SECURITY;
+ALTER TABLE organizations
+    FORCE ROW LEVEL SECURITY;
+
+CREATE POLICY organizations_tenant_isolation ON organizations
+    USING (id = NULLIF(current_setting('app.current_org', TRUE), '')::UUID);
+
+ALTER TABLE organization_members
+    ENABLE ROW LEVEL SECURITY;
+ALTER TABLE organization_members
+    FORCE ROW LEVEL SECURITY;
+
+CREATE POLICY organization_members_tenant_isolation ON organization_members
+    USING (organization_id = NULLIF(current_setting('app.current_org', TRUE), '')::UUID);
+
+-- Listing the organizations of a user spans tenants, so with `app.current_user` set instead the
+-- user can read their own memberships and the organizations they belong to.
+CREATE POLICY organization_members_member_read ON organization_members
+    FOR SELECT
+    USING (user_id = NULLIF(current_setting('app.current_user', TRUE), '')::UUID);
+
+CREATE POLICY organizations_member_read ON organizations
+    FOR SELECT
+    USING (id IN (SELECT organization_id
+                  FROM organization_members
+                  WHERE user_id = NULLIF(current_setting('app.current_user', TRUE), '')::UUID));
@@ -0,0 +1,24 @@
+CREATE TABLE user_invitations
+(
+    id          UUID                     NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+    email       TEXT                     NOT NULL,
+    role        USER_ROLE                NOT NULL,
+    invited_by  UUID                     REFERENCES users (id) ON DELETE SET NULL,
+    expires_at  TIMESTAMP WITH TIME ZONE NOT NULL,
+    accepted_at TIMESTAMP WITH TIME ZONE,
+    revoked_at  TIMESTAMP WITH TIME ZONE,
+    created_at  TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS index_user_invitations_by_email
+    ON user_invitations (email);
+
+INSERT INTO permissions (name, description)
+VALUES ('users:invite', 'Invite users by email');
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT roles.id, permissions.id
+FROM roles,
+     permissions
+WHERE roles.name = 'admin'
+  AND permissions.name = 'users:invite';
@@ -0,0 +1,7 @@
+ALTER TYPE USER_STATUS ADD VALUE IF NOT EXISTS 'suspended';
+ALTER TYPE USER_STATUS ADD VALUE IF NOT EXISTS 'banned';
+
+-- Why the account was suspended or banned, and when a suspension lifts (NULL = until lifted by an admin).
+ALTER TABLE users
+    ADD COLUMN status_reason TEXT,
+    ADD COLUMN status_until  TIMESTAMP WITH TIME ZONE;
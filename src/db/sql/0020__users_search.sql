@@ -0,0 +1,5 @@
+CREATE EXTENSION IF NOT EXISTS "pg_trgm";
+
+-- Trigram indexes back the `q` filter of the admin user list (ILIKE '%...%' on email and name).
+CREATE INDEX users_email_trgm_idx ON users USING GIN (email gin_trgm_ops);
+CREATE INDEX users_name_trgm_idx ON users USING GIN (name gin_trgm_ops);
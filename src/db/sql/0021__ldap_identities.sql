@@ -0,0 +1 @@
+ALTER TYPE OAUTH2_PROVIDER ADD VALUE IF NOT EXISTS 'ldap';
@@ -7,18 +7,21 @@ use tracing::error;
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub enum JobEventType {
   UserEvent,
+  MailEvent,
 }
 
 impl JobEventType {
   pub fn as_str(&self) -> &str {
     match self {
       JobEventType::UserEvent => "user_event",
+      JobEventType::MailEvent => "mail_event",
     }
   }
 
   pub fn from_str(s: &str) -> Result<Self, AppError> {
     match s {
       "user_event" => Ok(JobEventType::UserEvent),
+      "mail_event" => Ok(JobEventType::MailEvent),
       _ => Err(AppError::SomethingWentWrong),
     }
   }
@@ -90,6 +93,23 @@ mod tests {
     assert_eq!(data, json!({"id": 1, "verified": true}));
   }
 
+  #[tokio::test]
+  async fn test_get_job_event_with_mail_event() {
+    let message: Value = json!({
+        "event_type": "mail_event",
+        "data": {
+            "to": "user@example.com",
+            "subject": "subject",
+            "body": "body"
+        }
+    });
+    let result: Result<(JobEventType, Value), AppError> = JobEvent::get_job_event(&message).await;
+
+    assert!(result.is_ok());
+    let (job_event_type, _) = result.unwrap();
+    assert_eq!(job_event_type, JobEventType::MailEvent);
+  }
+
   #[tokio::test]
   async fn test_get_job_event_with_invalid_string_message() {
     let message: Value = json!(r#"invalid json string"#);
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobMailMessage {
+  pub(crate) to: String,
+  pub(crate) subject: String,
+  pub(crate) body: String,
+}
+
+impl JobMailMessage {
+  pub fn new(to: String, subject: String, body: String) -> Self {
+    JobMailMessage { to, subject, body }
+  }
+}
@@ -1,2 +1,3 @@
 pub(crate) mod event;
+pub(crate) mod mail;
 pub(crate) mod users;
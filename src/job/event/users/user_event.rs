@@ -5,10 +5,16 @@ use uuid::Uuid;
 pub struct JobUserMessage {
   pub(crate) id: Uuid,
   pub(crate) verified: bool,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) picture_url: Option<String>,
 }
 
 impl JobUserMessage {
-  pub fn new(id: Uuid, verified: bool) -> Self {
-    JobUserMessage { id, verified }
+  pub fn new(id: Uuid, verified: bool, picture_url: Option<String>) -> Self {
+    JobUserMessage {
+      id,
+      verified,
+      picture_url,
+    }
   }
 }
@@ -42,7 +42,7 @@ mod tests {
   fn test_job_message_with_struct_data() {
     let event_type: &str = "user_event";
     let id: Uuid = Uuid::new_v4();
-    let data = JobUserMessage { id, verified: true };
+    let data = JobUserMessage::new(id, true, None);
 
     let message: JobMessage = JobMessage::new(event_type, data);
 
@@ -0,0 +1,37 @@
+use crate::core::error::error::AppError;
+use crate::job::event::event::{JobEvent, JobEventType};
+use crate::job::event::mail::mail_event::JobMailMessage;
+use crate::service::aws::sqs::processor::processor::SQSProcessor;
+use crate::service::mail::mail::MailService;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::info;
+
+#[derive(Clone)]
+pub struct MailEventProcessor {
+  mail_service: Arc<dyn MailService>,
+}
+
+impl MailEventProcessor {
+  pub async fn new(mail_service: Arc<dyn MailService>) -> Self {
+    Self { mail_service }
+  }
+}
+
+#[async_trait]
+impl SQSProcessor for MailEventProcessor {
+  async fn process(&self, message: Value) -> Result<(), AppError> {
+    let job_event: (JobEventType, Value) = JobEvent::get_job_event(&message).await?;
+    if job_event.0 == JobEventType::MailEvent {
+      let data: JobMailMessage =
+        serde_json::from_value(job_event.1).map_err(|_| AppError::SomethingWentWrong)?;
+      info!("Sending mail to {}", data.to);
+      self
+        .mail_service
+        .send(&data.to, &data.subject, &data.body)
+        .await?;
+    }
+    Ok(())
+  }
+}
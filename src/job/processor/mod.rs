@@ -1 +1,3 @@
+pub(crate) mod mail;
+pub(crate) mod processor;
 pub(crate) mod users;
@@ -0,0 +1,37 @@
+use crate::core::error::error::AppError;
+use crate::job::event::event::{JobEvent, JobEventType};
+use crate::job::processor::mail::mail_event::MailEventProcessor;
+use crate::job::processor::users::user_event::UserEventProcessor;
+use crate::service::aws::sqs::processor::processor::SQSProcessor;
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Routes each message of a queue to the processor owning its event type.
+#[derive(Clone)]
+pub struct JobProcessor {
+  user_event_processor: UserEventProcessor,
+  mail_event_processor: MailEventProcessor,
+}
+
+impl JobProcessor {
+  pub fn new(
+    user_event_processor: UserEventProcessor,
+    mail_event_processor: MailEventProcessor,
+  ) -> Self {
+    Self {
+      user_event_processor,
+      mail_event_processor,
+    }
+  }
+}
+
+#[async_trait]
+impl SQSProcessor for JobProcessor {
+  async fn process(&self, message: Value) -> Result<(), AppError> {
+    let (job_event_type, _) = JobEvent::get_job_event(&message).await?;
+    match job_event_type {
+      JobEventType::UserEvent => self.user_event_processor.process(message).await,
+      JobEventType::MailEvent => self.mail_event_processor.process(message).await,
+    }
+  }
+}
@@ -9,7 +9,7 @@ use crate::service::aws::sqs::processor::processor::SQSProcessor;
 use async_trait::async_trait;
 use serde_json::Value;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info};
 
 #[derive(Clone)]
 pub struct UserEventProcessor {
@@ -41,7 +41,89 @@ impl SQSProcessor for UserEventProcessor {
           .await
           .expect("Could not update user status");
       }
+      if let Some(picture_url) = data.picture_url {
+        // The avatar is best effort: a broken provider URL must not block the user event.
+        if let Err(e) = self
+          .user_state
+          .manager
+          .import_profile_url(data.id, picture_url)
+          .await
+        {
+          error!("Could not import profile picture for {}: {:?}", data.id, e);
+        }
+      }
     }
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::repository::users::constant::user_constant::UserRole;
+  use crate::api::repository::users::entity::user_entity::UserEntity;
+  use crate::api::test::test_context::AuthSetup;
+  use crate::job::message::JobMessage;
+  use axum::routing::get;
+  use axum::Router;
+  use tokio::net::TcpListener;
+  use uuid::Uuid;
+
+  async fn start_picture_server() -> String {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: String = format!("http://{}", listener.local_addr().unwrap());
+    let app: Router = Router::new().route("/avatar.png", get(|| async { vec![137u8, 80, 78, 71] }));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    address
+  }
+
+  fn user_event(id: Uuid, picture_url: Option<String>) -> Value {
+    JobMessage::new(
+      JobEventType::UserEvent.as_str(),
+      JobUserMessage::new(id, true, picture_url),
+    )
+    .to_value()
+  }
+
+  #[tokio::test]
+  async fn test_process_user_event_refuses_internal_picture() {
+    let (_, _, user_state) = AuthSetup::init().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let address: String = start_picture_server().await;
+    let processor: UserEventProcessor = UserEventProcessor::new(user_state.clone()).await;
+
+    for picture_url in [
+      format!("{}/avatar.png", address),
+      format!("{}/avatar.png", address.replace("http://", "https://")),
+      "https://169.254.169.254/latest/meta-data".to_string(),
+    ] {
+      let result: Result<(), AppError> = user_state
+        .manager
+        .import_profile_url(user.id, picture_url)
+        .await;
+      assert!(matches!(result, Err(AppError::InvalidPictureUrl)));
+    }
+
+    let message: Value = user_event(user.id, Some(format!("{}/avatar.png", address)));
+    processor.process(message).await.unwrap();
+
+    let user: UserEntity = user_state.manager.me(user.id).await.unwrap();
+    assert!(user.profile_url.is_none());
+    assert_eq!(user.status.as_str(), UserStatus::Verified.as_str());
+  }
+
+  #[tokio::test]
+  async fn test_process_user_event_ignores_broken_picture() {
+    let (_, _, user_state) = AuthSetup::init().await;
+    let (user, _, _, _) = AuthSetup::init_user(UserRole::User).await;
+    let address: String = start_picture_server().await;
+    let processor: UserEventProcessor = UserEventProcessor::new(user_state.clone()).await;
+
+    let message: Value = user_event(user.id, Some(format!("{}/missing.png", address)));
+    processor.process(message).await.unwrap();
+
+    let user: UserEntity = user_state.manager.me(user.id).await.unwrap();
+    assert!(user.profile_url.is_none());
+    assert_eq!(user.status.as_str(), UserStatus::Verified.as_str());
+  }
+}
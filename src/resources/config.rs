@@ -1,3 +1,4 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
 use config::builder::DefaultState;
 use config::{Config, ConfigBuilder, ConfigError, Environment, File, FileFormat};
 use jsonwebtoken::Algorithm;
@@ -64,8 +65,16 @@ pub struct AppConfig {
   pub server: ServerConfig,
   pub postgres: PostgresConfig,
   pub jwt: JwtConfig,
+  pub magic_link: MagicLinkConfig,
+  pub invitation: InvitationConfig,
+  pub pagination: PaginationConfig,
+  pub mail: MailConfig,
   pub aws: AWSConfig,
   pub oauth2: HashMap<String, OAuth2Config>,
+  pub saml: SamlConfig,
+  pub ldap: LdapConfig,
+  pub scim: ScimConfig,
+  pub oidc_provider: OidcProviderConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -108,9 +117,38 @@ pub struct JwtConfig {
   pub public_key_base64: String,
   pub access_expiration_seconds: i64,
   pub refresh_expiration_seconds: i64,
+  pub impersonation_expiration_seconds: i64,
   pub algorithm: Algorithm,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct MagicLinkConfig {
+  pub url: String,
+  pub expiration_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InvitationConfig {
+  pub url: String,
+  pub expiration_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PaginationConfig {
+  pub default_per_page: usize,
+  pub max_per_page: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MailConfig {
+  pub host: String,
+  pub port: u16,
+  pub username: String,
+  pub password: String,
+  pub from: String,
+  pub tls: bool,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct AWSConfig {
   pub region: String,
@@ -143,13 +181,66 @@ pub struct AWSSQSJobConfig {
 pub struct OAuth2Config {
   pub client_id: String,
   pub client_secret: String,
-  pub auth_url: String,
-  pub token_url: String,
+  pub issuer: Option<String>,
+  pub auth_url: Option<String>,
+  pub token_url: Option<String>,
   pub redirect_url: String,
-  pub user_info_url: String,
+  pub user_info_url: Option<String>,
   pub scopes: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct SamlConfig {
+  pub url: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScimConfig {
+  pub url: String,
+  pub secret: String,
+}
+
+impl ScimConfig {
+  const PLACEHOLDER_SECRET: &'static str = "__REPLACE_ME__";
+
+  /// The bearer secret provisioning clients must send, or `None` while it is empty or still
+  /// the placeholder, which keeps the API disabled.
+  pub fn secret(&self) -> Option<&str> {
+    match self.secret.as_str() {
+      "" | Self::PLACEHOLDER_SECRET => None,
+      secret => Some(secret),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OidcProviderConfig {
+  pub issuer: String,
+  pub authorization_url: String,
+  pub code_expiration_seconds: i64,
+  pub id_token_expiration_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LdapConfig {
+  pub enabled: bool,
+  pub url: String,
+  pub bind_dn: String,
+  pub bind_password: String,
+  pub base_dn: String,
+  pub user_filter: String,
+  pub email_attribute: String,
+  pub name_attribute: String,
+  pub group_attribute: String,
+  pub group_roles: Vec<LdapGroupRoleConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LdapGroupRoleConfig {
+  pub group: String,
+  pub role: UserRole,
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
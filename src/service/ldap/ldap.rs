@@ -0,0 +1,151 @@
+use crate::api::repository::users::constant::user_constant::UserRole;
+use crate::core::error::error::AppError;
+use crate::resources::config::LdapConfig;
+use async_trait::async_trait;
+use ldap3::{ldap_escape, Ldap, LdapConnAsync, LdapConnSettings, LdapError, Scope, SearchEntry};
+use std::time::Duration;
+use tracing::error;
+
+const CONNECTION_TIMEOUT_SECONDS: u64 = 5;
+const INVALID_CREDENTIALS: u32 = 49;
+
+/// Directory entry that passed the bind check, with its groups already mapped to a role.
+#[derive(Debug, Clone)]
+pub struct LdapUser {
+  pub dn: String,
+  pub email: String,
+  pub name: Option<String>,
+  pub role: UserRole,
+}
+
+#[async_trait]
+pub trait LdapService: Send + Sync + 'static {
+  async fn authenticate(&self, email: &str, password: &str) -> Result<LdapUser, AppError>;
+}
+
+#[derive(Clone)]
+pub struct LdapServiceImpl {
+  config: LdapConfig,
+}
+
+impl LdapServiceImpl {
+  pub fn new(config: LdapConfig) -> Self {
+    Self { config }
+  }
+
+  async fn connect(&self) -> Result<Ldap, AppError> {
+    let settings: LdapConnSettings =
+      LdapConnSettings::new().set_conn_timeout(Duration::from_secs(CONNECTION_TIMEOUT_SECONDS));
+    let (conn, ldap) = LdapConnAsync::with_settings(settings, &self.config.url)
+      .await
+      .map_err(Self::ldap_error)?;
+    ldap3::drive!(conn);
+    Ok(ldap)
+  }
+
+  fn ldap_error(err: LdapError) -> AppError {
+    error!("LDAP request failed: {}", err);
+    AppError::SomethingWentWrong
+  }
+
+  /// Finds the entry for `email` with the service account.
+  async fn search(&self, ldap: &mut Ldap, email: &str) -> Result<Option<SearchEntry>, AppError> {
+    let bind = ldap
+      .simple_bind(&self.config.bind_dn, &self.config.bind_password)
+      .await
+      .map_err(Self::ldap_error)?;
+    if bind.rc != 0 {
+      error!("LDAP service bind failed: {}", bind);
+      return Err(AppError::SomethingWentWrong);
+    }
+
+    let filter: String = self
+      .config
+      .user_filter
+      .replace("{email}", &ldap_escape(email));
+    let attributes: Vec<&str> = vec![
+      self.config.email_attribute.as_str(),
+      self.config.name_attribute.as_str(),
+      self.config.group_attribute.as_str(),
+    ];
+    let (entries, _) = ldap
+      .search(&self.config.base_dn, Scope::Subtree, &filter, attributes)
+      .await
+      .and_then(|result| result.success())
+      .map_err(Self::ldap_error)?;
+    match entries.len() {
+      0 => Ok(None),
+      1 => Ok(entries.into_iter().next().map(SearchEntry::construct)),
+      _ => {
+        error!("LDAP filter {} matched more than one entry", filter);
+        Err(AppError::SomethingWentWrong)
+      }
+    }
+  }
+
+  fn attribute(entry: &SearchEntry, name: &str) -> Vec<String> {
+    entry
+      .attrs
+      .iter()
+      .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+      .map(|(_, values)| values.clone())
+      .unwrap_or_default()
+  }
+
+  fn role(&self, groups: &[String]) -> UserRole {
+    self
+      .config
+      .group_roles
+      .iter()
+      .find(|mapping| {
+        groups
+          .iter()
+          .any(|group| group.eq_ignore_ascii_case(&mapping.group))
+      })
+      .map(|mapping| mapping.role)
+      .unwrap_or(UserRole::User)
+  }
+}
+
+#[async_trait]
+impl LdapService for LdapServiceImpl {
+  /// Looks the user up with the service account, then binds as that entry with the given
+  /// password. An empty password is refused up front, as LDAP treats it as an anonymous bind.
+  async fn authenticate(&self, email: &str, password: &str) -> Result<LdapUser, AppError> {
+    let mut ldap: Ldap = self.connect().await?;
+    let entry: SearchEntry = self
+      .search(&mut ldap, email)
+      .await?
+      .ok_or(AppError::UserNotFound)?;
+    if password.is_empty() {
+      return Err(AppError::UserPasswordIncorrect);
+    }
+
+    let bind = ldap
+      .simple_bind(&entry.dn, password)
+      .await
+      .map_err(Self::ldap_error)?;
+    let _ = ldap.unbind().await;
+    match bind.rc {
+      0 => {}
+      INVALID_CREDENTIALS => return Err(AppError::UserPasswordIncorrect),
+      _ => {
+        error!("LDAP bind for {} failed: {}", entry.dn, bind);
+        return Err(AppError::SomethingWentWrong);
+      }
+    }
+
+    let email: String = Self::attribute(&entry, &self.config.email_attribute)
+      .into_iter()
+      .next()
+      .unwrap_or_else(|| email.to_string());
+    Ok(LdapUser {
+      dn: entry.dn.clone(),
+      email: email.to_lowercase(),
+      name: Self::attribute(&entry, &self.config.name_attribute)
+        .into_iter()
+        .next(),
+      role: self.role(&Self::attribute(&entry, &self.config.group_attribute)),
+    })
+  }
+}
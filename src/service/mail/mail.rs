@@ -0,0 +1,64 @@
+use crate::core::error::error::AppError;
+use crate::resources::config::MailConfig;
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::error;
+
+#[async_trait]
+pub trait MailService: Send + Sync + 'static {
+  async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct MailServiceImpl {
+  transport: AsyncSmtpTransport<Tokio1Executor>,
+  from: String,
+}
+
+impl MailServiceImpl {
+  pub fn new(config: MailConfig) -> Self {
+    let builder = match config.tls {
+      true => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host).unwrap(),
+      false => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+    };
+    let builder = match config.username.is_empty() {
+      true => builder,
+      false => builder.credentials(Credentials::new(config.username, config.password)),
+    };
+
+    MailServiceImpl {
+      transport: builder.port(config.port).build(),
+      from: config.from,
+    }
+  }
+}
+
+#[async_trait]
+impl MailService for MailServiceImpl {
+  async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), AppError> {
+    let parse = |address: &str| {
+      address.parse::<Mailbox>().map_err(|e| {
+        error!("Invalid mail address {}: {:?}", address, e);
+        AppError::SomethingWentWrong
+      })
+    };
+
+    let message: Message = Message::builder()
+      .from(parse(&self.from)?)
+      .to(parse(to)?)
+      .subject(subject)
+      .body(body.to_string())
+      .map_err(|e| {
+        error!("Failed to build mail: {:?}", e);
+        AppError::SomethingWentWrong
+      })?;
+
+    self.transport.send(message).await.map_err(|e| {
+      error!("Failed to send mail: {:?}", e);
+      AppError::SomethingWentWrong
+    })?;
+    Ok(())
+  }
+}
@@ -1,2 +1,5 @@
 pub(crate) mod aws;
+pub(crate) mod ldap;
+pub(crate) mod mail;
 pub(crate) mod oauth2;
+pub(crate) mod saml;
@@ -1 +1,2 @@
 pub(crate) mod oauth2;
+pub(crate) mod oidc;
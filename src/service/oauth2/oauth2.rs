@@ -1,67 +1,172 @@
-use crate::api::client::auth::request::auth_request::OAuth2SignInRequest;
-use crate::api::client::oauth2::response::oauth2_response::OAuth2UserInfo;
+use crate::api::client::oauth2::response::oauth2_response::{
+  GithubEmail, GithubUserInfo, MicrosoftUserInfo, OAuth2UserInfo,
+};
+use crate::api::repository::users::constant::user_constant::OAuth2Provider;
 use crate::core::error::error::AppError;
 use crate::resources::config::OAuth2Config;
+use crate::service::oauth2::oidc::OidcServiceImpl;
 use async_trait::async_trait;
 use oauth2::basic::BasicClient;
 use oauth2::reqwest::async_http_client;
 use oauth2::{
-  AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, RedirectUrl, Scope, TokenResponse,
-  TokenUrl,
+  AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge,
+  PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
 };
+use reqwest::header::USER_AGENT;
 use reqwest::Client;
-use tracing::error;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, warn};
+
+/// Authorization URL together with the values the callback has to be checked against.
+pub struct OAuth2Authorization {
+  pub url: String,
+  pub state: String,
+  pub pkce_verifier: String,
+  pub nonce: Option<String>,
+}
 
 #[async_trait]
 pub trait OAuth2Service: Send + Sync + 'static {
-  async fn get_redirect_url(&self) -> Result<String, AppError>;
-  async fn sign_in(&self, payload: OAuth2SignInRequest) -> Result<OAuth2UserInfo, AppError>;
+  async fn get_redirect_url(&self) -> Result<OAuth2Authorization, AppError>;
+  async fn sign_in(
+    &self,
+    code: String,
+    pkce_verifier: String,
+    nonce: Option<String>,
+  ) -> Result<OAuth2UserInfo, AppError>;
 }
 
 #[derive(Clone)]
 pub struct OAuth2ServiceImpl {
+  provider: OAuth2Provider,
   client: BasicClient,
   scopes: Vec<String>,
   user_info_url: String,
 }
 
 impl OAuth2ServiceImpl {
-  pub async fn new(config: OAuth2Config) -> Self {
+  pub async fn new(provider: OAuth2Provider, config: OAuth2Config) -> Self {
     let client: BasicClient = BasicClient::new(
       ClientId::new(config.client_id),
       Some(ClientSecret::new(config.client_secret)),
-      AuthUrl::new(config.auth_url).unwrap(),
-      Some(TokenUrl::new(config.token_url).unwrap()),
+      AuthUrl::new(config.auth_url.unwrap()).unwrap(),
+      Some(TokenUrl::new(config.token_url.unwrap()).unwrap()),
     )
     .set_redirect_uri(RedirectUrl::new(config.redirect_url).unwrap());
 
     OAuth2ServiceImpl {
+      provider,
       client,
       scopes: config.scopes,
-      user_info_url: config.user_info_url,
+      user_info_url: config.user_info_url.unwrap(),
+    }
+  }
+
+  /// Builds one service per entry of the `oauth2` config map, keyed by provider name.
+  /// Entries with an `issuer` use OpenID Connect discovery instead of fixed URLs.
+  pub async fn registry(
+    config: &HashMap<String, OAuth2Config>,
+  ) -> HashMap<OAuth2Provider, Arc<dyn OAuth2Service>> {
+    let mut registry: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>> = HashMap::new();
+    for (name, provider_config) in config {
+      match OAuth2Provider::from_str(name) {
+        Ok(OAuth2Provider::Saml | OAuth2Provider::Ldap) | Err(_) => {
+          warn!("Ignoring unsupported oauth2 provider: {}", name)
+        }
+        Ok(provider) => {
+          let service: Arc<dyn OAuth2Service> = match provider_config.issuer {
+            Some(_) => Arc::new(OidcServiceImpl::new(provider_config.clone())),
+            None => Arc::new(Self::new(provider, provider_config.clone()).await),
+          };
+          registry.insert(provider, service);
+        }
+      }
+    }
+    registry
+  }
+
+  async fn get_user_info<T: DeserializeOwned>(
+    &self,
+    url: &str,
+    token: &str,
+  ) -> Result<T, AppError> {
+    let client: Client = Client::new();
+    match client
+      .get(url)
+      .bearer_auth(token)
+      .header(USER_AGENT, env!("CARGO_PKG_NAME"))
+      .send()
+      .await
+    {
+      Ok(resp) if resp.status().is_success() => resp.json().await.map_err(|e| {
+        error!("Error parsing user info: {:?}", e);
+        AppError::SomethingWentWrong
+      }),
+      Ok(resp) => {
+        error!("Failed to retrieve user info, status: {}", resp.status());
+        Err(AppError::SomethingWentWrong)
+      }
+      Err(e) => {
+        error!("Error making request to user info endpoint: {:?}", e);
+        Err(AppError::SomethingWentWrong)
+      }
     }
   }
+
+  /// GitHub omits private emails from the profile, so fall back to the primary verified one.
+  async fn get_github_user_info(&self, token: &str) -> Result<OAuth2UserInfo, AppError> {
+    let user_info: GithubUserInfo = self.get_user_info(&self.user_info_url, token).await?;
+    let email: String = match user_info.email.clone() {
+      Some(email) => email,
+      None => self
+        .get_user_info::<Vec<GithubEmail>>(&format!("{}/emails", self.user_info_url), token)
+        .await?
+        .into_iter()
+        .find(|email| email.primary && email.verified)
+        .map(|email| email.email)
+        .ok_or(AppError::SomethingWentWrong)?,
+    };
+    Ok(user_info.into_user_info(email))
+  }
 }
 
 #[async_trait]
 impl OAuth2Service for OAuth2ServiceImpl {
-  async fn get_redirect_url(&self) -> Result<String, AppError> {
-    let (auth_url, _) = self
+  async fn get_redirect_url(&self) -> Result<OAuth2Authorization, AppError> {
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    let (auth_url, csrf_token) = self
       .scopes
       .clone()
       .into_iter()
       .fold(
-        self.client.authorize_url(CsrfToken::new_random),
+        self
+          .client
+          .authorize_url(CsrfToken::new_random)
+          .set_pkce_challenge(pkce_challenge),
         |acc, scope| acc.add_scope(Scope::new(scope)),
       )
       .url();
-    Ok(auth_url.to_string())
+    Ok(OAuth2Authorization {
+      url: auth_url.to_string(),
+      state: csrf_token.secret().to_string(),
+      pkce_verifier: pkce_verifier.secret().to_string(),
+      nonce: None,
+    })
   }
 
-  async fn sign_in(&self, payload: OAuth2SignInRequest) -> Result<OAuth2UserInfo, AppError> {
+  async fn sign_in(
+    &self,
+    code: String,
+    pkce_verifier: String,
+    _: Option<String>,
+  ) -> Result<OAuth2UserInfo, AppError> {
     let token: String = match self
       .client
-      .exchange_code(AuthorizationCode::new(payload.code))
+      .exchange_code(AuthorizationCode::new(code))
+      .set_pkce_verifier(PkceCodeVerifier::new(pkce_verifier))
       .request_async(async_http_client)
       .await
     {
@@ -72,26 +177,89 @@ impl OAuth2Service for OAuth2ServiceImpl {
       }
     };
 
-    let client: Client = Client::new();
-    let user_info: OAuth2UserInfo = match client
-      .get(self.user_info_url.clone())
-      .bearer_auth(&token)
-      .send()
-      .await
-    {
-      Ok(resp) if resp.status().is_success() => resp.json().await.map_err(|e| {
-        error!("Error parsing user info: {:?}", e);
-        return AppError::SomethingWentWrong;
-      })?,
-      Ok(resp) => {
-        error!("Failed to retrieve user info, status: {}", resp.status());
-        return Err(AppError::SomethingWentWrong);
-      }
-      Err(e) => {
-        error!("Error making request to user info endpoint: {:?}", e);
-        return Err(AppError::SomethingWentWrong);
+    let user_info: OAuth2UserInfo = match self.provider {
+      OAuth2Provider::Google | OAuth2Provider::Oidc => {
+        self.get_user_info(&self.user_info_url, &token).await?
       }
+      OAuth2Provider::Github => self.get_github_user_info(&token).await?,
+      OAuth2Provider::Microsoft => self
+        .get_user_info::<MicrosoftUserInfo>(&self.user_info_url, &token)
+        .await?
+        .into(),
+      OAuth2Provider::Saml | OAuth2Provider::Ldap => return Err(AppError::InvalidOauth2Provider),
     };
     Ok(user_info)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::launcher::APP_CONFIG;
+  use crate::resources::config::AppConfig;
+
+  #[tokio::test]
+  async fn test_registry_contains_configured_providers() {
+    let config: AppConfig = APP_CONFIG.clone();
+    let registry: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>> =
+      OAuth2ServiceImpl::registry(&config.oauth2).await;
+
+    assert!(registry.contains_key(&OAuth2Provider::Google));
+    assert!(registry.contains_key(&OAuth2Provider::Github));
+    assert!(registry.contains_key(&OAuth2Provider::Microsoft));
+    assert!(registry.contains_key(&OAuth2Provider::Oidc));
+  }
+
+  #[tokio::test]
+  async fn test_registry_ignores_unknown_provider() {
+    let config: AppConfig = APP_CONFIG.clone();
+    let mut oauth2_config: HashMap<String, OAuth2Config> = HashMap::new();
+    oauth2_config.insert("unknown".to_string(), config.oauth2["google"].clone());
+
+    let registry: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>> =
+      OAuth2ServiceImpl::registry(&oauth2_config).await;
+
+    assert!(registry.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_github_user_info_mapping() {
+    let user_info: GithubUserInfo = serde_json::from_value(serde_json::json!({
+      "id": 42,
+      "email": null,
+      "name": "Octo Cat",
+      "avatar_url": "https://avatars.githubusercontent.com/u/42"
+    }))
+    .unwrap();
+
+    let user_info: OAuth2UserInfo = user_info.into_user_info("octo@example.com".to_string());
+
+    assert_eq!(user_info.sub, "42");
+    assert_eq!(user_info.email, "octo@example.com");
+    assert!(user_info.email_verified);
+    assert_eq!(user_info.name, Some("Octo Cat".to_string()));
+    assert_eq!(
+      user_info.picture,
+      Some("https://avatars.githubusercontent.com/u/42".to_string())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_microsoft_user_info_mapping() {
+    let user_info: MicrosoftUserInfo = serde_json::from_value(serde_json::json!({
+      "id": "ms-id",
+      "mail": null,
+      "userPrincipalName": "user@contoso.com",
+      "displayName": "Contoso User"
+    }))
+    .unwrap();
+
+    let user_info: OAuth2UserInfo = user_info.into();
+
+    assert_eq!(user_info.sub, "ms-id");
+    assert_eq!(user_info.email, "user@contoso.com");
+    assert!(!user_info.email_verified);
+    assert_eq!(user_info.name, Some("Contoso User".to_string()));
+    assert_eq!(user_info.picture, None);
+  }
+}
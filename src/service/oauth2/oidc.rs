@@ -0,0 +1,416 @@
+use crate::api::client::oauth2::response::oauth2_response::{
+  OAuth2UserInfo, OidcDiscovery, OidcIdTokenClaims,
+};
+use crate::core::error::error::AppError;
+use crate::resources::config::OAuth2Config;
+use crate::service::oauth2::oauth2::{OAuth2Authorization, OAuth2Service};
+use crate::utils::random_utils::RandomUtils;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, DecodingKey, Header, Validation};
+use oauth2::basic::{
+  BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse, BasicTokenType,
+};
+use oauth2::reqwest::async_http_client;
+use oauth2::{
+  AuthUrl, AuthorizationCode, Client, ClientId, ClientSecret, CsrfToken, ExtraTokenFields,
+  PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, StandardRevocableToken,
+  StandardTokenResponse, TokenUrl,
+};
+use reqwest::Client as HttpClient;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use tracing::{error, warn};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OidcTokenFields {
+  pub id_token: String,
+}
+
+impl ExtraTokenFields for OidcTokenFields {}
+
+type OidcTokenResponse = StandardTokenResponse<OidcTokenFields, BasicTokenType>;
+
+type OidcClient = Client<
+  BasicErrorResponse,
+  OidcTokenResponse,
+  BasicTokenType,
+  BasicTokenIntrospectionResponse,
+  StandardRevocableToken,
+  BasicRevocationErrorResponse,
+>;
+
+/// OpenID Connect provider configured by issuer; endpoints come from discovery.
+pub struct OidcServiceImpl {
+  config: OAuth2Config,
+  discovery: RwLock<Option<OidcDiscovery>>,
+  jwks: RwLock<Option<JwkSet>>,
+}
+
+impl OidcServiceImpl {
+  pub fn new(config: OAuth2Config) -> Self {
+    OidcServiceImpl {
+      config,
+      discovery: RwLock::new(None),
+      jwks: RwLock::new(None),
+    }
+  }
+
+  async fn discovery(&self) -> Result<OidcDiscovery, AppError> {
+    let cached: Option<OidcDiscovery> = self.discovery.read().unwrap().clone();
+    if let Some(discovery) = cached {
+      return Ok(discovery);
+    }
+
+    let issuer: String = self.config.issuer.clone().unwrap_or_default();
+    let issuer: &str = issuer.trim_end_matches('/');
+    let discovery: OidcDiscovery =
+      Self::get_json(&format!("{}/.well-known/openid-configuration", issuer)).await?;
+    if discovery.issuer.trim_end_matches('/') != issuer {
+      error!(
+        "OIDC discovery issuer mismatch: expected {}, got {}",
+        issuer, discovery.issuer
+      );
+      return Err(AppError::SomethingWentWrong);
+    }
+
+    *self.discovery.write().unwrap() = Some(discovery.clone());
+    Ok(discovery)
+  }
+
+  /// Looks the signing key up in the cached JWKS, refetching it only when the key is not
+  /// there yet, e.g. after the issuer rotated its keys.
+  async fn jwk(&self, discovery: &OidcDiscovery, kid: Option<&str>) -> Result<Jwk, AppError> {
+    let find = |jwks: &JwkSet| -> Option<Jwk> {
+      match kid {
+        Some(kid) => jwks.find(kid),
+        None => jwks.keys.first(),
+      }
+      .cloned()
+    };
+
+    let cached: Option<Jwk> = self.jwks.read().unwrap().as_ref().and_then(find);
+    if let Some(jwk) = cached {
+      return Ok(jwk);
+    }
+
+    let jwks: JwkSet = Self::get_json(&discovery.jwks_uri).await?;
+    let jwk: Option<Jwk> = find(&jwks);
+    *self.jwks.write().unwrap() = Some(jwks);
+    jwk.ok_or(AppError::InvalidIdToken)
+  }
+
+  fn client(&self, discovery: &OidcDiscovery) -> Result<OidcClient, AppError> {
+    let auth_url: AuthUrl =
+      AuthUrl::new(discovery.authorization_endpoint.clone()).map_err(|e| {
+        error!("Invalid OIDC authorization endpoint: {:?}", e);
+        AppError::SomethingWentWrong
+      })?;
+    let token_url: TokenUrl = TokenUrl::new(discovery.token_endpoint.clone()).map_err(|e| {
+      error!("Invalid OIDC token endpoint: {:?}", e);
+      AppError::SomethingWentWrong
+    })?;
+    let client: OidcClient = OidcClient::new(
+      ClientId::new(self.config.client_id.clone()),
+      Some(ClientSecret::new(self.config.client_secret.clone())),
+      auth_url,
+      Some(token_url),
+    )
+    .set_redirect_uri(RedirectUrl::new(self.config.redirect_url.clone()).unwrap());
+    Ok(client)
+  }
+
+  async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T, AppError> {
+    match HttpClient::new().get(url).send().await {
+      Ok(resp) if resp.status().is_success() => resp.json().await.map_err(|e| {
+        error!("Error parsing OIDC response from {}: {:?}", url, e);
+        AppError::SomethingWentWrong
+      }),
+      Ok(resp) => {
+        error!("Failed to fetch {}, status: {}", url, resp.status());
+        Err(AppError::SomethingWentWrong)
+      }
+      Err(e) => {
+        error!("Error making request to {}: {:?}", url, e);
+        Err(AppError::SomethingWentWrong)
+      }
+    }
+  }
+
+  /// Checks signature against the issuer JWKS, then issuer, audience, expiry and nonce.
+  async fn validate_id_token(
+    &self,
+    discovery: &OidcDiscovery,
+    id_token: &str,
+    nonce: &str,
+  ) -> Result<OidcIdTokenClaims, AppError> {
+    let header: Header = decode_header(id_token).map_err(|_| AppError::InvalidIdToken)?;
+    let jwk: Jwk = self.jwk(discovery, header.kid.as_deref()).await?;
+    let key: DecodingKey = DecodingKey::from_jwk(&jwk).map_err(|_| AppError::InvalidIdToken)?;
+
+    let mut validation: Validation = Validation::new(header.alg);
+    validation.set_audience(&[&self.config.client_id]);
+    validation.set_issuer(&[&discovery.issuer]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+    let claims: OidcIdTokenClaims = decode::<OidcIdTokenClaims>(id_token, &key, &validation)
+      .map_err(|e| {
+        warn!("Rejected id_token: {:?}", e);
+        AppError::InvalidIdToken
+      })?
+      .claims;
+    if claims.nonce.as_deref() != Some(nonce) {
+      warn!("Rejected id_token: nonce mismatch");
+      return Err(AppError::InvalidIdToken);
+    }
+    Ok(claims)
+  }
+}
+
+#[async_trait]
+impl OAuth2Service for OidcServiceImpl {
+  async fn get_redirect_url(&self) -> Result<OAuth2Authorization, AppError> {
+    let discovery: OidcDiscovery = self.discovery().await?;
+    let client: OidcClient = self.client(&discovery)?;
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    let nonce: String = RandomUtils::alphanumeric(32);
+    let (auth_url, csrf_token) = self
+      .config
+      .scopes
+      .clone()
+      .into_iter()
+      .fold(
+        client
+          .authorize_url(CsrfToken::new_random)
+          .set_pkce_challenge(pkce_challenge)
+          .add_extra_param("nonce", nonce.clone()),
+        |acc, scope| acc.add_scope(Scope::new(scope)),
+      )
+      .url();
+    Ok(OAuth2Authorization {
+      url: auth_url.to_string(),
+      state: csrf_token.secret().to_string(),
+      pkce_verifier: pkce_verifier.secret().to_string(),
+      nonce: Some(nonce),
+    })
+  }
+
+  async fn sign_in(
+    &self,
+    code: String,
+    pkce_verifier: String,
+    nonce: Option<String>,
+  ) -> Result<OAuth2UserInfo, AppError> {
+    let nonce: String = nonce.ok_or(AppError::InvalidIdToken)?;
+    let discovery: OidcDiscovery = self.discovery().await?;
+    let id_token: String = match self
+      .client(&discovery)?
+      .exchange_code(AuthorizationCode::new(code))
+      .set_pkce_verifier(PkceCodeVerifier::new(pkce_verifier))
+      .request_async(async_http_client)
+      .await
+    {
+      Ok(resp) => resp.extra_fields().id_token.clone(),
+      Err(e) => {
+        error!("Error during token exchange: {:?}", e);
+        return Err(AppError::SomethingWentWrong);
+      }
+    };
+
+    let claims: OidcIdTokenClaims = self
+      .validate_id_token(&discovery, &id_token, &nonce)
+      .await?;
+    Ok(OAuth2UserInfo {
+      sub: claims.sub,
+      email: claims.email.ok_or(AppError::InvalidIdToken)?,
+      email_verified: claims.email_verified,
+      name: claims.name,
+      picture: claims.picture,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::repository::users::constant::user_constant::OAuth2Provider;
+  use crate::api::test::oidc_stub::StubIssuer;
+  use crate::service::oauth2::oauth2::OAuth2ServiceImpl;
+  use crate::utils::datetime_utils::TimeUtils;
+  use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+  use base64::Engine;
+  use serde_json::{json, Value};
+  use std::collections::HashMap;
+  use std::sync::Arc;
+
+  fn service(stub: &StubIssuer) -> OidcServiceImpl {
+    OidcServiceImpl::new(stub.config())
+  }
+
+  fn id_token_claims(stub: &StubIssuer, nonce: &str) -> Value {
+    stub.claims("oidc-user", "oidc-user@example.com", nonce)
+  }
+
+  async fn sign_in_with(
+    stub: &StubIssuer,
+    service: &OidcServiceImpl,
+    claims: impl FnOnce(&str) -> Value,
+  ) -> Result<OAuth2UserInfo, AppError> {
+    let authorization: OAuth2Authorization = service.get_redirect_url().await.unwrap();
+    let nonce: String = authorization.nonce.unwrap();
+    stub.issue(StubIssuer::sign(&claims(&nonce)));
+    service
+      .sign_in("code".to_string(), authorization.pkce_verifier, Some(nonce))
+      .await
+  }
+
+  #[tokio::test]
+  async fn test_oidc_redirect_url_uses_discovery() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let authorization: OAuth2Authorization = service.get_redirect_url().await.unwrap();
+
+    assert!(authorization
+      .url
+      .starts_with(&format!("{}/authorize", stub.issuer)));
+    assert!(authorization.url.contains("code_challenge="));
+    assert!(authorization
+      .url
+      .contains(&format!("nonce={}", authorization.nonce.unwrap())));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_registry_uses_issuer() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let oauth2_config: HashMap<String, OAuth2Config> =
+      HashMap::from([("oidc".to_string(), stub.config())]);
+
+    let registry: HashMap<OAuth2Provider, Arc<dyn OAuth2Service>> =
+      OAuth2ServiceImpl::registry(&oauth2_config).await;
+    let authorization: OAuth2Authorization = registry[&OAuth2Provider::Oidc]
+      .get_redirect_url()
+      .await
+      .unwrap();
+
+    assert!(authorization
+      .url
+      .starts_with(&format!("{}/authorize", stub.issuer)));
+    assert!(authorization.nonce.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_success() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let user_info: OAuth2UserInfo =
+      sign_in_with(&stub, &service, |nonce| id_token_claims(&stub, nonce))
+        .await
+        .unwrap();
+
+    assert_eq!(user_info.sub, "oidc-user");
+    assert_eq!(user_info.email, "oidc-user@example.com");
+    assert!(user_info.email_verified);
+    assert_eq!(user_info.name, Some("OIDC User".to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_nonce_mismatch() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let result: Result<OAuth2UserInfo, AppError> =
+      sign_in_with(&stub, &service, |_| id_token_claims(&stub, "other-nonce")).await;
+
+    assert!(matches!(result, Err(AppError::InvalidIdToken)));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_wrong_audience() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let result: Result<OAuth2UserInfo, AppError> = sign_in_with(&stub, &service, |nonce| {
+      let mut claims: Value = id_token_claims(&stub, nonce);
+      claims["aud"] = json!("other-client");
+      claims
+    })
+    .await;
+
+    assert!(matches!(result, Err(AppError::InvalidIdToken)));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_expired() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let result: Result<OAuth2UserInfo, AppError> = sign_in_with(&stub, &service, |nonce| {
+      let mut claims: Value = id_token_claims(&stub, nonce);
+      claims["exp"] = json!(TimeUtils::utc_now().timestamp() - 3600);
+      claims
+    })
+    .await;
+
+    assert!(matches!(result, Err(AppError::InvalidIdToken)));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_bad_signature() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    let authorization: OAuth2Authorization = service.get_redirect_url().await.unwrap();
+    let nonce: String = authorization.nonce.unwrap();
+    let id_token: String = StubIssuer::sign(&id_token_claims(&stub, &nonce));
+    let forged_token: String = StubIssuer::sign(&id_token_claims(&stub, "other-nonce"));
+    let (unsigned, _) = id_token.rsplit_once('.').unwrap();
+    let (_, forged_signature) = forged_token.rsplit_once('.').unwrap();
+    stub.issue(format!("{}.{}", unsigned, forged_signature));
+
+    let result: Result<OAuth2UserInfo, AppError> = service
+      .sign_in("code".to_string(), authorization.pkce_verifier, Some(nonce))
+      .await;
+
+    assert!(matches!(result, Err(AppError::InvalidIdToken)));
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_caches_jwks() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+
+    for _ in 0..2 {
+      sign_in_with(&stub, &service, |nonce| id_token_claims(&stub, nonce))
+        .await
+        .unwrap();
+    }
+
+    assert_eq!(stub.jwks_requests(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_oidc_sign_in_unknown_kid_refetches_jwks() {
+    let stub: Arc<StubIssuer> = StubIssuer::start().await;
+    let service: OidcServiceImpl = service(&stub);
+    sign_in_with(&stub, &service, |nonce| id_token_claims(&stub, nonce))
+      .await
+      .unwrap();
+
+    let authorization: OAuth2Authorization = service.get_redirect_url().await.unwrap();
+    let nonce: String = authorization.nonce.unwrap();
+    let id_token: String = StubIssuer::sign(&id_token_claims(&stub, &nonce));
+    let (_, payload_and_signature) = id_token.split_once('.').unwrap();
+    let header: String = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","kid":"rotated-key"}"#);
+    stub.issue(format!("{}.{}", header, payload_and_signature));
+
+    let result: Result<OAuth2UserInfo, AppError> = service
+      .sign_in("code".to_string(), authorization.pkce_verifier, Some(nonce))
+      .await;
+
+    assert!(matches!(result, Err(AppError::InvalidIdToken)));
+    assert_eq!(stub.jwks_requests(), 2);
+  }
+}
@@ -0,0 +1,290 @@
+use crate::core::error::error::AppError;
+use roxmltree::{Node, NodeId, NodeType};
+use std::collections::BTreeMap;
+
+pub const C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+pub const EXC_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+
+/// Canonical XML (without comments) of the subtree rooted at an element, as used by XML
+/// signatures. Exclusive canonicalization only renders the namespaces an element actually
+/// uses, plus the ones named in the `InclusiveNamespaces` prefix list.
+pub struct Canonicalizer<'a> {
+  exclusive: bool,
+  inclusive_prefixes: &'a [String],
+  excluded: Option<NodeId>,
+}
+
+type Rendered<'b> = BTreeMap<&'b str, &'b str>;
+
+impl<'a> Canonicalizer<'a> {
+  pub fn new(algorithm: &str, inclusive_prefixes: &'a [String]) -> Result<Self, AppError> {
+    let exclusive: bool = match algorithm {
+      EXC_C14N => true,
+      C14N => false,
+      _ => return Err(AppError::InvalidSamlResponse),
+    };
+    Ok(Self {
+      exclusive,
+      inclusive_prefixes,
+      excluded: None,
+    })
+  }
+
+  /// Leaves this node out of the output, which is how the enveloped-signature transform
+  /// removes the `Signature` element from the content it signs.
+  pub fn excluding(mut self, node: Node) -> Self {
+    self.excluded = Some(node.id());
+    self
+  }
+
+  pub fn canonicalize(&self, node: Node) -> String {
+    let mut output: String = String::new();
+    self.write_element(node, &Rendered::new(), &mut output);
+    output
+  }
+
+  fn write_element<'b>(&self, node: Node<'b, '_>, rendered: &Rendered<'b>, output: &mut String) {
+    let source: &str = node.document().input_text();
+    let qname: &str = Self::element_qname(source, node);
+
+    let mut declarations: Rendered<'b> = Rendered::new();
+    for (prefix, uri) in self.namespaces_to_render(node, qname) {
+      if rendered.get(prefix).copied().unwrap_or("") != uri {
+        declarations.insert(prefix, uri);
+      }
+    }
+
+    output.push('<');
+    output.push_str(qname);
+    for (prefix, uri) in &declarations {
+      match *prefix {
+        "" => output.push_str(" xmlns=\""),
+        _ => {
+          output.push_str(" xmlns:");
+          output.push_str(prefix);
+          output.push_str("=\"");
+        }
+      }
+      output.push_str(&Self::escape_attribute(uri));
+      output.push('"');
+    }
+
+    let mut attributes: Vec<(&str, &str, &str, &str)> = node
+      .attributes()
+      .map(|attribute| {
+        (
+          attribute.namespace().unwrap_or(""),
+          attribute.name(),
+          &source[attribute.range_qname()],
+          attribute.value(),
+        )
+      })
+      .collect();
+    attributes.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    for (_, _, name, value) in attributes {
+      output.push(' ');
+      output.push_str(name);
+      output.push_str("=\"");
+      output.push_str(&Self::escape_attribute(value));
+      output.push('"');
+    }
+    output.push('>');
+
+    let mut scope: Rendered<'b> = rendered.clone();
+    scope.extend(declarations);
+    for child in node.children() {
+      if Some(child.id()) == self.excluded {
+        continue;
+      }
+      match child.node_type() {
+        NodeType::Element => self.write_element(child, &scope, output),
+        NodeType::Text => output.push_str(&Self::escape_text(child.text().unwrap_or(""))),
+        NodeType::PI => {
+          if let Some(pi) = child.pi() {
+            output.push_str("<?");
+            output.push_str(pi.target);
+            if let Some(value) = pi.value {
+              output.push(' ');
+              output.push_str(value);
+            }
+            output.push_str("?>");
+          }
+        }
+        _ => {}
+      }
+    }
+
+    output.push_str("</");
+    output.push_str(qname);
+    output.push('>');
+  }
+
+  /// Namespaces in scope at this element that the output has to declare, keyed by prefix
+  /// with the default namespace as the empty prefix.
+  fn namespaces_to_render<'b>(&self, node: Node<'b, '_>, qname: &str) -> Rendered<'b> {
+    let in_scope: Rendered<'b> = node
+      .namespaces()
+      .map(|namespace| (namespace.name().unwrap_or(""), namespace.uri()))
+      .filter(|(prefix, _)| *prefix != "xml")
+      .collect();
+    if !self.exclusive {
+      let mut namespaces: Rendered<'b> = in_scope;
+      namespaces.entry("").or_insert("");
+      return namespaces;
+    }
+
+    let source: &str = node.document().input_text();
+    let mut used: Vec<&str> = vec![Self::prefix(qname)];
+    used.extend(
+      node
+        .attributes()
+        .filter(|attribute| attribute.namespace().is_some())
+        .map(|attribute| Self::prefix(&source[attribute.range_qname()])),
+    );
+    used.extend(
+      self
+        .inclusive_prefixes
+        .iter()
+        .map(|prefix| match prefix.as_str() {
+          "#default" => "",
+          prefix => prefix,
+        }),
+    );
+
+    let mut namespaces: Rendered<'b> = Rendered::new();
+    for prefix in used {
+      match in_scope.get_key_value(prefix) {
+        Some((prefix, uri)) => {
+          namespaces.insert(prefix, uri);
+        }
+        None if prefix.is_empty() => {
+          namespaces.insert("", "");
+        }
+        None => {}
+      }
+    }
+    namespaces
+  }
+
+  fn element_qname<'b>(source: &'b str, node: Node) -> &'b str {
+    let tag: &str = &source[node.range().start + 1..];
+    let end: usize = tag
+      .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+      .unwrap_or(tag.len());
+    &tag[..end]
+  }
+
+  fn prefix(qname: &str) -> &str {
+    qname
+      .split_once(':')
+      .map(|(prefix, _)| prefix)
+      .unwrap_or("")
+  }
+
+  pub fn escape_attribute(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+    for c in value.chars() {
+      match c {
+        '&' => escaped.push_str("&amp;"),
+        '<' => escaped.push_str("&lt;"),
+        '"' => escaped.push_str("&quot;"),
+        '\t' => escaped.push_str("&#x9;"),
+        '\n' => escaped.push_str("&#xA;"),
+        '\r' => escaped.push_str("&#xD;"),
+        c => escaped.push(c),
+      }
+    }
+    escaped
+  }
+
+  fn escape_text(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+    for c in value.chars() {
+      match c {
+        '&' => escaped.push_str("&amp;"),
+        '<' => escaped.push_str("&lt;"),
+        '>' => escaped.push_str("&gt;"),
+        '\r' => escaped.push_str("&#xD;"),
+        c => escaped.push(c),
+      }
+    }
+    escaped
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use roxmltree::Document;
+
+  const XML: &str = r#"<root xmlns="urn:default" xmlns:a="urn:a" xmlns:unused="urn:unused" b:attr="2" z="1" a:attr="3" xmlns:b="urn:b">
+  <!-- dropped -->
+  <a:child id="x &amp; &lt;y&gt;" empty="">text &amp; &lt;more&gt;<inner/></a:child>
+  <plain xmlns="" att="&#9;tab"><a:deep/></plain>
+</root>"#;
+
+  #[test]
+  fn test_exclusive_canonicalization() {
+    let document: Document = Document::parse(XML).unwrap();
+    let canonical: String = Canonicalizer::new(EXC_C14N, &[])
+      .unwrap()
+      .canonicalize(document.root_element());
+    assert_eq!(
+      canonical,
+      concat!(
+        r#"<root xmlns="urn:default" xmlns:a="urn:a" xmlns:b="urn:b" z="1" a:attr="3" b:attr="2">"#,
+        "\n  \n  ",
+        r#"<a:child empty="" id="x &amp; &lt;y>">text &amp; &lt;more&gt;<inner></inner></a:child>"#,
+        "\n  ",
+        r#"<plain xmlns="" att="&#x9;tab"><a:deep></a:deep></plain>"#,
+        "\n</root>",
+      )
+    );
+  }
+
+  #[test]
+  fn test_inclusive_canonicalization() {
+    let document: Document = Document::parse(XML).unwrap();
+    let canonical: String = Canonicalizer::new(C14N, &[])
+      .unwrap()
+      .canonicalize(document.root_element());
+    assert!(canonical.starts_with(concat!(
+      r#"<root xmlns="urn:default" xmlns:a="urn:a" xmlns:b="urn:b" xmlns:unused="urn:unused""#,
+      r#" z="1" a:attr="3" b:attr="2">"#,
+    )));
+  }
+
+  #[test]
+  fn test_exclusive_canonicalization_of_subtree() {
+    let document: Document = Document::parse(XML).unwrap();
+    let child = document
+      .descendants()
+      .find(|node| node.has_tag_name(("urn:a", "child")))
+      .unwrap();
+    let inner = child.first_element_child().unwrap();
+
+    let canonical: String = Canonicalizer::new(EXC_C14N, &[])
+      .unwrap()
+      .canonicalize(child);
+    assert_eq!(
+      canonical,
+      concat!(
+        r#"<a:child xmlns:a="urn:a" empty="" id="x &amp; &lt;y>">"#,
+        r#"text &amp; &lt;more&gt;<inner xmlns="urn:default"></inner></a:child>"#,
+      )
+    );
+
+    let prefixes: Vec<String> = vec!["unused".to_string()];
+    let canonical: String = Canonicalizer::new(EXC_C14N, &prefixes)
+      .unwrap()
+      .excluding(inner)
+      .canonicalize(child);
+    assert_eq!(
+      canonical,
+      concat!(
+        r#"<a:child xmlns:a="urn:a" xmlns:unused="urn:unused" empty="" id="x &amp; &lt;y>">"#,
+        r#"text &amp; &lt;more&gt;</a:child>"#,
+      )
+    );
+  }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod canonicalizer;
+pub(crate) mod saml;
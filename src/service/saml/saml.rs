@@ -0,0 +1,390 @@
+use crate::api::repository::saml_tenants::entity::saml_tenant_entity::SamlTenantEntity;
+use crate::core::error::error::AppError;
+use crate::resources::config::SamlConfig;
+use crate::service::saml::canonicalizer::{Canonicalizer, C14N, EXC_C14N};
+use crate::utils::datetime_utils::TimeUtils;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use openssl::hash::{hash, MessageDigest};
+use openssl::pkey::{PKey, Public};
+use openssl::sign::Verifier;
+use openssl::x509::X509;
+use roxmltree::{Document, Node};
+
+pub const PROTOCOL_NS: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+pub const ASSERTION_NS: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+pub const DSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+const METADATA_NS: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+const STATUS_SUCCESS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+const BEARER: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const CLOCK_SKEW_SECONDS: i64 = 60;
+
+/// What the ACS keeps from a validated assertion.
+#[derive(Debug, Clone)]
+pub struct SamlAssertion {
+  pub id: String,
+  pub name_id: String,
+  pub email: String,
+  pub name: Option<String>,
+  pub expires_at: DateTime<Utc>,
+}
+
+pub trait SamlService: Send + Sync + 'static {
+  fn entity_id(&self, tenant: &SamlTenantEntity) -> String;
+  fn acs_url(&self, tenant: &SamlTenantEntity) -> String;
+  fn metadata(&self, tenant: &SamlTenantEntity) -> String;
+  fn is_valid_certificate(&self, certificate: &str) -> bool;
+  fn validate(
+    &self,
+    tenant: &SamlTenantEntity,
+    saml_response: &str,
+  ) -> Result<SamlAssertion, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SamlServiceImpl {
+  config: SamlConfig,
+}
+
+impl SamlServiceImpl {
+  pub fn new(config: SamlConfig) -> Self {
+    Self { config }
+  }
+
+  fn child<'a, 'input>(node: Node<'a, 'input>, ns: &str, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name((ns, name)))
+  }
+
+  fn required_child<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: &str,
+    name: &str,
+  ) -> Result<Node<'a, 'input>, AppError> {
+    Self::child(node, ns, name).ok_or(AppError::InvalidSamlResponse)
+  }
+
+  fn text(node: Node) -> String {
+    node
+      .descendants()
+      .filter(|descendant| descendant.is_text())
+      .filter_map(|descendant| descendant.text())
+      .collect::<String>()
+      .trim()
+      .to_string()
+  }
+
+  fn instant(node: Node, name: &str) -> Result<Option<DateTime<Utc>>, AppError> {
+    node
+      .attribute(name)
+      .map(|value| {
+        DateTime::parse_from_rfc3339(value)
+          .map(|instant| instant.with_timezone(&Utc))
+          .map_err(|_| AppError::InvalidSamlResponse)
+      })
+      .transpose()
+  }
+
+  fn certificate_key(certificate: &str) -> Result<PKey<Public>, AppError> {
+    let x509: X509 = if certificate.contains("-----BEGIN") {
+      X509::from_pem(certificate.as_bytes())
+    } else {
+      let der: Vec<u8> = BASE64_STANDARD
+        .decode(certificate.split_whitespace().collect::<String>())
+        .map_err(|_| AppError::SomethingWentWrong)?;
+      X509::from_der(&der)
+    }
+    .map_err(|_| AppError::SomethingWentWrong)?;
+    x509.public_key().map_err(|_| AppError::SomethingWentWrong)
+  }
+
+  fn digest(algorithm: Option<&str>) -> Result<MessageDigest, AppError> {
+    match algorithm {
+      Some("http://www.w3.org/2001/04/xmlenc#sha256")
+      | Some("http://www.w3.org/2001/04/xmldsig-more#rsa-sha256") => Ok(MessageDigest::sha256()),
+      Some("http://www.w3.org/2001/04/xmlenc#sha512")
+      | Some("http://www.w3.org/2001/04/xmldsig-more#rsa-sha512") => Ok(MessageDigest::sha512()),
+      _ => Err(AppError::InvalidSamlResponse),
+    }
+  }
+
+  fn inclusive_prefixes(method: Node) -> Vec<String> {
+    method
+      .children()
+      .find(|child| child.has_tag_name((EXC_C14N, "InclusiveNamespaces")))
+      .and_then(|namespaces| namespaces.attribute("PrefixList"))
+      .map(|prefixes| prefixes.split_whitespace().map(String::from).collect())
+      .unwrap_or_default()
+  }
+
+  fn decode_base64(value: &str) -> Result<Vec<u8>, AppError> {
+    BASE64_STANDARD
+      .decode(value.split_whitespace().collect::<String>())
+      .map_err(|_| AppError::InvalidSamlResponse)
+  }
+
+  /// Checks the enveloped signature of `element` against the tenant's certificate. Returns
+  /// false when the element carries no signature and an error when the signature is bad.
+  /// The key in the message's own `KeyInfo` is never trusted.
+  fn verify_signature(element: Node, key: &PKey<Public>) -> Result<bool, AppError> {
+    let Some(signature) = Self::child(element, DSIG_NS, "Signature") else {
+      return Ok(false);
+    };
+    let signed_info: Node = Self::required_child(signature, DSIG_NS, "SignedInfo")?;
+
+    let mut references = signed_info
+      .children()
+      .filter(|child| child.has_tag_name((DSIG_NS, "Reference")));
+    let reference: Node = references.next().ok_or(AppError::InvalidSamlResponse)?;
+    if references.next().is_some() {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    // The reference must point at the element holding the signature, and that id must be
+    // unique in the document so a wrapped copy cannot stand in for it.
+    let id: &str = element
+      .attribute("ID")
+      .ok_or(AppError::InvalidSamlResponse)?;
+    if reference.attribute("URI") != Some(&format!("#{}", id))
+      || element
+        .document()
+        .descendants()
+        .filter(|node| node.attribute("ID") == Some(id))
+        .count()
+        != 1
+    {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let mut c14n_method: Option<Node> = None;
+    if let Some(transforms) = Self::child(reference, DSIG_NS, "Transforms") {
+      for transform in transforms
+        .children()
+        .filter(|child| child.has_tag_name((DSIG_NS, "Transform")))
+      {
+        match transform.attribute("Algorithm") {
+          Some(ENVELOPED_SIGNATURE) => {}
+          Some(_) if c14n_method.is_none() => c14n_method = Some(transform),
+          _ => return Err(AppError::InvalidSamlResponse),
+        }
+      }
+    }
+    let c14n_prefixes: Vec<String> = c14n_method
+      .map(Self::inclusive_prefixes)
+      .unwrap_or_default();
+    let c14n_algorithm: &str = c14n_method
+      .and_then(|method| method.attribute("Algorithm"))
+      .unwrap_or(C14N);
+    let content: String = Canonicalizer::new(c14n_algorithm, &c14n_prefixes)?
+      .excluding(signature)
+      .canonicalize(element);
+
+    let digest_method: Node = Self::required_child(reference, DSIG_NS, "DigestMethod")?;
+    let digest: Vec<u8> = hash(
+      Self::digest(digest_method.attribute("Algorithm"))?,
+      content.as_bytes(),
+    )
+    .map_err(|_| AppError::InvalidSamlResponse)?
+    .to_vec();
+    let expected_digest: Vec<u8> = Self::decode_base64(&Self::text(Self::required_child(
+      reference,
+      DSIG_NS,
+      "DigestValue",
+    )?))?;
+    if digest != expected_digest {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let canonicalization: Node =
+      Self::required_child(signed_info, DSIG_NS, "CanonicalizationMethod")?;
+    let signed_info_prefixes: Vec<String> = Self::inclusive_prefixes(canonicalization);
+    let signed_info_content: String = Canonicalizer::new(
+      canonicalization
+        .attribute("Algorithm")
+        .ok_or(AppError::InvalidSamlResponse)?,
+      &signed_info_prefixes,
+    )?
+    .canonicalize(signed_info);
+
+    let signature_method: Node = Self::required_child(signed_info, DSIG_NS, "SignatureMethod")?;
+    let signature_value: Vec<u8> = Self::decode_base64(&Self::text(Self::required_child(
+      signature,
+      DSIG_NS,
+      "SignatureValue",
+    )?))?;
+    let mut verifier: Verifier =
+      Verifier::new(Self::digest(signature_method.attribute("Algorithm"))?, key)
+        .map_err(|_| AppError::InvalidSamlResponse)?;
+    let verified: bool = verifier
+      .verify_oneshot(&signature_value, signed_info_content.as_bytes())
+      .unwrap_or(false);
+    if !verified {
+      return Err(AppError::InvalidSamlResponse);
+    }
+    Ok(true)
+  }
+
+  fn attribute_value(assertion: Node, name: &str) -> Option<String> {
+    Self::child(assertion, ASSERTION_NS, "AttributeStatement")?
+      .children()
+      .filter(|child| child.has_tag_name((ASSERTION_NS, "Attribute")))
+      .find(|attribute| attribute.attribute("Name") == Some(name))
+      .and_then(|attribute| Self::child(attribute, ASSERTION_NS, "AttributeValue"))
+      .map(Self::text)
+      .filter(|value| !value.is_empty())
+  }
+}
+
+impl SamlService for SamlServiceImpl {
+  fn entity_id(&self, tenant: &SamlTenantEntity) -> String {
+    format!("{}/{}/metadata", self.config.url, tenant.slug)
+  }
+
+  fn acs_url(&self, tenant: &SamlTenantEntity) -> String {
+    format!("{}/{}/acs", self.config.url, tenant.slug)
+  }
+
+  fn is_valid_certificate(&self, certificate: &str) -> bool {
+    Self::certificate_key(certificate).is_ok()
+  }
+
+  fn metadata(&self, tenant: &SamlTenantEntity) -> String {
+    format!(
+      concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<md:EntityDescriptor xmlns:md="{}" entityID="{}">"#,
+        r#"<md:SPSSODescriptor AuthnRequestsSigned="false" WantAssertionsSigned="true" "#,
+        r#"protocolSupportEnumeration="{}">"#,
+        r#"<md:NameIDFormat>urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress</md:NameIDFormat>"#,
+        r#"<md:AssertionConsumerService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" "#,
+        r#"Location="{}" index="0" isDefault="true"/>"#,
+        r#"</md:SPSSODescriptor>"#,
+        r#"</md:EntityDescriptor>"#,
+      ),
+      METADATA_NS,
+      Canonicalizer::escape_attribute(&self.entity_id(tenant)),
+      PROTOCOL_NS,
+      Canonicalizer::escape_attribute(&self.acs_url(tenant)),
+    )
+  }
+
+  /// Validates an HTTP-POST `SAMLResponse` issued by the tenant's IdP: either the response or
+  /// its single assertion must be signed by the configured certificate, and the assertion
+  /// must be addressed to this tenant's ACS and still be within its validity window.
+  fn validate(
+    &self,
+    tenant: &SamlTenantEntity,
+    saml_response: &str,
+  ) -> Result<SamlAssertion, AppError> {
+    let xml: String = String::from_utf8(Self::decode_base64(saml_response)?)
+      .map_err(|_| AppError::InvalidSamlResponse)?;
+    let document: Document = Document::parse(&xml).map_err(|_| AppError::InvalidSamlResponse)?;
+    let response: Node = document.root_element();
+    if !response.has_tag_name((PROTOCOL_NS, "Response")) {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let acs_url: String = self.acs_url(tenant);
+    if response
+      .attribute("Destination")
+      .is_some_and(|destination| destination != acs_url)
+    {
+      return Err(AppError::InvalidSamlResponse);
+    }
+    let status: Option<&str> = Self::child(response, PROTOCOL_NS, "Status")
+      .and_then(|status| Self::child(status, PROTOCOL_NS, "StatusCode"))
+      .and_then(|code| code.attribute("Value"));
+    if status != Some(STATUS_SUCCESS) {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let mut assertions = document
+      .descendants()
+      .filter(|node| node.has_tag_name((ASSERTION_NS, "Assertion")));
+    let assertion: Node = assertions.next().ok_or(AppError::InvalidSamlResponse)?;
+    if assertions.next().is_some() || assertion.parent_element() != Some(response) {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let key: PKey<Public> = Self::certificate_key(&tenant.idp_certificate)?;
+    let response_signed: bool = Self::verify_signature(response, &key)?;
+    let assertion_signed: bool = Self::verify_signature(assertion, &key)?;
+    if !response_signed && !assertion_signed {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let issuer: String = Self::text(Self::required_child(assertion, ASSERTION_NS, "Issuer")?);
+    if issuer != tenant.idp_entity_id {
+      return Err(AppError::InvalidSamlResponse);
+    }
+
+    let now: DateTime<Utc> = TimeUtils::utc_now();
+    let skew: Duration = Duration::seconds(CLOCK_SKEW_SECONDS);
+    let entity_id: String = self.entity_id(tenant);
+    if let Some(conditions) = Self::child(assertion, ASSERTION_NS, "Conditions") {
+      let not_before: Option<DateTime<Utc>> = Self::instant(conditions, "NotBefore")?;
+      let not_on_or_after: Option<DateTime<Utc>> = Self::instant(conditions, "NotOnOrAfter")?;
+      if not_before.is_some_and(|instant| now + skew < instant)
+        || not_on_or_after.is_some_and(|instant| now - skew >= instant)
+      {
+        return Err(AppError::InvalidSamlResponse);
+      }
+      let audience_allowed: bool = conditions
+        .children()
+        .filter(|child| child.has_tag_name((ASSERTION_NS, "AudienceRestriction")))
+        .all(|restriction| {
+          restriction
+            .children()
+            .filter(|child| child.has_tag_name((ASSERTION_NS, "Audience")))
+            .any(|audience| Self::text(audience) == entity_id)
+        });
+      if !audience_allowed {
+        return Err(AppError::InvalidSamlResponse);
+      }
+    }
+
+    let subject: Node = Self::required_child(assertion, ASSERTION_NS, "Subject")?;
+    let name_id: String = Self::text(Self::required_child(subject, ASSERTION_NS, "NameID")?);
+    let mut expires_at: Option<DateTime<Utc>> = None;
+    for confirmation in subject
+      .children()
+      .filter(|child| child.has_tag_name((ASSERTION_NS, "SubjectConfirmation")))
+      .filter(|confirmation| confirmation.attribute("Method") == Some(BEARER))
+    {
+      let Some(data) = Self::child(confirmation, ASSERTION_NS, "SubjectConfirmationData") else {
+        continue;
+      };
+      let not_on_or_after: Option<DateTime<Utc>> = Self::instant(data, "NotOnOrAfter")?;
+      if data.attribute("Recipient") == Some(acs_url.as_str())
+        && not_on_or_after.is_some_and(|instant| now - skew < instant)
+      {
+        expires_at = not_on_or_after;
+        break;
+      }
+    }
+    let expires_at: DateTime<Utc> = expires_at.ok_or(AppError::InvalidSamlResponse)?;
+
+    let email: String = match &tenant.email_attribute {
+      Some(attribute) => Self::attribute_value(assertion, attribute),
+      None => Some(name_id.clone()),
+    }
+    .filter(|email| tenant.allows_email(email))
+    .ok_or(AppError::InvalidSamlResponse)?;
+    let name: Option<String> = tenant
+      .name_attribute
+      .as_deref()
+      .and_then(|attribute| Self::attribute_value(assertion, attribute));
+
+    Ok(SamlAssertion {
+      id: assertion
+        .attribute("ID")
+        .ok_or(AppError::InvalidSamlResponse)?
+        .to_string(),
+      name_id,
+      email: email.to_lowercase(),
+      name,
+      expires_at,
+    })
+  }
+}
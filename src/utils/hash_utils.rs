@@ -1,4 +1,8 @@
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
 use bcrypt::{hash, verify};
+use openssl::memcmp;
+use openssl::sha::sha256;
 
 pub const COST: u32 = 8;
 
@@ -12,6 +16,17 @@ impl HashUtils {
   pub fn verify_password(password: &str, hashed_password: &str) -> bool {
     verify(password, hashed_password).unwrap_or(false)
   }
+
+  /// SHA-256 digest of a high-entropy random secret, which unlike a password needs no slow
+  /// hash to resist guessing and is checked on every request that carries it.
+  pub fn hash_secret(secret: &str) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(sha256(secret.as_bytes()))
+  }
+
+  pub fn verify_secret(secret: &str, digest: &str) -> bool {
+    let expected: String = Self::hash_secret(secret);
+    expected.len() == digest.len() && memcmp::eq(expected.as_bytes(), digest.as_bytes())
+  }
 }
 
 #[cfg(test)]
@@ -40,4 +55,13 @@ mod tests {
       &hashed_password
     ));
   }
+
+  #[tokio::test]
+  async fn test_verify_secret() {
+    let digest: String = HashUtils::hash_secret("my_secret");
+
+    assert_ne!(digest, "my_secret");
+    assert!(HashUtils::verify_secret("my_secret", &digest));
+    assert!(!HashUtils::verify_secret("wrong_secret", &digest));
+  }
 }
@@ -1,2 +1,4 @@
 pub(crate) mod datetime_utils;
 pub(crate) mod hash_utils;
+pub(crate) mod network_utils;
+pub(crate) mod random_utils;
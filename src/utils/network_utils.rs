@@ -0,0 +1,112 @@
+use reqwest::Url;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use tokio::net::lookup_host;
+
+pub struct NetworkUtils;
+
+impl NetworkUtils {
+  /// Whether the address is reachable on the public internet, as opposed to loopback, private,
+  /// link-local (cloud metadata endpoints) or otherwise reserved ranges.
+  pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+      IpAddr::V4(ip) => Self::is_public_ipv4(ip),
+      IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+        Some(mapped) => Self::is_public_ipv4(mapped),
+        None => Self::is_public_ipv6(ip),
+      },
+    }
+  }
+
+  fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(ip.is_unspecified()
+      || ip.is_loopback()
+      || ip.is_private()
+      || ip.is_link_local()
+      || ip.is_broadcast()
+      || ip.is_documentation()
+      || ip.is_multicast()
+      || first == 0
+      || first >= 240
+      || (first == 100 && (64..128).contains(&second)))
+  }
+
+  fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first: u16 = ip.segments()[0];
+    !(ip.is_unspecified()
+      || ip.is_loopback()
+      || ip.is_multicast()
+      || (first & 0xfe00) == 0xfc00
+      || (first & 0xffc0) == 0xfe80)
+  }
+
+  /// Host and addresses of an https URL whose host only resolves to public addresses.
+  pub async fn resolve_public_https(url: &Url) -> Option<(String, Vec<SocketAddr>)> {
+    if url.scheme() != "https" {
+      return None;
+    }
+    let host: &str = url.host_str()?;
+    let port: u16 = url.port_or_known_default()?;
+    let ip_host: &str = host.trim_start_matches('[').trim_end_matches(']');
+    let addresses: Vec<SocketAddr> = lookup_host((ip_host, port)).await.ok()?.collect();
+    match !addresses.is_empty()
+      && addresses
+        .iter()
+        .all(|address| Self::is_public_ip(address.ip()))
+    {
+      true => Some((host.to_string(), addresses)),
+      false => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::network_utils::NetworkUtils;
+  use reqwest::Url;
+  use std::net::IpAddr;
+
+  #[tokio::test]
+  async fn test_is_public_ip() {
+    for ip in ["93.184.216.34", "2606:4700::1111"] {
+      assert!(NetworkUtils::is_public_ip(ip.parse::<IpAddr>().unwrap()));
+    }
+    for ip in [
+      "127.0.0.1",
+      "10.0.0.1",
+      "172.16.0.1",
+      "192.168.1.1",
+      "169.254.169.254",
+      "100.64.0.1",
+      "0.0.0.0",
+      "::1",
+      "fd00::1",
+      "fe80::1",
+      "::ffff:127.0.0.1",
+    ] {
+      assert!(!NetworkUtils::is_public_ip(ip.parse::<IpAddr>().unwrap()));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_resolve_public_https_rejects_internal_urls() {
+    for url in [
+      "http://93.184.216.34/avatar.png",
+      "https://127.0.0.1/avatar.png",
+      "https://169.254.169.254/latest/meta-data",
+      "https://[::1]/avatar.png",
+      "https://localhost/avatar.png",
+    ] {
+      let url: Url = Url::parse(url).unwrap();
+      assert!(NetworkUtils::resolve_public_https(&url).await.is_none());
+    }
+  }
+
+  #[tokio::test]
+  async fn test_resolve_public_https_accepts_public_ip() {
+    let url: Url = Url::parse("https://93.184.216.34/avatar.png").unwrap();
+    let (host, addresses) = NetworkUtils::resolve_public_https(&url).await.unwrap();
+    assert_eq!(host, "93.184.216.34");
+    assert_eq!(addresses[0].port(), 443);
+  }
+}
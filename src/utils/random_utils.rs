@@ -0,0 +1,32 @@
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+
+pub struct RandomUtils;
+
+impl RandomUtils {
+  pub fn alphanumeric(length: usize) -> String {
+    thread_rng()
+      .sample_iter(&Alphanumeric)
+      .take(length)
+      .map(char::from)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::random_utils::RandomUtils;
+
+  #[tokio::test]
+  async fn test_alphanumeric() {
+    let value: String = RandomUtils::alphanumeric(32);
+
+    assert_eq!(value.len(), 32);
+    assert!(value.chars().all(|c| c.is_ascii_alphanumeric()));
+  }
+
+  #[tokio::test]
+  async fn test_alphanumeric_is_random() {
+    assert_ne!(RandomUtils::alphanumeric(32), RandomUtils::alphanumeric(32));
+  }
+}
@@ -5,8 +5,11 @@ use crate::core::logging::logging::Logging;
 use crate::db::db::PostgresDatabase;
 use crate::job::consumer::consumer::{JobConsumer, JobConsumerImpl};
 use crate::job::kind::JobKind;
+use crate::job::processor::mail::mail_event::MailEventProcessor;
+use crate::job::processor::processor::JobProcessor;
 use crate::job::processor::users::user_event::UserEventProcessor;
 use crate::resources::config::AppConfig;
+use crate::service::mail::mail::MailServiceImpl;
 use axum::Router;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -30,10 +33,13 @@ impl Worker {
     Ok(processor)
   }
 
-  async fn setup_job_consumer(
-    &self,
-    processor: UserEventProcessor,
-  ) -> JobConsumerImpl<UserEventProcessor> {
+  async fn init_mail_event_processor(&self) -> Result<MailEventProcessor, AppError> {
+    let mail_service: MailServiceImpl = MailServiceImpl::new(self.config.mail.clone());
+    let processor: MailEventProcessor = MailEventProcessor::new(Arc::new(mail_service)).await;
+    Ok(processor)
+  }
+
+  async fn setup_job_consumer(&self, processor: JobProcessor) -> JobConsumerImpl<JobProcessor> {
     JobConsumerImpl::new(
       self.config.aws.region.clone(),
       self.config.aws.sqs.clone(),
@@ -53,8 +59,11 @@ impl Worker {
   }
 
   pub async fn start(self) -> Result<(), AppError> {
-    let processor: UserEventProcessor = self.init_user_event_processor().await?;
-    let consumer: JobConsumerImpl<UserEventProcessor> = self.setup_job_consumer(processor).await;
+    let processor: JobProcessor = JobProcessor::new(
+      self.init_user_event_processor().await?,
+      self.init_mail_event_processor().await?,
+    );
+    let consumer: JobConsumerImpl<JobProcessor> = self.setup_job_consumer(processor).await;
     consumer.start(&JobKind::Local).await?;
 
     let app: Router = self.configure_routes();